@@ -161,16 +161,14 @@ pub fn bench_ffts(c: &mut Criterion) {
         });
         write_to_json(&bench_id, "fftw-fwd", n);
 
-        if n <= 1024 {
-            let ordered = concrete_fft::ordered::Plan::new(
-                n,
-                concrete_fft::ordered::Method::Measure(bench_duration),
-            );
+        let ordered = concrete_fft::ordered::Plan::new(
+            n,
+            concrete_fft::ordered::Method::Measure(bench_duration),
+        );
 
-            let bench_id = format!("concrete-fwd-{n}");
-            c.bench_function(&bench_id, |b| b.iter(|| ordered.fwd(dst, stack.rb_mut())));
-            write_to_json(&bench_id, "concrete-fwd", n);
-        }
+        let bench_id = format!("concrete-fwd-{n}");
+        c.bench_function(&bench_id, |b| b.iter(|| ordered.fwd(dst, stack.rb_mut())));
+        write_to_json(&bench_id, "concrete-fwd", n);
 
         let bench_id = format!("unordered-fwd-{n}");
         c.bench_function(&bench_id, |b| {
@@ -208,6 +206,53 @@ pub fn bench_ffts(c: &mut Criterion) {
     }
 }
 
+pub fn bench_batch(c: &mut Criterion) {
+    let count = 64;
+    for n in [1 << 6, 1 << 7, 1 << 8, 1 << 9, 1 << 10] {
+        let bench_duration = std::time::Duration::from_millis(10);
+        let unordered = concrete_fft::unordered::Plan::new(
+            n,
+            concrete_fft::unordered::Method::Measure(bench_duration),
+        );
+
+        let mut mem = dyn_stack::GlobalPodBuffer::new(unordered.fft_scratch().unwrap());
+        let mut stack = PodStack::new(&mut mem);
+        let mut buf = vec![c64::new(0.0, 0.0); n * count];
+
+        let bench_id = format!("unordered-fwd-loop-{n}x{count}");
+        c.bench_function(&bench_id, |b| {
+            b.iter(|| {
+                for buf in buf.chunks_exact_mut(n) {
+                    unordered.fwd(buf, stack.rb_mut());
+                }
+            });
+        });
+        write_to_json(&bench_id, "unordered-fwd-loop", n);
+
+        let bench_id = format!("unordered-fwd-batch-{n}x{count}");
+        c.bench_function(&bench_id, |b| {
+            b.iter(|| unordered.fwd_batch(&mut buf, count, stack.rb_mut()));
+        });
+        write_to_json(&bench_id, "unordered-fwd-batch", n);
+
+        let bench_id = format!("unordered-inv-loop-{n}x{count}");
+        c.bench_function(&bench_id, |b| {
+            b.iter(|| {
+                for buf in buf.chunks_exact_mut(n) {
+                    unordered.inv(buf, stack.rb_mut());
+                }
+            });
+        });
+        write_to_json(&bench_id, "unordered-inv-loop", n);
+
+        let bench_id = format!("unordered-inv-batch-{n}x{count}");
+        c.bench_function(&bench_id, |b| {
+            b.iter(|| unordered.inv_batch(&mut buf, count, stack.rb_mut()));
+        });
+        write_to_json(&bench_id, "unordered-inv-batch", n);
+    }
+}
+
 #[cfg(feature = "fft128")]
 pub fn bench_fft128(c: &mut Criterion) {
     for n in [64, 128, 256, 512, 1024, 2048, 4096, 8192, 16384] {
@@ -296,7 +341,7 @@ pub fn bench_fft128(c: &mut Criterion) {
         }
 
         #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-        #[cfg(feature = "nightly")]
+        #[cfg(feature = "avx512")]
         if let Some(simd) = pulp::x86::V4::try_new() {
             let bench_id = format!("concrete-fft128-avx512-fwd-{n}");
             c.bench_function(&bench_id, |bench| {
@@ -337,7 +382,7 @@ pub fn bench_fft128(c: &mut Criterion) {
     }
 }
 
-criterion_group!(fft, bench_ffts);
+criterion_group!(fft, bench_ffts, bench_batch);
 #[cfg(feature = "fft128")]
 criterion_group!(fft128, bench_fft128);
 
@@ -7,13 +7,14 @@ function usage() {
     echo
     echo "--help                    Print this message"
     echo "--rust-toolchain          The toolchain to check the version for with leading"
-    echo "--min-rust-version        Check toolchain version is >= to this version, default is 1.65"
+    echo "--min-rust-version        Check toolchain version is >= to this version, default is 1.89"
     echo
 }
 
 RUST_TOOLCHAIN=""
-# We set the default rust version 1.65 which is the minimum version required for stable GATs
-MIN_RUST_VERSION="1.65"
+# We set the default rust version 1.89 which is the minimum version required for the stable
+# AVX-512 intrinsics
+MIN_RUST_VERSION="1.89"
 
 while [ -n "$1" ]
 do
@@ -0,0 +1,134 @@
+use crate::{c32, c64, fft_simd::*};
+use pulp::{aarch64::*, cast, f32x4, f64x2};
+
+impl FftSimd<c64x1> for Neon {
+    #[inline(always)]
+    fn try_new() -> Option<Self> {
+        Self::try_new()
+    }
+    #[inline(always)]
+    fn vectorize(self, f: impl pulp::NullaryFnOnce<Output = ()>) {
+        self.vectorize(f)
+    }
+
+    #[inline(always)]
+    fn splat_f64(self, value: f64) -> c64x1 {
+        cast(self.splat_f64x2(value))
+    }
+
+    #[inline(always)]
+    fn splat(self, value: c64) -> c64x1 {
+        cast(value)
+    }
+
+    #[inline(always)]
+    fn xor(self, a: c64x1, b: c64x1) -> c64x1 {
+        cast(self.xor_f64x2(cast(a), cast(b)))
+    }
+
+    #[inline(always)]
+    fn swap_re_im(self, xy: c64x1) -> c64x1 {
+        let xy: f64x2 = cast(xy);
+        cast(f64x2(xy.1, xy.0))
+    }
+
+    #[inline(always)]
+    fn add(self, a: c64x1, b: c64x1) -> c64x1 {
+        cast(self.add_f64x2(cast(a), cast(b)))
+    }
+
+    #[inline(always)]
+    fn sub(self, a: c64x1, b: c64x1) -> c64x1 {
+        cast(self.sub_f64x2(cast(a), cast(b)))
+    }
+
+    #[inline(always)]
+    fn real_mul(self, a: c64x1, b: c64x1) -> c64x1 {
+        cast(self.mul_f64x2(cast(a), cast(b)))
+    }
+
+    #[inline(always)]
+    fn mul(self, a: c64x1, b: c64x1) -> c64x1 {
+        let xy: f64x2 = cast(b);
+        let yx: f64x2 = cast(self.swap_re_im(b));
+        let ab: f64x2 = cast(a);
+        let aa = f64x2(ab.0, ab.0);
+        let bb = f64x2(-ab.1, ab.1);
+        // (a * x - b * y, a * y + b * x)
+        cast(self.mul_add_f64x2(bb, yx, self.mul_f64x2(aa, xy)))
+    }
+}
+
+impl FftSimd<c32x2> for Neon {
+    #[inline(always)]
+    fn try_new() -> Option<Self> {
+        Self::try_new()
+    }
+    #[inline(always)]
+    fn vectorize(self, f: impl pulp::NullaryFnOnce<Output = ()>) {
+        self.vectorize(f)
+    }
+
+    #[inline(always)]
+    fn splat_f64(self, value: f64) -> c32x2 {
+        cast(self.splat_f32x4(value as f32))
+    }
+
+    #[inline(always)]
+    fn splat(self, value: c32) -> c32x2 {
+        // a c32 has the same size as an f64, so we can broadcast its bits as such
+        cast(self.splat_f64x2(cast(value)))
+    }
+
+    #[inline(always)]
+    fn xor(self, a: c32x2, b: c32x2) -> c32x2 {
+        cast(self.xor_f32x4(cast(a), cast(b)))
+    }
+
+    #[inline(always)]
+    fn swap_re_im(self, xy: c32x2) -> c32x2 {
+        let xy: f32x4 = cast(xy);
+        cast(f32x4(xy.1, xy.0, xy.3, xy.2))
+    }
+
+    #[inline(always)]
+    fn add(self, a: c32x2, b: c32x2) -> c32x2 {
+        cast(self.add_f32x4(cast(a), cast(b)))
+    }
+
+    #[inline(always)]
+    fn sub(self, a: c32x2, b: c32x2) -> c32x2 {
+        cast(self.sub_f32x4(cast(a), cast(b)))
+    }
+
+    #[inline(always)]
+    fn real_mul(self, a: c32x2, b: c32x2) -> c32x2 {
+        cast(self.mul_f32x4(cast(a), cast(b)))
+    }
+
+    #[inline(always)]
+    fn mul(self, a: c32x2, b: c32x2) -> c32x2 {
+        let xy: f32x4 = cast(b);
+        let yx: f32x4 = cast(self.swap_re_im(b));
+        let ab: f32x4 = cast(a);
+        let aa = f32x4(ab.0, ab.0, ab.2, ab.2);
+        let bb = f32x4(-ab.1, ab.1, -ab.3, ab.3);
+        // (a * x - b * y, a * y + b * x)
+        cast(self.mul_add_f32x4(bb, yx, self.mul_f32x4(aa, xy)))
+    }
+
+    #[inline(always)]
+    fn catlo(self, a: c32x2, b: c32x2) -> c32x2 {
+        // each c32 has the same size as an f64, so we can move them around as such
+        let a: f64x2 = cast(a);
+        let b: f64x2 = cast(b);
+        cast(f64x2(a.0, b.0))
+    }
+
+    #[inline(always)]
+    fn cathi(self, a: c32x2, b: c32x2) -> c32x2 {
+        let a: f64x2 = cast(a);
+        let b: f64x2 = cast(b);
+        cast(f64x2(a.1, b.1))
+    }
+}
@@ -0,0 +1,484 @@
+//! Discrete cosine and sine transform module.
+//!
+//! This module computes the type-II and type-III discrete cosine transforms (DCT) and discrete
+//! sine transforms (DST) of real vectors whose size is a power of two. Given a buffer of real
+//! numbers $[x_0, \dots, x_{n-1}]$, the DCT-II $[X_0, \dots, X_{n-1}]$ is given by
+//! $$X_k = \sum_{j = 0}^{n-1} x_j \cos\left(\frac{\pi (2j + 1) k}{2n}\right),$$
+//! and the DCT-III $[Y_0, \dots, Y_{n-1}]$ is given by
+//! $$Y_j = \frac{X_0}{2} + \sum_{k = 1}^{n-1} X_k \cos\left(\frac{\pi (2j + 1) k}{2n}\right).$$
+//! Similarly, the DST-II is given by
+//! $$X_k = \sum_{j = 0}^{n-1} x_j \sin\left(\frac{\pi (2j + 1) (k + 1)}{2n}\right),$$
+//! and the DST-III is given by
+//! $$Y_j = \frac{(-1)^j X_{n-1}}{2} + \sum_{k = 0}^{n-2} X_k
+//! \sin\left(\frac{\pi (2j + 1) (k + 1)}{2n}\right).$$
+//!
+//! The type-III transforms are the inverses of the type-II transforms, up to a factor of $n/2$.
+//!
+//! The transforms of size $n$ are computed with an ordered complex FFT of size $n/2$, by
+//! reordering the input and applying twiddling factors before and after the FFT.
+
+use crate::{c64, check_power_of_two, fft_simd::sincospi64, ordered, PlanError};
+use aligned_vec::{avec, ABox, CACHELINE_ALIGN};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+
+pub use crate::ordered::Method;
+
+/// Discrete cosine and sine transform plan.
+///
+/// This type holds an ordered FFT plan of size `n / 2` and the twiddling factors used to compute
+/// the transforms of size `n`. The size must be a power of two, and at least `2`.
+#[derive(Clone)]
+pub struct Plan {
+    plan: ordered::Plan,
+    // `exp(-2iπk/n)` for `k` in `0..n / 2`
+    twiddles: ABox<[c64]>,
+    // `exp(-iπk/2n)` for `k` in `0..=n / 2`
+    twiddles_dct: ABox<[c64]>,
+}
+
+impl core::fmt::Debug for Plan {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Plan")
+            .field("fft_size", &self.fft_size())
+            .field("plan", &self.plan)
+            .finish()
+    }
+}
+
+impl Plan {
+    /// Returns a new transform plan for vectors of size `n`. The internal ordered FFT plan of
+    /// size `n / 2` is selected by the provided method.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `n` is not a power of two, or if it is less than `2`.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::dct::{Method, Plan};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(8, Method::Measure(Duration::from_millis(10)));
+    /// ```
+    #[track_caller]
+    pub fn new(n: usize, method: Method) -> Self {
+        match Self::try_new(n, method) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Returns a new transform plan for vectors of size `n`, or an error if `n` is not a power
+    /// of two, or if it is less than `2`.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::dct::{Method, Plan};
+    /// use concrete_fft::PlanError;
+    /// use core::time::Duration;
+    ///
+    /// let method = Method::Measure(Duration::from_millis(10));
+    /// assert!(Plan::try_new(8, method).is_ok());
+    /// assert_eq!(
+    ///     Plan::try_new(6, method).unwrap_err(),
+    ///     PlanError::NotPowerOfTwo { size: 6 },
+    /// );
+    /// ```
+    pub fn try_new(n: usize, method: Method) -> Result<Self, PlanError> {
+        check_power_of_two(n)?;
+        if n < 2 {
+            return Err(PlanError::TooSmall { size: n, min: 2 });
+        }
+
+        let m = n / 2;
+        let plan = ordered::Plan::try_new(m, method)?;
+
+        let mut twiddles = avec![c64::default(); m].into_boxed_slice();
+        let theta = -2.0 / n as f64;
+        for (k, w) in twiddles.iter_mut().enumerate() {
+            let (s, c) = sincospi64(theta * k as f64);
+            *w = c64 { re: c, im: s };
+        }
+
+        let mut twiddles_dct = avec![c64::default(); m + 1].into_boxed_slice();
+        let theta = -0.5 / n as f64;
+        for (k, w) in twiddles_dct.iter_mut().enumerate() {
+            let (s, c) = sincospi64(theta * k as f64);
+            *w = c64 { re: c, im: s };
+        }
+
+        Ok(Self {
+            plan,
+            twiddles,
+            twiddles_dct,
+        })
+    }
+
+    /// Returns the vector size of the transforms.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     dct::{Method, Plan},
+    ///     ordered::FftAlgo,
+    /// };
+    ///
+    /// let plan = Plan::new(8, Method::UserProvided(FftAlgo::Dif4));
+    /// assert_eq!(plan.fft_size(), 8);
+    /// ```
+    pub fn fft_size(&self) -> usize {
+        2 * self.plan.fft_size()
+    }
+
+    /// Returns the size and alignment of the scratch memory needed to perform a transform.
+    pub fn fft_scratch(&self) -> Result<StackReq, SizeOverflow> {
+        StackReq::try_new_aligned::<c64>(self.plan.fft_size(), CACHELINE_ALIGN)?
+            .try_and(self.plan.fft_scratch()?)
+    }
+
+    /// Performs a DCT-II in place, using the provided stack as scratch space.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `buf` is not equal to the transform size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::dct::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [1.0; 4];
+    /// plan.dct2(&mut buf, stack);
+    /// assert!((buf[0] - 4.0).abs() < 1e-12);
+    /// ```
+    pub fn dct2(&self, buf: &mut [f64], stack: PodStack) {
+        let n = self.fft_size();
+        assert_eq!(buf.len(), n);
+        let m = n / 2;
+
+        let (z, stack) = stack.make_aligned_raw::<c64>(m, CACHELINE_ALIGN);
+
+        // reorder the input into `v = [x_0, x_2, ..., x_{n-2}, x_{n-1}, ..., x_3, x_1]`, then
+        // pack the even terms of `v` in the real part and the odd terms in the imaginary part
+        let v = |i: usize| {
+            if i < m {
+                buf[2 * i]
+            } else {
+                buf[2 * (n - i) - 1]
+            }
+        };
+        for (k, z) in z.iter_mut().enumerate() {
+            *z = c64 {
+                re: v(2 * k),
+                im: v(2 * k + 1),
+            };
+        }
+
+        self.plan.fwd(z, stack);
+
+        // unpack the FFT of `v`, `V_k = E_k + exp(-2iπk/n) O_k`, then compute
+        // `X_k - i X_{n-k} = exp(-iπk/2n) V_k`
+        let z0 = z[0];
+        buf[0] = z0.re + z0.im;
+        buf[m] = (self.twiddles_dct[m] * (z0.re - z0.im)).re;
+        for k in 1..m {
+            let zk = z[k];
+            let zj = z[m - k].conj();
+
+            let even = (zk + zj) * 0.5;
+            let odd = (zk - zj) * c64 { re: 0.0, im: -0.5 };
+            let w = self.twiddles_dct[k] * (even + self.twiddles[k] * odd);
+
+            buf[k] = w.re;
+            buf[n - k] = -w.im;
+        }
+    }
+
+    /// Performs a DCT-III in place, using the provided stack as scratch space.
+    ///
+    /// The DCT-III is the inverse of the DCT-II up to a factor, so that applying [`Plan::dct2`]
+    /// followed by [`Plan::dct3`] multiplies the vector by `n / 2`.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `buf` is not equal to the transform size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::dct::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer, ReborrowMut};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let mut stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [1.0, 2.0, 3.0, 4.0];
+    /// plan.dct2(&mut buf, stack.rb_mut());
+    /// plan.dct3(&mut buf, stack);
+    /// for (x, expected) in buf.iter().zip([2.0, 4.0, 6.0, 8.0]) {
+    ///     assert!((x - expected).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn dct3(&self, buf: &mut [f64], stack: PodStack) {
+        let n = self.fft_size();
+        assert_eq!(buf.len(), n);
+        let m = n / 2;
+
+        let (z, stack) = stack.make_aligned_raw::<c64>(m, CACHELINE_ALIGN);
+
+        // `V_k = exp(iπk/2n) (X_k - i X_{n-k})`, where `X_n = 0`
+        let v = |k: usize| {
+            let x_rev = if k == 0 { 0.0 } else { buf[n - k] };
+            self.twiddles_dct[k].conj()
+                * c64 {
+                    re: buf[k],
+                    im: -x_rev,
+                }
+        };
+
+        // `E_k = (V_k + V_{k+m}) / 2`, `O_k = exp(2iπk/n) (V_k - V_{k+m}) / 2`, and
+        // `Z_k = E_k + i O_k`, where `V_{k+m} = conj(V_{m-k})`
+        for (k, z) in z.iter_mut().enumerate() {
+            let vk = v(k);
+            let vj = if k == 0 { v(m) } else { v(m - k).conj() };
+
+            let even = (vk + vj) * 0.5;
+            let odd = self.twiddles[k].conj() * (vk - vj) * c64 { re: 0.0, im: 0.5 };
+            *z = even + odd;
+        }
+
+        self.plan.inv(z, stack);
+
+        // undo the reordering of the input of the DCT-II
+        for (k, z) in z.iter().enumerate() {
+            for (i, v) in [(2 * k, z.re), (2 * k + 1, z.im)] {
+                if i < m {
+                    buf[2 * i] = v;
+                } else {
+                    buf[2 * (n - i) - 1] = v;
+                }
+            }
+        }
+    }
+
+    /// Performs a DST-II in place, using the provided stack as scratch space.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `buf` is not equal to the transform size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::dct::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [1.0, -1.0, 1.0, -1.0];
+    /// plan.dst2(&mut buf, stack);
+    /// assert!((buf[3] - 4.0).abs() < 1e-12);
+    /// ```
+    pub fn dst2(&self, buf: &mut [f64], stack: PodStack) {
+        // the DST-II of `x` is the reversed DCT-II of `(-1)^j x_j`
+        for x in buf.iter_mut().skip(1).step_by(2) {
+            *x = -*x;
+        }
+        self.dct2(buf, stack);
+        buf.reverse();
+    }
+
+    /// Performs a DST-III in place, using the provided stack as scratch space.
+    ///
+    /// The DST-III is the inverse of the DST-II up to a factor, so that applying [`Plan::dst2`]
+    /// followed by [`Plan::dst3`] multiplies the vector by `n / 2`.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `buf` is not equal to the transform size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::dct::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer, ReborrowMut};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let mut stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [1.0, 2.0, 3.0, 4.0];
+    /// plan.dst2(&mut buf, stack.rb_mut());
+    /// plan.dst3(&mut buf, stack);
+    /// for (x, expected) in buf.iter().zip([2.0, 4.0, 6.0, 8.0]) {
+    ///     assert!((x - expected).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn dst3(&self, buf: &mut [f64], stack: PodStack) {
+        // the DST-III of `X` is `(-1)^j` times the DCT-III of the reversed `X`
+        buf.reverse();
+        self.dct3(buf, stack);
+        for x in buf.iter_mut().skip(1).step_by(2) {
+            *x = -*x;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ordered::FftAlgo;
+    use alloc::vec::Vec;
+    use core::f64::consts::PI;
+    use dyn_stack::{GlobalPodBuffer, ReborrowMut};
+    use rand::random;
+
+    extern crate alloc;
+
+    fn dct2_naive(x: &[f64]) -> Vec<f64> {
+        let n = x.len();
+        (0..n)
+            .map(|k| {
+                x.iter()
+                    .enumerate()
+                    .map(|(j, x)| x * (PI * ((2 * j + 1) * k) as f64 / (2 * n) as f64).cos())
+                    .sum()
+            })
+            .collect()
+    }
+
+    fn dct3_naive(x: &[f64]) -> Vec<f64> {
+        let n = x.len();
+        (0..n)
+            .map(|j| {
+                x[0] / 2.0
+                    + x.iter()
+                        .enumerate()
+                        .skip(1)
+                        .map(|(k, x)| x * (PI * ((2 * j + 1) * k) as f64 / (2 * n) as f64).cos())
+                        .sum::<f64>()
+            })
+            .collect()
+    }
+
+    fn dst2_naive(x: &[f64]) -> Vec<f64> {
+        let n = x.len();
+        (0..n)
+            .map(|k| {
+                x.iter()
+                    .enumerate()
+                    .map(|(j, x)| x * (PI * ((2 * j + 1) * (k + 1)) as f64 / (2 * n) as f64).sin())
+                    .sum()
+            })
+            .collect()
+    }
+
+    fn dst3_naive(x: &[f64]) -> Vec<f64> {
+        let n = x.len();
+        (0..n)
+            .map(|j| {
+                let sign = if j % 2 == 0 { 1.0 } else { -1.0 };
+                sign * x[n - 1] / 2.0
+                    + x[..n - 1]
+                        .iter()
+                        .enumerate()
+                        .map(|(k, x)| {
+                            x * (PI * ((2 * j + 1) * (k + 1)) as f64 / (2 * n) as f64).sin()
+                        })
+                        .sum::<f64>()
+            })
+            .collect()
+    }
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        let n = actual.len() as f64;
+        for (actual, expected) in actual.iter().zip(expected) {
+            assert!((actual - expected).abs() < 1e-12 * n);
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_transforms() {
+        type Transform = (fn(&Plan, &mut [f64], PodStack), fn(&[f64]) -> Vec<f64>);
+        let transforms: [Transform; 4] = [
+            (Plan::dct2, dct2_naive),
+            (Plan::dct3, dct3_naive),
+            (Plan::dst2, dst2_naive),
+            (Plan::dst3, dst3_naive),
+        ];
+
+        for exp in 1..=9 {
+            let n: usize = 1 << exp;
+            for algo in [FftAlgo::Dif2, FftAlgo::Dit4, FftAlgo::Dif8, FftAlgo::Dit16] {
+                let plan = Plan::new(n, Method::UserProvided(algo));
+                let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+                let mut stack = PodStack::new(&mut mem);
+
+                for (transform, naive) in transforms {
+                    let x: Vec<f64> = (0..n).map(|_| random::<f64>() - 0.5).collect();
+                    let mut y = x.clone();
+                    transform(&plan, &mut y, stack.rb_mut());
+                    assert_close(&y, &naive(&x));
+                }
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_roundtrip() {
+        for exp in 1..=14 {
+            let n: usize = 1 << exp;
+            let plan = Plan::new(n, Method::UserProvided(FftAlgo::Dif4));
+            let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+            let mut stack = PodStack::new(&mut mem);
+
+            let x: Vec<f64> = (0..n).map(|_| random::<f64>() - 0.5).collect();
+            let expected: Vec<f64> = x.iter().map(|x| x * (n / 2) as f64).collect();
+
+            let mut y = x.clone();
+            plan.dct2(&mut y, stack.rb_mut());
+            plan.dct3(&mut y, stack.rb_mut());
+            assert_close(&y, &expected);
+
+            let mut y = x.clone();
+            plan.dst2(&mut y, stack.rb_mut());
+            plan.dst3(&mut y, stack.rb_mut());
+            assert_close(&y, &expected);
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_invalid_size() {
+        let method = Method::UserProvided(FftAlgo::Dif4);
+        assert_eq!(
+            Plan::try_new(1, method).unwrap_err(),
+            PlanError::TooSmall { size: 1, min: 2 },
+        );
+        assert_eq!(
+            Plan::try_new(12, method).unwrap_err(),
+            PlanError::NotPowerOfTwo { size: 12 },
+        );
+    }
+}
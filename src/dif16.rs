@@ -1,5 +1,11 @@
+#[cfg(target_arch = "aarch64")]
+use crate::fft_simd::{c32x2, c64x1};
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::fft_simd::{c32x4, c64x2};
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx512"))]
+use crate::fft_simd::{c32x8, c64x4};
 use crate::{
-    c64,
+    c32, c64,
     dif2::{split_2, split_mut_2},
     dif8::{split_8, split_mut_8},
     fft_simd::{FftSimd, FftSimdExt, Pod},
@@ -75,7 +81,7 @@ fn stockham_core_1x2<c64xN: Pod>(
     x: &[c64xN],
     y: &mut [c64xN],
     w_init: &[c64xN],
-    _w: &[c64],
+    _w: &[c64xN::Lane],
 ) {
     assert_eq!(s, 1);
 
@@ -277,7 +283,7 @@ fn stockham_core_1x4<c64xN: Pod>(
     x: &[c64xN],
     y: &mut [c64xN],
     w_init: &[c64xN],
-    _w: &[c64],
+    _w: &[c64xN::Lane],
 ) {
     assert_eq!(s, 1);
 
@@ -445,6 +451,164 @@ fn stockham_core_1x4<c64xN: Pod>(
     }
 }
 
+#[inline(always)]
+fn stockham_core_1x8<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    fwd: bool,
+    s: usize,
+    x: &[c64xN],
+    y: &mut [c64xN],
+    w_init: &[c64xN],
+    _w: &[c64xN::Lane],
+) {
+    assert_eq!(s, 1);
+
+    let y = pulp::as_arrays_mut::<16, _>(y).0;
+    let (x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, xa, xb, xc, xd, xe, xf) = split_16(x);
+    let (_, w1, w2, w3, w4, w5, w6, w7, w8, w9, wa, wb, wc, wd, we, wf) = split_16(w_init);
+
+    for (
+        (x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, xa, xb, xc, xd, xe, xf),
+        y,
+        (w1, w2, w3, w4, w5, w6, w7, w8, w9, wa, wb, wc, wd, we, wf),
+    ) in izip!(
+        izip!(x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, xa, xb, xc, xd, xe, xf),
+        y,
+        izip!(w1, w2, w3, w4, w5, w6, w7, w8, w9, wa, wb, wc, wd, we, wf),
+    ) {
+        let x0 = *x0;
+        let x1 = *x1;
+        let x2 = *x2;
+        let x3 = *x3;
+        let x4 = *x4;
+        let x5 = *x5;
+        let x6 = *x6;
+        let x7 = *x7;
+        let x8 = *x8;
+        let x9 = *x9;
+        let xa = *xa;
+        let xb = *xb;
+        let xc = *xc;
+        let xd = *xd;
+        let xe = *xe;
+        let xf = *xf;
+
+        let a08 = simd.add(x0, x8);
+        let s08 = simd.sub(x0, x8);
+        let a4c = simd.add(x4, xc);
+        let s4c = simd.sub(x4, xc);
+        let a2a = simd.add(x2, xa);
+        let s2a = simd.sub(x2, xa);
+        let a6e = simd.add(x6, xe);
+        let s6e = simd.sub(x6, xe);
+        let a19 = simd.add(x1, x9);
+        let s19 = simd.sub(x1, x9);
+        let a5d = simd.add(x5, xd);
+        let s5d = simd.sub(x5, xd);
+        let a3b = simd.add(x3, xb);
+        let s3b = simd.sub(x3, xb);
+        let a7f = simd.add(x7, xf);
+        let s7f = simd.sub(x7, xf);
+
+        let js4c = simd.mul_j(fwd, s4c);
+        let js6e = simd.mul_j(fwd, s6e);
+        let js5d = simd.mul_j(fwd, s5d);
+        let js7f = simd.mul_j(fwd, s7f);
+
+        let a08p1a4c = simd.add(a08, a4c);
+        let s08mjs4c = simd.sub(s08, js4c);
+        let a08m1a4c = simd.sub(a08, a4c);
+        let s08pjs4c = simd.add(s08, js4c);
+        let a2ap1a6e = simd.add(a2a, a6e);
+        let s2amjs6e = simd.sub(s2a, js6e);
+        let a2am1a6e = simd.sub(a2a, a6e);
+        let s2apjs6e = simd.add(s2a, js6e);
+        let a19p1a5d = simd.add(a19, a5d);
+        let s19mjs5d = simd.sub(s19, js5d);
+        let a19m1a5d = simd.sub(a19, a5d);
+        let s19pjs5d = simd.add(s19, js5d);
+        let a3bp1a7f = simd.add(a3b, a7f);
+        let s3bmjs7f = simd.sub(s3b, js7f);
+        let a3bm1a7f = simd.sub(a3b, a7f);
+        let s3bpjs7f = simd.add(s3b, js7f);
+
+        let w8_s2amjs6e = simd.mul_exp_neg_pi_over_8(fwd, s2amjs6e);
+        let j_a2am1a6e = simd.mul_j(fwd, a2am1a6e);
+        let v8_s2apjs6e = simd.mul_exp_pi_over_8(fwd, s2apjs6e);
+
+        let a08p1a4c_p1_a2ap1a6e = simd.add(a08p1a4c, a2ap1a6e);
+        let s08mjs4c_pw_s2amjs6e = simd.add(s08mjs4c, w8_s2amjs6e);
+        let a08m1a4c_mj_a2am1a6e = simd.sub(a08m1a4c, j_a2am1a6e);
+        let s08pjs4c_mv_s2apjs6e = simd.sub(s08pjs4c, v8_s2apjs6e);
+        let a08p1a4c_m1_a2ap1a6e = simd.sub(a08p1a4c, a2ap1a6e);
+        let s08mjs4c_mw_s2amjs6e = simd.sub(s08mjs4c, w8_s2amjs6e);
+        let a08m1a4c_pj_a2am1a6e = simd.add(a08m1a4c, j_a2am1a6e);
+        let s08pjs4c_pv_s2apjs6e = simd.add(s08pjs4c, v8_s2apjs6e);
+
+        let w8_s3bmjs7f = simd.mul_exp_neg_pi_over_8(fwd, s3bmjs7f);
+        let j_a3bm1a7f = simd.mul_j(fwd, a3bm1a7f);
+        let v8_s3bpjs7f = simd.mul_exp_pi_over_8(fwd, s3bpjs7f);
+
+        let a19p1a5d_p1_a3bp1a7f = simd.add(a19p1a5d, a3bp1a7f);
+        let s19mjs5d_pw_s3bmjs7f = simd.add(s19mjs5d, w8_s3bmjs7f);
+        let a19m1a5d_mj_a3bm1a7f = simd.sub(a19m1a5d, j_a3bm1a7f);
+        let s19pjs5d_mv_s3bpjs7f = simd.sub(s19pjs5d, v8_s3bpjs7f);
+        let a19p1a5d_m1_a3bp1a7f = simd.sub(a19p1a5d, a3bp1a7f);
+        let s19mjs5d_mw_s3bmjs7f = simd.sub(s19mjs5d, w8_s3bmjs7f);
+        let a19m1a5d_pj_a3bm1a7f = simd.add(a19m1a5d, j_a3bm1a7f);
+        let s19pjs5d_pv_s3bpjs7f = simd.add(s19pjs5d, v8_s3bpjs7f);
+
+        let h1_s19mjs5d_pw_s3bmjs7f = simd.mul_exp_pi_over_16(fwd, s19mjs5d_pw_s3bmjs7f);
+        let w8_a19m1a5d_mj_a3bm1a7f = simd.mul_exp_neg_pi_over_8(fwd, a19m1a5d_mj_a3bm1a7f);
+        let h3_s19pjs5d_mv_s3bpjs7f = simd.mul_exp_17pi_over_16(fwd, s19pjs5d_mv_s3bpjs7f);
+        let j_a19p1a5d_m1_a3bp1a7f = simd.mul_j(fwd, a19p1a5d_m1_a3bp1a7f);
+        let hd_s19mjs5d_mw_s3bmjs7f = simd.mul_exp_neg_17pi_over_16(fwd, s19mjs5d_mw_s3bmjs7f);
+        let v8_a19m1a5d_pj_a3bm1a7f = simd.mul_exp_pi_over_8(fwd, a19m1a5d_pj_a3bm1a7f);
+        let hf_s19pjs5d_pv_s3bpjs7f = simd.mul_exp_neg_pi_over_16(fwd, s19pjs5d_pv_s3bpjs7f);
+
+        let w1 = *w1;
+        let w2 = *w2;
+        let w3 = *w3;
+        let w4 = *w4;
+        let w5 = *w5;
+        let w6 = *w6;
+        let w7 = *w7;
+        let w8 = *w8;
+        let w9 = *w9;
+        let wa = *wa;
+        let wb = *wb;
+        let wc = *wc;
+        let wd = *wd;
+        let we = *we;
+        let wf = *wf;
+
+        let a_ = simd.add(a08p1a4c_p1_a2ap1a6e, a19p1a5d_p1_a3bp1a7f);
+        let b_ = simd.mul(w1, simd.add(s08mjs4c_pw_s2amjs6e, h1_s19mjs5d_pw_s3bmjs7f));
+        let c_ = simd.mul(w2, simd.add(a08m1a4c_mj_a2am1a6e, w8_a19m1a5d_mj_a3bm1a7f));
+        let d_ = simd.mul(w3, simd.add(s08pjs4c_mv_s2apjs6e, h3_s19pjs5d_mv_s3bpjs7f));
+        let e_ = simd.mul(w4, simd.sub(a08p1a4c_m1_a2ap1a6e, j_a19p1a5d_m1_a3bp1a7f));
+        let f_ = simd.mul(w5, simd.sub(s08mjs4c_mw_s2amjs6e, hd_s19mjs5d_mw_s3bmjs7f));
+        let g_ = simd.mul(w6, simd.sub(a08m1a4c_pj_a2am1a6e, v8_a19m1a5d_pj_a3bm1a7f));
+        let h_ = simd.mul(w7, simd.sub(s08pjs4c_pv_s2apjs6e, hf_s19pjs5d_pv_s3bpjs7f));
+
+        let i_ = simd.mul(w8, simd.sub(a08p1a4c_p1_a2ap1a6e, a19p1a5d_p1_a3bp1a7f));
+        let j_ = simd.mul(w9, simd.sub(s08mjs4c_pw_s2amjs6e, h1_s19mjs5d_pw_s3bmjs7f));
+        let k_ = simd.mul(wa, simd.sub(a08m1a4c_mj_a2am1a6e, w8_a19m1a5d_mj_a3bm1a7f));
+        let l_ = simd.mul(wb, simd.sub(s08pjs4c_mv_s2apjs6e, h3_s19pjs5d_mv_s3bpjs7f));
+        let m_ = simd.mul(wc, simd.add(a08p1a4c_m1_a2ap1a6e, j_a19p1a5d_m1_a3bp1a7f));
+        let n_ = simd.mul(wd, simd.add(s08mjs4c_mw_s2amjs6e, hd_s19mjs5d_mw_s3bmjs7f));
+        let o_ = simd.mul(we, simd.add(a08m1a4c_pj_a2am1a6e, v8_a19m1a5d_pj_a3bm1a7f));
+        let p_ = simd.mul(wf, simd.add(s08pjs4c_pv_s2apjs6e, hf_s19pjs5d_pv_s3bpjs7f));
+
+        let [a0, a1, a2, a3, a4, a5, a6, a7] = simd.transpose_8x8([a_, b_, c_, d_, e_, f_, g_, h_]);
+        let [i0, i1, i2, i3, i4, i5, i6, i7] = simd.transpose_8x8([i_, j_, k_, l_, m_, n_, o_, p_]);
+
+        *y = [
+            a0, i0, a1, i1, a2, i2, a3, i3, a4, i4, a5, i5, a6, i6, a7, i7,
+        ];
+    }
+}
+
 #[inline(always)]
 fn stockham_core_generic<c64xN: Pod>(
     simd: impl FftSimd<c64xN>,
@@ -453,7 +617,7 @@ fn stockham_core_generic<c64xN: Pod>(
     x: &[c64xN],
     y: &mut [c64xN],
     _w_init: &[c64xN],
-    w: &[c64],
+    w: &[c64xN::Lane],
 ) {
     assert_eq!(s % simd.lane_count(), 0);
     let simd_s = s / simd.lane_count();
@@ -630,7 +794,7 @@ fn stockham_core<c64xN: Pod>(
     x: &[c64xN],
     y: &mut [c64xN],
     w_init: &[c64xN],
-    w: &[c64],
+    w: &[c64xN::Lane],
 ) {
     // we create a fn pointer that will be force-inlined in release builds
     // but not in debug builds. this helps keep compile times low, since dead code
@@ -640,6 +804,8 @@ fn stockham_core<c64xN: Pod>(
         stockham_core_1x2
     } else if s == 1 && simd.lane_count() == 4 {
         stockham_core_1x4
+    } else if s == 1 && simd.lane_count() == 8 {
+        stockham_core_1x8
     } else {
         stockham_core_generic
     };
@@ -786,8 +952,44 @@ pub fn stockham_dif16_end<c64xN: Pod>(
     x: &mut [c64xN],
     y: &mut [c64xN],
 ) {
+    if !write_to_x {
+        stockham_dif16_end_out_of_place(simd, fwd, s, x, y);
+        return;
+    }
+
     assert_eq!(s % simd.lane_count(), 0);
     let (x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, xa, xb, xc, xd, xe, xf) = split_mut_16(x);
+
+    // we create a fn pointer that will be force-inlined in release builds
+    // but not in debug builds. this helps keep compile times low, since dead code
+    // elimination handles this well in release builds. and the function pointer indirection
+    // prevents inlining in debug builds.
+    let last_butterfly: fn(_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _) -> _ =
+        last_butterfly;
+
+    for (x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, xa, xb, xc, xd, xe, xf) in
+        izip!(x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, xa, xb, xc, xd, xe, xf)
+    {
+        (
+            *x0, *x1, *x2, *x3, *x4, *x5, *x6, *x7, *x8, *x9, *xa, *xb, *xc, *xd, *xe, *xf,
+        ) = last_butterfly(
+            simd, fwd, *x0, *x1, *x2, *x3, *x4, *x5, *x6, *x7, *x8, *x9, *xa, *xb, *xc, *xd, *xe,
+            *xf,
+        );
+    }
+}
+
+/// Out-of-place version of [`stockham_dif16_end`], reading from `x` and writing to `y`.
+#[inline(always)]
+pub fn stockham_dif16_end_out_of_place<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    fwd: bool,
+    s: usize,
+    x: &[c64xN],
+    y: &mut [c64xN],
+) {
+    assert_eq!(s % simd.lane_count(), 0);
+    let (x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, xa, xb, xc, xd, xe, xf) = split_16(x);
     let (y0, y1, y2, y3, y4, y5, y6, y7, y8, y9, ya, yb, yc, yd, ye, yf) = split_mut_16(y);
 
     // we create a fn pointer that will be force-inlined in release builds
@@ -797,32 +999,19 @@ pub fn stockham_dif16_end<c64xN: Pod>(
     let last_butterfly: fn(_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _) -> _ =
         last_butterfly;
 
-    if write_to_x {
-        for (x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, xa, xb, xc, xd, xe, xf) in
-            izip!(x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, xa, xb, xc, xd, xe, xf)
-        {
-            (
-                *x0, *x1, *x2, *x3, *x4, *x5, *x6, *x7, *x8, *x9, *xa, *xb, *xc, *xd, *xe, *xf,
-            ) = last_butterfly(
-                simd, fwd, *x0, *x1, *x2, *x3, *x4, *x5, *x6, *x7, *x8, *x9, *xa, *xb, *xc, *xd,
-                *xe, *xf,
-            );
-        }
-    } else {
-        for (
-            (x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, xa, xb, xc, xd, xe, xf),
-            (y0, y1, y2, y3, y4, y5, y6, y7, y8, y9, ya, yb, yc, yd, ye, yf),
-        ) in izip!(
-            izip!(x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, xa, xb, xc, xd, xe, xf),
-            izip!(y0, y1, y2, y3, y4, y5, y6, y7, y8, y9, ya, yb, yc, yd, ye, yf),
-        ) {
-            (
-                *y0, *y1, *y2, *y3, *y4, *y5, *y6, *y7, *y8, *y9, *ya, *yb, *yc, *yd, *ye, *yf,
-            ) = last_butterfly(
-                simd, fwd, *x0, *x1, *x2, *x3, *x4, *x5, *x6, *x7, *x8, *x9, *xa, *xb, *xc, *xd,
-                *xe, *xf,
-            );
-        }
+    for (
+        (x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, xa, xb, xc, xd, xe, xf),
+        (y0, y1, y2, y3, y4, y5, y6, y7, y8, y9, ya, yb, yc, yd, ye, yf),
+    ) in izip!(
+        izip!(x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, xa, xb, xc, xd, xe, xf),
+        izip!(y0, y1, y2, y3, y4, y5, y6, y7, y8, y9, ya, yb, yc, yd, ye, yf),
+    ) {
+        (
+            *y0, *y1, *y2, *y3, *y4, *y5, *y6, *y7, *y8, *y9, *ya, *yb, *yc, *yd, *ye, *yf,
+        ) = last_butterfly(
+            simd, fwd, *x0, *x1, *x2, *x3, *x4, *x5, *x6, *x7, *x8, *x9, *xa, *xb, *xc, *xd, *xe,
+            *xf,
+        );
     }
 }
 
@@ -842,10 +1031,24 @@ impl RecursiveFft for Dif16<nat::N0> {
         x: &mut [c64xN],
         y: &mut [c64xN],
         _w_init: &[c64xN],
-        _w: &[c64],
+        _w: &[c64xN::Lane],
     ) {
         crate::dif2::stockham_dif2_end(simd, fwd, write_to_x, s, x, y);
     }
+
+    #[inline(always)]
+    fn fft_recurse_out_of_place_impl<c64xN: Pod>(
+        simd: impl FftSimd<c64xN>,
+        fwd: bool,
+        s: usize,
+        src: &[c64xN],
+        x: &mut [c64xN],
+        _y: &mut [c64xN],
+        _w_init: &[c64xN],
+        _w: &[c64xN::Lane],
+    ) {
+        crate::dif2::stockham_dif2_end_out_of_place(simd, fwd, s, src, x);
+    }
 }
 
 // size 4
@@ -859,10 +1062,24 @@ impl RecursiveFft for Dif16<nat::N1> {
         x: &mut [c64xN],
         y: &mut [c64xN],
         _w_init: &[c64xN],
-        _w: &[c64],
+        _w: &[c64xN::Lane],
     ) {
         crate::dif4::stockham_dif4_end(simd, fwd, write_to_x, s, x, y);
     }
+
+    #[inline(always)]
+    fn fft_recurse_out_of_place_impl<c64xN: Pod>(
+        simd: impl FftSimd<c64xN>,
+        fwd: bool,
+        s: usize,
+        src: &[c64xN],
+        x: &mut [c64xN],
+        _y: &mut [c64xN],
+        _w_init: &[c64xN],
+        _w: &[c64xN::Lane],
+    ) {
+        crate::dif4::stockham_dif4_end_out_of_place(simd, fwd, s, src, x);
+    }
 }
 
 // size 8
@@ -876,10 +1093,24 @@ impl RecursiveFft for Dif16<nat::N2> {
         x: &mut [c64xN],
         y: &mut [c64xN],
         _w_init: &[c64xN],
-        _w: &[c64],
+        _w: &[c64xN::Lane],
     ) {
         crate::dif8::stockham_dif8_end(simd, fwd, write_to_x, s, x, y);
     }
+
+    #[inline(always)]
+    fn fft_recurse_out_of_place_impl<c64xN: Pod>(
+        simd: impl FftSimd<c64xN>,
+        fwd: bool,
+        s: usize,
+        src: &[c64xN],
+        x: &mut [c64xN],
+        _y: &mut [c64xN],
+        _w_init: &[c64xN],
+        _w: &[c64xN::Lane],
+    ) {
+        crate::dif8::stockham_dif8_end_out_of_place(simd, fwd, s, src, x);
+    }
 }
 
 // size 16
@@ -893,10 +1124,24 @@ impl RecursiveFft for Dif16<nat::N3> {
         x: &mut [c64xN],
         y: &mut [c64xN],
         _w_init: &[c64xN],
-        _w: &[c64],
+        _w: &[c64xN::Lane],
     ) {
         stockham_dif16_end(simd, fwd, write_to_x, s, x, y);
     }
+
+    #[inline(always)]
+    fn fft_recurse_out_of_place_impl<c64xN: Pod>(
+        simd: impl FftSimd<c64xN>,
+        fwd: bool,
+        s: usize,
+        src: &[c64xN],
+        x: &mut [c64xN],
+        _y: &mut [c64xN],
+        _w_init: &[c64xN],
+        _w: &[c64xN::Lane],
+    ) {
+        stockham_dif16_end_out_of_place(simd, fwd, s, src, x);
+    }
 }
 
 impl<N: nat::Nat> RecursiveFft for Dif16<nat::Plus4<N>>
@@ -912,14 +1157,29 @@ where
         x: &mut [c64xN],
         y: &mut [c64xN],
         w_init: &[c64xN],
-        w: &[c64],
+        w: &[c64xN::Lane],
     ) {
         stockham_core(simd, fwd, s, x, y, w_init, w);
         Dif16::<N>::fft_recurse_impl(simd, fwd, !write_to_x, s * 16, y, x, w_init, w);
     }
+
+    #[inline(always)]
+    fn fft_recurse_out_of_place_impl<c64xN: Pod>(
+        simd: impl FftSimd<c64xN>,
+        fwd: bool,
+        s: usize,
+        src: &[c64xN],
+        x: &mut [c64xN],
+        y: &mut [c64xN],
+        w_init: &[c64xN],
+        w: &[c64xN::Lane],
+    ) {
+        stockham_core(simd, fwd, s, src, y, w_init, w);
+        Dif16::<N>::fft_recurse_impl(simd, fwd, false, s * 16, y, x, w_init, w);
+    }
 }
 
-pub(crate) fn fft_impl<c64xN: Pod>(simd: impl FftSimd<c64xN>) -> crate::FftImpl {
+pub(crate) fn fft_impl<c64xN: Pod>(simd: impl FftSimd<c64xN>) -> crate::FftImpl<c64xN::Lane> {
     let fwd = [
         fn_ptr::<true, Dif16<nat::N0>, _, _>(simd),
         fn_ptr::<true, Dif16<nat::N1>, _, _>(simd),
@@ -947,20 +1207,68 @@ pub(crate) fn fft_impl<c64xN: Pod>(simd: impl FftSimd<c64xN>) -> crate::FftImpl
     crate::FftImpl { fwd, inv }
 }
 
-pub fn fft_impl_dispatch(n: usize) -> [fn(&mut [c64], &mut [c64], &[c64], &[c64]); 2] {
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")),
+    allow(unused_variables)
+)]
+pub fn fft_impl_dispatch(
+    n: usize,
+    level: crate::SimdLevel,
+) -> [fn(Option<&[c64]>, &mut [c64], &mut [c64], &[c64], &[c64]); 2] {
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     {
-        #[cfg(feature = "nightly")]
-        if let Some(simd) = pulp::x86::V4::try_new() {
-            if n >= 16 * simd.lane_count() {
-                return fft_impl(simd).make_fn_ptr(n);
+        #[cfg(feature = "avx512")]
+        if let Some(simd) = level.v4() {
+            if n >= 16 * FftSimd::<c64x4>::lane_count(simd) {
+                return fft_impl::<c64x4>(simd).make_fn_ptr(n);
+            }
+        }
+        if let Some(simd) = level.v3() {
+            if n >= 16 * FftSimd::<c64x2>::lane_count(simd) {
+                return fft_impl::<c64x2>(simd).make_fn_ptr(n);
             }
         }
-        if let Some(simd) = pulp::x86::V3::try_new() {
-            if n >= 16 * simd.lane_count() {
-                return fft_impl(simd).make_fn_ptr(n);
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if let Some(simd) = level.neon() {
+            if n >= 16 * FftSimd::<c64x1>::lane_count(simd) {
+                return fft_impl::<c64x1>(simd).make_fn_ptr(n);
+            }
+        }
+    }
+    fft_impl::<c64>(crate::fft_simd::Scalar).make_fn_ptr(n)
+}
+
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")),
+    allow(unused_variables)
+)]
+pub fn fft_impl_dispatch_c32(
+    n: usize,
+    level: crate::SimdLevel,
+) -> [fn(Option<&[c32]>, &mut [c32], &mut [c32], &[c32], &[c32]); 2] {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        #[cfg(feature = "avx512")]
+        if let Some(simd) = level.v4() {
+            if n >= 16 * FftSimd::<c32x8>::lane_count(simd) {
+                return fft_impl::<c32x8>(simd).make_fn_ptr(n);
+            }
+        }
+        if let Some(simd) = level.v3() {
+            if n >= 16 * FftSimd::<c32x4>::lane_count(simd) {
+                return fft_impl::<c32x4>(simd).make_fn_ptr(n);
+            }
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if let Some(simd) = level.neon() {
+            if n >= 16 * FftSimd::<c32x2>::lane_count(simd) {
+                return fft_impl::<c32x2>(simd).make_fn_ptr(n);
             }
         }
     }
-    fft_impl(crate::fft_simd::Scalar).make_fn_ptr(n)
+    fft_impl::<c32>(crate::fft_simd::Scalar).make_fn_ptr(n)
 }
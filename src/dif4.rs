@@ -1,5 +1,11 @@
+#[cfg(target_arch = "aarch64")]
+use crate::fft_simd::{c32x2, c64x1};
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::fft_simd::{c32x4, c64x2};
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx512"))]
+use crate::fft_simd::{c32x8, c64x4};
 use crate::{
-    c64,
+    c32, c64,
     dif2::{split_2, split_mut_2},
     fft_simd::{FftSimd, FftSimdExt, Pod},
     fn_ptr, nat, RecursiveFft,
@@ -21,14 +27,14 @@ pub fn split_mut_4<T>(slice: &mut [T]) -> (&mut [T], &mut [T], &mut [T], &mut [T
 }
 
 #[inline(always)]
-fn stockham_core_1x2<c64xN: Pod>(
+pub(crate) fn stockham_core_1x2<c64xN: Pod>(
     simd: impl FftSimd<c64xN>,
     fwd: bool,
     s: usize,
     x: &[c64xN],
     y: &mut [c64xN],
     w_init: &[c64xN],
-    _w: &[c64],
+    _w: &[c64xN::Lane],
 ) {
     assert_eq!(s, 1);
 
@@ -70,14 +76,14 @@ fn stockham_core_1x2<c64xN: Pod>(
 }
 
 #[inline(always)]
-fn stockham_core_1x4<c64xN: Pod>(
+pub(crate) fn stockham_core_1x4<c64xN: Pod>(
     simd: impl FftSimd<c64xN>,
     fwd: bool,
     s: usize,
     x: &[c64xN],
     y: &mut [c64xN],
     w_init: &[c64xN],
-    _w: &[c64],
+    _w: &[c64xN::Lane],
 ) {
     assert_eq!(s, 1);
 
@@ -114,6 +120,55 @@ fn stockham_core_1x4<c64xN: Pod>(
     }
 }
 
+#[inline(always)]
+fn stockham_core_4x8<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    fwd: bool,
+    s: usize,
+    x: &[c64xN],
+    y: &mut [c64xN],
+    _w_init: &[c64xN],
+    w: &[c64xN::Lane],
+) {
+    assert_eq!(s, 4);
+
+    let w = pulp::as_arrays::<4, _>(w).0;
+
+    let y = pulp::as_arrays_mut::<4, _>(y).0;
+    let (x0, x1, x2, x3) = split_4(x);
+
+    for (x0, x1, x2, x3, y, w) in izip!(x0, x1, x2, x3, y, w.chunks_exact(8)) {
+        // the low and high halves of the registers belong to consecutive butterflies
+        let [_, w1_lo, w2_lo, w3_lo] = w[0];
+        let [_, w1_hi, w2_hi, w3_hi] = w[4];
+
+        let w1 = simd.catlo(simd.splat(w1_lo), simd.splat(w1_hi));
+        let w2 = simd.catlo(simd.splat(w2_lo), simd.splat(w2_hi));
+        let w3 = simd.catlo(simd.splat(w3_lo), simd.splat(w3_hi));
+
+        let a = *x0;
+        let b = *x1;
+        let c = *x2;
+        let d = *x3;
+
+        let apc = simd.add(a, c);
+        let amc = simd.sub(a, c);
+
+        let bpd = simd.add(b, d);
+        let jbmd = simd.mul_j(fwd, simd.sub(b, d));
+
+        let aa = simd.add(apc, bpd);
+        let bb = simd.mul(w1, simd.sub(amc, jbmd));
+        let cc = simd.mul(w2, simd.sub(apc, bpd));
+        let dd = simd.mul(w3, simd.add(amc, jbmd));
+
+        y[0] = simd.catlo(aa, bb);
+        y[1] = simd.catlo(cc, dd);
+        y[2] = simd.cathi(aa, bb);
+        y[3] = simd.cathi(cc, dd);
+    }
+}
+
 #[inline(always)]
 fn stockham_core_generic<c64xN: Pod>(
     simd: impl FftSimd<c64xN>,
@@ -122,7 +177,7 @@ fn stockham_core_generic<c64xN: Pod>(
     x: &[c64xN],
     y: &mut [c64xN],
     _w_init: &[c64xN],
-    w: &[c64],
+    w: &[c64xN::Lane],
 ) {
     assert_eq!(s % simd.lane_count(), 0);
     let simd_s = s / simd.lane_count();
@@ -175,7 +230,7 @@ fn stockham_core<c64xN: Pod>(
     x: &[c64xN],
     y: &mut [c64xN],
     w_init: &[c64xN],
-    w: &[c64],
+    w: &[c64xN::Lane],
 ) {
     // we create a fn pointer that will be force-inlined in release builds
     // but not in debug builds. this helps keep compile times low, since dead code
@@ -183,8 +238,10 @@ fn stockham_core<c64xN: Pod>(
     // prevents inlining in debug builds.
     let stockham = if s == 1 && simd.lane_count() == 2 {
         stockham_core_1x2
-    } else if s == 1 && simd.lane_count() == 4 {
+    } else if s == 1 && (simd.lane_count() == 4 || simd.lane_count() == 8) {
         stockham_core_1x4
+    } else if s == 4 && simd.lane_count() == 8 {
+        stockham_core_4x8
     } else {
         stockham_core_generic
     };
@@ -222,8 +279,36 @@ pub fn stockham_dif4_end<c64xN: Pod>(
     x: &mut [c64xN],
     y: &mut [c64xN],
 ) {
+    if !write_to_x {
+        stockham_dif4_end_out_of_place(simd, fwd, s, x, y);
+        return;
+    }
+
     assert_eq!(s % simd.lane_count(), 0);
     let (x0, x1, x2, x3) = split_mut_4(x);
+
+    // we create a fn pointer that will be force-inlined in release builds
+    // but not in debug builds. this helps keep compile times low, since dead code
+    // elimination handles this well in release builds. and the function pointer indirection
+    // prevents inlining in debug builds.
+    let last_butterfly: fn(_, _, _, _, _, _) -> _ = last_butterfly;
+
+    for (x0, x1, x2, x3) in izip!(x0, x1, x2, x3) {
+        (*x0, *x1, *x2, *x3) = last_butterfly(simd, fwd, *x0, *x1, *x2, *x3);
+    }
+}
+
+/// Out-of-place version of [`stockham_dif4_end`], reading from `x` and writing to `y`.
+#[inline(always)]
+pub fn stockham_dif4_end_out_of_place<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    fwd: bool,
+    s: usize,
+    x: &[c64xN],
+    y: &mut [c64xN],
+) {
+    assert_eq!(s % simd.lane_count(), 0);
+    let (x0, x1, x2, x3) = split_4(x);
     let (y0, y1, y2, y3) = split_mut_4(y);
 
     // we create a fn pointer that will be force-inlined in release builds
@@ -232,14 +317,8 @@ pub fn stockham_dif4_end<c64xN: Pod>(
     // prevents inlining in debug builds.
     let last_butterfly: fn(_, _, _, _, _, _) -> _ = last_butterfly;
 
-    if write_to_x {
-        for (x0, x1, x2, x3) in izip!(x0, x1, x2, x3) {
-            (*x0, *x1, *x2, *x3) = last_butterfly(simd, fwd, *x0, *x1, *x2, *x3);
-        }
-    } else {
-        for (x0, x1, x2, x3, y0, y1, y2, y3) in izip!(x0, x1, x2, x3, y0, y1, y2, y3) {
-            (*y0, *y1, *y2, *y3) = last_butterfly(simd, fwd, *x0, *x1, *x2, *x3);
-        }
+    for (x0, x1, x2, x3, y0, y1, y2, y3) in izip!(x0, x1, x2, x3, y0, y1, y2, y3) {
+        (*y0, *y1, *y2, *y3) = last_butterfly(simd, fwd, *x0, *x1, *x2, *x3);
     }
 }
 
@@ -259,10 +338,24 @@ impl RecursiveFft for Dif4<nat::N0> {
         x: &mut [c64xN],
         y: &mut [c64xN],
         _w_init: &[c64xN],
-        _w: &[c64],
+        _w: &[c64xN::Lane],
     ) {
         crate::dif2::stockham_dif2_end(simd, fwd, write_to_x, s, x, y);
     }
+
+    #[inline(always)]
+    fn fft_recurse_out_of_place_impl<c64xN: Pod>(
+        simd: impl FftSimd<c64xN>,
+        fwd: bool,
+        s: usize,
+        src: &[c64xN],
+        x: &mut [c64xN],
+        _y: &mut [c64xN],
+        _w_init: &[c64xN],
+        _w: &[c64xN::Lane],
+    ) {
+        crate::dif2::stockham_dif2_end_out_of_place(simd, fwd, s, src, x);
+    }
 }
 
 // size 4
@@ -276,10 +369,24 @@ impl RecursiveFft for Dif4<nat::N1> {
         x: &mut [c64xN],
         y: &mut [c64xN],
         _w_init: &[c64xN],
-        _w: &[c64],
+        _w: &[c64xN::Lane],
     ) {
         stockham_dif4_end(simd, fwd, write_to_x, s, x, y);
     }
+
+    #[inline(always)]
+    fn fft_recurse_out_of_place_impl<c64xN: Pod>(
+        simd: impl FftSimd<c64xN>,
+        fwd: bool,
+        s: usize,
+        src: &[c64xN],
+        x: &mut [c64xN],
+        _y: &mut [c64xN],
+        _w_init: &[c64xN],
+        _w: &[c64xN::Lane],
+    ) {
+        stockham_dif4_end_out_of_place(simd, fwd, s, src, x);
+    }
 }
 
 impl<N: nat::Nat> RecursiveFft for Dif4<nat::Plus2<N>>
@@ -295,14 +402,29 @@ where
         x: &mut [c64xN],
         y: &mut [c64xN],
         w_init: &[c64xN],
-        w: &[c64],
+        w: &[c64xN::Lane],
     ) {
         stockham_core(simd, fwd, s, x, y, w_init, w);
         Dif4::<N>::fft_recurse_impl(simd, fwd, !write_to_x, s * 4, y, x, w_init, w);
     }
+
+    #[inline(always)]
+    fn fft_recurse_out_of_place_impl<c64xN: Pod>(
+        simd: impl FftSimd<c64xN>,
+        fwd: bool,
+        s: usize,
+        src: &[c64xN],
+        x: &mut [c64xN],
+        y: &mut [c64xN],
+        w_init: &[c64xN],
+        w: &[c64xN::Lane],
+    ) {
+        stockham_core(simd, fwd, s, src, y, w_init, w);
+        Dif4::<N>::fft_recurse_impl(simd, fwd, false, s * 4, y, x, w_init, w);
+    }
 }
 
-pub(crate) fn fft_impl<c64xN: Pod>(simd: impl FftSimd<c64xN>) -> crate::FftImpl {
+pub(crate) fn fft_impl<c64xN: Pod>(simd: impl FftSimd<c64xN>) -> crate::FftImpl<c64xN::Lane> {
     let fwd = [
         fn_ptr::<true, Dif4<nat::N0>, _, _>(simd),
         fn_ptr::<true, Dif4<nat::N1>, _, _>(simd),
@@ -330,20 +452,68 @@ pub(crate) fn fft_impl<c64xN: Pod>(simd: impl FftSimd<c64xN>) -> crate::FftImpl
     crate::FftImpl { fwd, inv }
 }
 
-pub fn fft_impl_dispatch(n: usize) -> [fn(&mut [c64], &mut [c64], &[c64], &[c64]); 2] {
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")),
+    allow(unused_variables)
+)]
+pub fn fft_impl_dispatch(
+    n: usize,
+    level: crate::SimdLevel,
+) -> [fn(Option<&[c64]>, &mut [c64], &mut [c64], &[c64], &[c64]); 2] {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        #[cfg(feature = "avx512")]
+        if let Some(simd) = level.v4() {
+            if n >= 4 * FftSimd::<c64x4>::lane_count(simd) {
+                return fft_impl::<c64x4>(simd).make_fn_ptr(n);
+            }
+        }
+        if let Some(simd) = level.v3() {
+            if n >= 4 * FftSimd::<c64x2>::lane_count(simd) {
+                return fft_impl::<c64x2>(simd).make_fn_ptr(n);
+            }
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if let Some(simd) = level.neon() {
+            if n >= 4 * FftSimd::<c64x1>::lane_count(simd) {
+                return fft_impl::<c64x1>(simd).make_fn_ptr(n);
+            }
+        }
+    }
+    fft_impl::<c64>(crate::fft_simd::Scalar).make_fn_ptr(n)
+}
+
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")),
+    allow(unused_variables)
+)]
+pub fn fft_impl_dispatch_c32(
+    n: usize,
+    level: crate::SimdLevel,
+) -> [fn(Option<&[c32]>, &mut [c32], &mut [c32], &[c32], &[c32]); 2] {
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     {
-        #[cfg(feature = "nightly")]
-        if let Some(simd) = pulp::x86::V4::try_new() {
-            if n >= 4 * simd.lane_count() {
-                return fft_impl(simd).make_fn_ptr(n);
+        #[cfg(feature = "avx512")]
+        if let Some(simd) = level.v4() {
+            if n >= 4 * FftSimd::<c32x8>::lane_count(simd) {
+                return fft_impl::<c32x8>(simd).make_fn_ptr(n);
+            }
+        }
+        if let Some(simd) = level.v3() {
+            if n >= 4 * FftSimd::<c32x4>::lane_count(simd) {
+                return fft_impl::<c32x4>(simd).make_fn_ptr(n);
             }
         }
-        if let Some(simd) = pulp::x86::V3::try_new() {
-            if n >= 4 * simd.lane_count() {
-                return fft_impl(simd).make_fn_ptr(n);
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if let Some(simd) = level.neon() {
+            if n >= 4 * FftSimd::<c32x2>::lane_count(simd) {
+                return fft_impl::<c32x2>(simd).make_fn_ptr(n);
             }
         }
     }
-    fft_impl(crate::fft_simd::Scalar).make_fn_ptr(n)
+    fft_impl::<c32>(crate::fft_simd::Scalar).make_fn_ptr(n)
 }
@@ -1,5 +1,11 @@
+#[cfg(target_arch = "aarch64")]
+use crate::fft_simd::{c32x2, c64x1};
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::fft_simd::{c32x4, c64x2};
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx512"))]
+use crate::fft_simd::{c32x8, c64x4};
 use crate::{
-    c64,
+    c32, c64,
     dif2::{split_2, split_mut_2},
     dif4::{split_4, split_mut_4},
     fft_simd::{FftSimd, FftSimdExt, Pod},
@@ -44,7 +50,7 @@ fn stockham_core_1x2<c64xN: Pod>(
     x: &[c64xN],
     y: &mut [c64xN],
     w_init: &[c64xN],
-    _w: &[c64],
+    _w: &[c64xN::Lane],
 ) {
     assert_eq!(s, 1);
 
@@ -129,7 +135,7 @@ fn stockham_core_1x4<c64xN: Pod>(
     x: &[c64xN],
     y: &mut [c64xN],
     w_init: &[c64xN],
-    _w: &[c64],
+    _w: &[c64xN::Lane],
 ) {
     assert_eq!(s, 1);
 
@@ -200,6 +206,75 @@ fn stockham_core_1x4<c64xN: Pod>(
     }
 }
 
+#[inline(always)]
+fn stockham_core_1x8<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    fwd: bool,
+    s: usize,
+    x: &[c64xN],
+    y: &mut [c64xN],
+    w_init: &[c64xN],
+    _w: &[c64xN::Lane],
+) {
+    assert_eq!(s, 1);
+
+    let y = pulp::as_arrays_mut::<8, _>(y).0;
+    let (x0, x1, x2, x3, x4, x5, x6, x7) = split_8(x);
+    let (_, w1, w2, w3, w4, w5, w6, w7) = split_8(w_init);
+
+    for ((x0, x1, x2, x3, x4, x5, x6, x7), y, (w1, w2, w3, w4, w5, w6, w7)) in izip!(
+        izip!(x0, x1, x2, x3, x4, x5, x6, x7),
+        y,
+        izip!(w1, w2, w3, w4, w5, w6, w7),
+    ) {
+        let x0 = *x0;
+        let x1 = *x1;
+        let x2 = *x2;
+        let x3 = *x3;
+        let x4 = *x4;
+        let x5 = *x5;
+        let x6 = *x6;
+        let x7 = *x7;
+
+        let a04 = simd.add(x0, x4);
+        let s04 = simd.sub(x0, x4);
+        let a26 = simd.add(x2, x6);
+        let js26 = simd.mul_j(fwd, simd.sub(x2, x6));
+        let a15 = simd.add(x1, x5);
+        let s15 = simd.sub(x1, x5);
+        let a37 = simd.add(x3, x7);
+        let js37 = simd.mul_j(fwd, simd.sub(x3, x7));
+
+        let a04_p1_a26 = simd.add(a04, a26);
+        let s04_mj_s26 = simd.sub(s04, js26);
+        let a04_m1_a26 = simd.sub(a04, a26);
+        let s04_pj_s26 = simd.add(s04, js26);
+        let a15_p1_a37 = simd.add(a15, a37);
+        let w8_s15_mj_s37 = simd.mul_exp_neg_pi_over_8(fwd, simd.sub(s15, js37));
+        let j_a15_m1_a37 = simd.mul_j(fwd, simd.sub(a15, a37));
+        let v8_s15_pj_s37 = simd.mul_exp_pi_over_8(fwd, simd.add(s15, js37));
+
+        let w1 = *w1;
+        let w2 = *w2;
+        let w3 = *w3;
+        let w4 = *w4;
+        let w5 = *w5;
+        let w6 = *w6;
+        let w7 = *w7;
+
+        let a = simd.add(a04_p1_a26, a15_p1_a37);
+        let b = simd.mul(w1, simd.add(s04_mj_s26, w8_s15_mj_s37));
+        let c = simd.mul(w2, simd.sub(a04_m1_a26, j_a15_m1_a37));
+        let d = simd.mul(w3, simd.sub(s04_pj_s26, v8_s15_pj_s37));
+        let e = simd.mul(w4, simd.sub(a04_p1_a26, a15_p1_a37));
+        let f = simd.mul(w5, simd.sub(s04_mj_s26, w8_s15_mj_s37));
+        let g = simd.mul(w6, simd.add(a04_m1_a26, j_a15_m1_a37));
+        let h = simd.mul(w7, simd.add(s04_pj_s26, v8_s15_pj_s37));
+
+        *y = simd.transpose_8x8([a, b, c, d, e, f, g, h]);
+    }
+}
+
 #[inline(always)]
 fn stockham_core_generic<c64xN: Pod>(
     simd: impl FftSimd<c64xN>,
@@ -208,7 +283,7 @@ fn stockham_core_generic<c64xN: Pod>(
     x: &[c64xN],
     y: &mut [c64xN],
     _w_init: &[c64xN],
-    w: &[c64],
+    w: &[c64xN::Lane],
 ) {
     assert_eq!(s % simd.lane_count(), 0);
     let simd_s = s / simd.lane_count();
@@ -291,7 +366,7 @@ fn stockham_core<c64xN: Pod>(
     x: &[c64xN],
     y: &mut [c64xN],
     w_init: &[c64xN],
-    w: &[c64],
+    w: &[c64xN::Lane],
 ) {
     // we create a fn pointer that will be force-inlined in release builds
     // but not in debug builds. this helps keep compile times low, since dead code
@@ -301,6 +376,8 @@ fn stockham_core<c64xN: Pod>(
         stockham_core_1x2
     } else if s == 1 && simd.lane_count() == 4 {
         stockham_core_1x4
+    } else if s == 1 && simd.lane_count() == 8 {
+        stockham_core_1x8
     } else {
         stockham_core_generic
     };
@@ -359,8 +436,37 @@ pub fn stockham_dif8_end<c64xN: Pod>(
     x: &mut [c64xN],
     y: &mut [c64xN],
 ) {
+    if !write_to_x {
+        stockham_dif8_end_out_of_place(simd, fwd, s, x, y);
+        return;
+    }
+
     assert_eq!(s % simd.lane_count(), 0);
     let (x0, x1, x2, x3, x4, x5, x6, x7) = split_mut_8(x);
+
+    // we create a fn pointer that will be force-inlined in release builds
+    // but not in debug builds. this helps keep compile times low, since dead code
+    // elimination handles this well in release builds. and the function pointer indirection
+    // prevents inlining in debug builds.
+    let last_butterfly: fn(_, _, _, _, _, _, _, _, _, _) -> _ = last_butterfly;
+
+    for (x0, x1, x2, x3, x4, x5, x6, x7) in izip!(x0, x1, x2, x3, x4, x5, x6, x7) {
+        (*x0, *x1, *x2, *x3, *x4, *x5, *x6, *x7) =
+            last_butterfly(simd, fwd, *x0, *x1, *x2, *x3, *x4, *x5, *x6, *x7);
+    }
+}
+
+/// Out-of-place version of [`stockham_dif8_end`], reading from `x` and writing to `y`.
+#[inline(always)]
+pub fn stockham_dif8_end_out_of_place<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    fwd: bool,
+    s: usize,
+    x: &[c64xN],
+    y: &mut [c64xN],
+) {
+    assert_eq!(s % simd.lane_count(), 0);
+    let (x0, x1, x2, x3, x4, x5, x6, x7) = split_8(x);
     let (y0, y1, y2, y3, y4, y5, y6, y7) = split_mut_8(y);
 
     // we create a fn pointer that will be force-inlined in release builds
@@ -369,19 +475,12 @@ pub fn stockham_dif8_end<c64xN: Pod>(
     // prevents inlining in debug builds.
     let last_butterfly: fn(_, _, _, _, _, _, _, _, _, _) -> _ = last_butterfly;
 
-    if write_to_x {
-        for (x0, x1, x2, x3, x4, x5, x6, x7) in izip!(x0, x1, x2, x3, x4, x5, x6, x7) {
-            (*x0, *x1, *x2, *x3, *x4, *x5, *x6, *x7) =
-                last_butterfly(simd, fwd, *x0, *x1, *x2, *x3, *x4, *x5, *x6, *x7);
-        }
-    } else {
-        for ((x0, x1, x2, x3, x4, x5, x6, x7), (y0, y1, y2, y3, y4, y5, y6, y7)) in izip!(
-            izip!(x0, x1, x2, x3, x4, x5, x6, x7),
-            izip!(y0, y1, y2, y3, y4, y5, y6, y7),
-        ) {
-            (*y0, *y1, *y2, *y3, *y4, *y5, *y6, *y7) =
-                last_butterfly(simd, fwd, *x0, *x1, *x2, *x3, *x4, *x5, *x6, *x7);
-        }
+    for ((x0, x1, x2, x3, x4, x5, x6, x7), (y0, y1, y2, y3, y4, y5, y6, y7)) in izip!(
+        izip!(x0, x1, x2, x3, x4, x5, x6, x7),
+        izip!(y0, y1, y2, y3, y4, y5, y6, y7),
+    ) {
+        (*y0, *y1, *y2, *y3, *y4, *y5, *y6, *y7) =
+            last_butterfly(simd, fwd, *x0, *x1, *x2, *x3, *x4, *x5, *x6, *x7);
     }
 }
 
@@ -401,10 +500,24 @@ impl RecursiveFft for Dif8<nat::N0> {
         x: &mut [c64xN],
         y: &mut [c64xN],
         _w_init: &[c64xN],
-        _w: &[c64],
+        _w: &[c64xN::Lane],
     ) {
         crate::dif2::stockham_dif2_end(simd, fwd, write_to_x, s, x, y);
     }
+
+    #[inline(always)]
+    fn fft_recurse_out_of_place_impl<c64xN: Pod>(
+        simd: impl FftSimd<c64xN>,
+        fwd: bool,
+        s: usize,
+        src: &[c64xN],
+        x: &mut [c64xN],
+        _y: &mut [c64xN],
+        _w_init: &[c64xN],
+        _w: &[c64xN::Lane],
+    ) {
+        crate::dif2::stockham_dif2_end_out_of_place(simd, fwd, s, src, x);
+    }
 }
 
 // size 4
@@ -418,10 +531,24 @@ impl RecursiveFft for Dif8<nat::N1> {
         x: &mut [c64xN],
         y: &mut [c64xN],
         _w_init: &[c64xN],
-        _w: &[c64],
+        _w: &[c64xN::Lane],
     ) {
         crate::dif4::stockham_dif4_end(simd, fwd, write_to_x, s, x, y);
     }
+
+    #[inline(always)]
+    fn fft_recurse_out_of_place_impl<c64xN: Pod>(
+        simd: impl FftSimd<c64xN>,
+        fwd: bool,
+        s: usize,
+        src: &[c64xN],
+        x: &mut [c64xN],
+        _y: &mut [c64xN],
+        _w_init: &[c64xN],
+        _w: &[c64xN::Lane],
+    ) {
+        crate::dif4::stockham_dif4_end_out_of_place(simd, fwd, s, src, x);
+    }
 }
 
 // size 8
@@ -435,10 +562,24 @@ impl RecursiveFft for Dif8<nat::N2> {
         x: &mut [c64xN],
         y: &mut [c64xN],
         _w_init: &[c64xN],
-        _w: &[c64],
+        _w: &[c64xN::Lane],
     ) {
         stockham_dif8_end(simd, fwd, write_to_x, s, x, y);
     }
+
+    #[inline(always)]
+    fn fft_recurse_out_of_place_impl<c64xN: Pod>(
+        simd: impl FftSimd<c64xN>,
+        fwd: bool,
+        s: usize,
+        src: &[c64xN],
+        x: &mut [c64xN],
+        _y: &mut [c64xN],
+        _w_init: &[c64xN],
+        _w: &[c64xN::Lane],
+    ) {
+        stockham_dif8_end_out_of_place(simd, fwd, s, src, x);
+    }
 }
 
 impl<N: nat::Nat> RecursiveFft for Dif8<nat::Plus3<N>>
@@ -454,14 +595,29 @@ where
         x: &mut [c64xN],
         y: &mut [c64xN],
         w_init: &[c64xN],
-        w: &[c64],
+        w: &[c64xN::Lane],
     ) {
         stockham_core(simd, fwd, s, x, y, w_init, w);
         Dif8::<N>::fft_recurse_impl(simd, fwd, !write_to_x, s * 8, y, x, w_init, w);
     }
+
+    #[inline(always)]
+    fn fft_recurse_out_of_place_impl<c64xN: Pod>(
+        simd: impl FftSimd<c64xN>,
+        fwd: bool,
+        s: usize,
+        src: &[c64xN],
+        x: &mut [c64xN],
+        y: &mut [c64xN],
+        w_init: &[c64xN],
+        w: &[c64xN::Lane],
+    ) {
+        stockham_core(simd, fwd, s, src, y, w_init, w);
+        Dif8::<N>::fft_recurse_impl(simd, fwd, false, s * 8, y, x, w_init, w);
+    }
 }
 
-pub(crate) fn fft_impl<c64xN: Pod>(simd: impl FftSimd<c64xN>) -> crate::FftImpl {
+pub(crate) fn fft_impl<c64xN: Pod>(simd: impl FftSimd<c64xN>) -> crate::FftImpl<c64xN::Lane> {
     let fwd = [
         fn_ptr::<true, Dif8<nat::N0>, _, _>(simd),
         fn_ptr::<true, Dif8<nat::N1>, _, _>(simd),
@@ -489,20 +645,68 @@ pub(crate) fn fft_impl<c64xN: Pod>(simd: impl FftSimd<c64xN>) -> crate::FftImpl
     crate::FftImpl { fwd, inv }
 }
 
-pub fn fft_impl_dispatch(n: usize) -> [fn(&mut [c64], &mut [c64], &[c64], &[c64]); 2] {
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")),
+    allow(unused_variables)
+)]
+pub fn fft_impl_dispatch(
+    n: usize,
+    level: crate::SimdLevel,
+) -> [fn(Option<&[c64]>, &mut [c64], &mut [c64], &[c64], &[c64]); 2] {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        #[cfg(feature = "avx512")]
+        if let Some(simd) = level.v4() {
+            if n >= 8 * FftSimd::<c64x4>::lane_count(simd) {
+                return fft_impl::<c64x4>(simd).make_fn_ptr(n);
+            }
+        }
+        if let Some(simd) = level.v3() {
+            if n >= 8 * FftSimd::<c64x2>::lane_count(simd) {
+                return fft_impl::<c64x2>(simd).make_fn_ptr(n);
+            }
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if let Some(simd) = level.neon() {
+            if n >= 8 * FftSimd::<c64x1>::lane_count(simd) {
+                return fft_impl::<c64x1>(simd).make_fn_ptr(n);
+            }
+        }
+    }
+    fft_impl::<c64>(crate::fft_simd::Scalar).make_fn_ptr(n)
+}
+
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")),
+    allow(unused_variables)
+)]
+pub fn fft_impl_dispatch_c32(
+    n: usize,
+    level: crate::SimdLevel,
+) -> [fn(Option<&[c32]>, &mut [c32], &mut [c32], &[c32], &[c32]); 2] {
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     {
-        #[cfg(feature = "nightly")]
-        if let Some(simd) = pulp::x86::V4::try_new() {
-            if n >= 8 * simd.lane_count() {
-                return fft_impl(simd).make_fn_ptr(n);
+        #[cfg(feature = "avx512")]
+        if let Some(simd) = level.v4() {
+            if n >= 8 * FftSimd::<c32x8>::lane_count(simd) {
+                return fft_impl::<c32x8>(simd).make_fn_ptr(n);
             }
         }
-        if let Some(simd) = pulp::x86::V3::try_new() {
-            if n >= 8 * simd.lane_count() {
-                return fft_impl(simd).make_fn_ptr(n);
+        if let Some(simd) = level.v3() {
+            if n >= 8 * FftSimd::<c32x4>::lane_count(simd) {
+                return fft_impl::<c32x4>(simd).make_fn_ptr(n);
+            }
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if let Some(simd) = level.neon() {
+            if n >= 8 * FftSimd::<c32x2>::lane_count(simd) {
+                return fft_impl::<c32x2>(simd).make_fn_ptr(n);
             }
         }
     }
-    fft_impl(crate::fft_simd::Scalar).make_fn_ptr(n)
+    fft_impl::<c32>(crate::fft_simd::Scalar).make_fn_ptr(n)
 }
@@ -1,5 +1,9 @@
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::fft_simd::c64x2;
+#[cfg(target_arch = "aarch64")]
+use crate::fft_simd::{c32x2, c64x1};
 use crate::{
-    c64,
+    c32, c64,
     dif2::{split_2, split_mut_2},
     fft_simd::{FftSimd, Pod},
     fn_ptr, nat, RecursiveFft,
@@ -13,7 +17,7 @@ fn stockham_core_1x2<c64xN: Pod>(
     x: &mut [c64xN],
     y: &[c64xN],
     w_init: &[c64xN],
-    _w: &[c64],
+    _w: &[c64xN::Lane],
 ) {
     assert_eq!(s, 1);
 
@@ -42,7 +46,7 @@ fn stockham_core_generic<c64xN: Pod>(
     x: &mut [c64xN],
     y: &[c64xN],
     _w_init: &[c64xN],
-    w: &[c64],
+    w: &[c64xN::Lane],
 ) {
     assert_eq!(s % simd.lane_count(), 0);
     let simd_s = s / simd.lane_count();
@@ -80,7 +84,7 @@ fn stockham_core<c64xN: Pod>(
     x: &mut [c64xN],
     y: &[c64xN],
     w_init: &[c64xN],
-    w: &[c64],
+    w: &[c64xN::Lane],
 ) {
     // we create a fn pointer that will be force-inlined in release builds
     // but not in debug builds. this helps keep compile times low, since dead code
@@ -113,9 +117,13 @@ pub fn stockham_dit2_end<c64xN: Pod>(
     x: &mut [c64xN],
     y: &mut [c64xN],
 ) {
+    if !read_from_x {
+        stockham_dit2_end_out_of_place(simd, fwd, s, x, y);
+        return;
+    }
+
     assert_eq!(s % simd.lane_count(), 0);
     let (x0, x1) = split_mut_2(x);
-    let (y0, y1) = split_mut_2(y);
 
     // we create a fn pointer that will be force-inlined in release builds
     // but not in debug builds. this helps keep compile times low, since dead code
@@ -123,14 +131,32 @@ pub fn stockham_dit2_end<c64xN: Pod>(
     // prevents inlining in debug builds.
     let last_butterfly: fn(_, _, _, _) -> _ = last_butterfly;
 
-    if read_from_x {
-        for (x0, x1) in izip!(x0, x1) {
-            (*x0, *x1) = last_butterfly(simd, fwd, *x0, *x1);
-        }
-    } else {
-        for (x0, x1, y0, y1) in izip!(x0, x1, y0, y1) {
-            (*x0, *x1) = last_butterfly(simd, fwd, *y0, *y1);
-        }
+    for (x0, x1) in izip!(x0, x1) {
+        (*x0, *x1) = last_butterfly(simd, fwd, *x0, *x1);
+    }
+}
+
+/// Out-of-place version of [`stockham_dit2_end`], reading from `y` and writing to `x`.
+#[inline(always)]
+pub fn stockham_dit2_end_out_of_place<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    fwd: bool,
+    s: usize,
+    x: &mut [c64xN],
+    y: &[c64xN],
+) {
+    assert_eq!(s % simd.lane_count(), 0);
+    let (x0, x1) = split_mut_2(x);
+    let (y0, y1) = split_2(y);
+
+    // we create a fn pointer that will be force-inlined in release builds
+    // but not in debug builds. this helps keep compile times low, since dead code
+    // elimination handles this well in release builds. and the function pointer indirection
+    // prevents inlining in debug builds.
+    let last_butterfly: fn(_, _, _, _) -> _ = last_butterfly;
+
+    for (x0, x1, y0, y1) in izip!(x0, x1, y0, y1) {
+        (*x0, *x1) = last_butterfly(simd, fwd, *y0, *y1);
     }
 }
 
@@ -150,10 +176,24 @@ impl RecursiveFft for Dit2<nat::N0> {
         x: &mut [c64xN],
         y: &mut [c64xN],
         _w_init: &[c64xN],
-        _w: &[c64],
+        _w: &[c64xN::Lane],
     ) {
         stockham_dit2_end(simd, fwd, read_from_x, s, x, y);
     }
+
+    #[inline(always)]
+    fn fft_recurse_out_of_place_impl<c64xN: Pod>(
+        simd: impl FftSimd<c64xN>,
+        fwd: bool,
+        s: usize,
+        src: &[c64xN],
+        x: &mut [c64xN],
+        _y: &mut [c64xN],
+        _w_init: &[c64xN],
+        _w: &[c64xN::Lane],
+    ) {
+        stockham_dit2_end_out_of_place(simd, fwd, s, x, src);
+    }
 }
 
 impl<N: nat::Nat> RecursiveFft for Dit2<nat::Plus1<N>>
@@ -169,14 +209,29 @@ where
         x: &mut [c64xN],
         y: &mut [c64xN],
         w_init: &[c64xN],
-        w: &[c64],
+        w: &[c64xN::Lane],
     ) {
         Dit2::<N>::fft_recurse_impl(simd, fwd, !read_from_x, s * 2, y, x, w_init, w);
         stockham_core(simd, fwd, s, x, y, w_init, w);
     }
+
+    #[inline(always)]
+    fn fft_recurse_out_of_place_impl<c64xN: Pod>(
+        simd: impl FftSimd<c64xN>,
+        fwd: bool,
+        s: usize,
+        src: &[c64xN],
+        x: &mut [c64xN],
+        y: &mut [c64xN],
+        w_init: &[c64xN],
+        w: &[c64xN::Lane],
+    ) {
+        Dit2::<N>::fft_recurse_out_of_place_impl(simd, fwd, s * 2, src, y, x, w_init, w);
+        stockham_core(simd, fwd, s, x, y, w_init, w);
+    }
 }
 
-pub(crate) fn fft_impl<c64xN: Pod>(simd: impl FftSimd<c64xN>) -> crate::FftImpl {
+pub(crate) fn fft_impl<c64xN: Pod>(simd: impl FftSimd<c64xN>) -> crate::FftImpl<c64xN::Lane> {
     // special case, for DIT2, fwd and inv are the same
     let ptrs = [
         fn_ptr::<true, Dit2<nat::N0>, _, _>(simd),
@@ -196,14 +251,45 @@ pub(crate) fn fft_impl<c64xN: Pod>(simd: impl FftSimd<c64xN>) -> crate::FftImpl
     }
 }
 
-pub fn fft_impl_dispatch(n: usize) -> [fn(&mut [c64], &mut [c64], &[c64], &[c64]); 2] {
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")),
+    allow(unused_variables)
+)]
+pub fn fft_impl_dispatch(
+    n: usize,
+    level: crate::SimdLevel,
+) -> [fn(Option<&[c64]>, &mut [c64], &mut [c64], &[c64], &[c64]); 2] {
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     {
-        if let Some(simd) = pulp::x86::V3::try_new() {
-            if n >= 2 * simd.lane_count() {
-                return fft_impl(simd).make_fn_ptr(n);
+        if let Some(simd) = level.v3() {
+            if n >= 2 * FftSimd::<c64x2>::lane_count(simd) {
+                return fft_impl::<c64x2>(simd).make_fn_ptr(n);
+            }
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if let Some(simd) = level.neon() {
+            if n >= 2 * FftSimd::<c64x1>::lane_count(simd) {
+                return fft_impl::<c64x1>(simd).make_fn_ptr(n);
+            }
+        }
+    }
+    fft_impl::<c64>(crate::fft_simd::Scalar).make_fn_ptr(n)
+}
+
+#[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+pub fn fft_impl_dispatch_c32(
+    n: usize,
+    level: crate::SimdLevel,
+) -> [fn(Option<&[c32]>, &mut [c32], &mut [c32], &[c32], &[c32]); 2] {
+    #[cfg(target_arch = "aarch64")]
+    {
+        if let Some(simd) = level.neon() {
+            if n >= 2 * FftSimd::<c32x2>::lane_count(simd) {
+                return fft_impl::<c32x2>(simd).make_fn_ptr(n);
             }
         }
     }
-    fft_impl(crate::fft_simd::Scalar).make_fn_ptr(n)
+    fft_impl::<c32>(crate::fft_simd::Scalar).make_fn_ptr(n)
 }
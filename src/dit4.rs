@@ -1,5 +1,11 @@
+#[cfg(target_arch = "aarch64")]
+use crate::fft_simd::{c32x2, c64x1};
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::fft_simd::{c32x4, c64x2};
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx512"))]
+use crate::fft_simd::{c32x8, c64x4};
 use crate::{
-    c64,
+    c32, c64,
     dif4::{split_4, split_mut_4},
     fft_simd::{FftSimd, FftSimdExt, Pod},
     fn_ptr, nat, RecursiveFft,
@@ -13,7 +19,7 @@ fn stockham_core_1x2<c64xN: Pod>(
     x: &mut [c64xN],
     y: &[c64xN],
     w_init: &[c64xN],
-    _w: &[c64],
+    _w: &[c64xN::Lane],
 ) {
     assert_eq!(s, 1);
 
@@ -56,7 +62,7 @@ fn stockham_core_1x4<c64xN: Pod>(
     x: &mut [c64xN],
     y: &[c64xN],
     w_init: &[c64xN],
-    _w: &[c64],
+    _w: &[c64xN::Lane],
 ) {
     assert_eq!(s, 1);
 
@@ -74,7 +80,7 @@ fn stockham_core_1x4<c64xN: Pod>(
         let abcd2 = y[2];
         let abcd3 = y[3];
 
-        let (a, b, c, d) = simd.transpose(abcd0, abcd1, abcd2, abcd3);
+        let (a, b, c, d) = simd.untranspose(abcd0, abcd1, abcd2, abcd3);
 
         let b = simd.mul(w1, b);
         let c = simd.mul(w2, c);
@@ -92,6 +98,54 @@ fn stockham_core_1x4<c64xN: Pod>(
     }
 }
 
+#[inline(always)]
+fn stockham_core_4x8<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    fwd: bool,
+    s: usize,
+    x: &mut [c64xN],
+    y: &[c64xN],
+    _w_init: &[c64xN],
+    w: &[c64xN::Lane],
+) {
+    assert_eq!(s, 4);
+
+    let w = pulp::as_arrays::<4, _>(w).0;
+
+    let y = pulp::as_arrays::<4, _>(y).0;
+    let (x0, x1, x2, x3) = split_mut_4(x);
+
+    for (y, x0, x1, x2, x3, w) in izip!(y, x0, x1, x2, x3, w.chunks_exact(8)) {
+        // the low and high halves of the registers belong to consecutive butterflies
+        let [_, w1_lo, w2_lo, w3_lo] = w[0];
+        let [_, w1_hi, w2_hi, w3_hi] = w[4];
+
+        let w1 = simd.catlo(simd.splat(w1_lo), simd.splat(w1_hi));
+        let w2 = simd.catlo(simd.splat(w2_lo), simd.splat(w2_hi));
+        let w3 = simd.catlo(simd.splat(w3_lo), simd.splat(w3_hi));
+
+        let ab0 = y[0];
+        let cd0 = y[1];
+        let ab1 = y[2];
+        let cd1 = y[3];
+
+        let a = simd.catlo(ab0, ab1);
+        let b = simd.mul(w1, simd.cathi(ab0, ab1));
+        let c = simd.mul(w2, simd.catlo(cd0, cd1));
+        let d = simd.mul(w3, simd.cathi(cd0, cd1));
+
+        let apc = simd.add(a, c);
+        let amc = simd.sub(a, c);
+        let bpd = simd.add(b, d);
+        let jbmd = simd.mul_j(fwd, simd.sub(b, d));
+
+        *x0 = simd.add(apc, bpd);
+        *x1 = simd.sub(amc, jbmd);
+        *x2 = simd.sub(apc, bpd);
+        *x3 = simd.add(amc, jbmd);
+    }
+}
+
 #[inline(always)]
 fn stockham_core_generic<c64xN: Pod>(
     simd: impl FftSimd<c64xN>,
@@ -100,7 +154,7 @@ fn stockham_core_generic<c64xN: Pod>(
     x: &mut [c64xN],
     y: &[c64xN],
     _w_init: &[c64xN],
-    w: &[c64],
+    w: &[c64xN::Lane],
 ) {
     assert_eq!(s % simd.lane_count(), 0);
     let simd_s = s / simd.lane_count();
@@ -152,7 +206,7 @@ fn stockham_core<c64xN: Pod>(
     x: &mut [c64xN],
     y: &[c64xN],
     w_init: &[c64xN],
-    w: &[c64],
+    w: &[c64xN::Lane],
 ) {
     // we create a fn pointer that will be force-inlined in release builds
     // but not in debug builds. this helps keep compile times low, since dead code
@@ -160,8 +214,10 @@ fn stockham_core<c64xN: Pod>(
     // prevents inlining in debug builds.
     let stockham = if s == 1 && simd.lane_count() == 2 {
         stockham_core_1x2
-    } else if s == 1 && simd.lane_count() == 4 {
+    } else if s == 1 && (simd.lane_count() == 4 || simd.lane_count() == 8) {
         stockham_core_1x4
+    } else if s == 4 && simd.lane_count() == 8 {
+        stockham_core_4x8
     } else {
         stockham_core_generic
     };
@@ -199,9 +255,13 @@ pub fn stockham_dit4_end<c64xN: Pod>(
     x: &mut [c64xN],
     y: &mut [c64xN],
 ) {
+    if !read_from_x {
+        stockham_dit4_end_out_of_place(simd, fwd, s, x, y);
+        return;
+    }
+
     assert_eq!(s % simd.lane_count(), 0);
     let (x0, x1, x2, x3) = split_mut_4(x);
-    let (y0, y1, y2, y3) = split_mut_4(y);
 
     // we create a fn pointer that will be force-inlined in release builds
     // but not in debug builds. this helps keep compile times low, since dead code
@@ -209,14 +269,32 @@ pub fn stockham_dit4_end<c64xN: Pod>(
     // prevents inlining in debug builds.
     let last_butterfly: fn(_, _, _, _, _, _) -> _ = last_butterfly;
 
-    if read_from_x {
-        for (x0, x1, x2, x3) in izip!(x0, x1, x2, x3) {
-            (*x0, *x1, *x2, *x3) = last_butterfly(simd, fwd, *x0, *x1, *x2, *x3);
-        }
-    } else {
-        for (x0, x1, x2, x3, y0, y1, y2, y3) in izip!(x0, x1, x2, x3, y0, y1, y2, y3) {
-            (*x0, *x1, *x2, *x3) = last_butterfly(simd, fwd, *y0, *y1, *y2, *y3);
-        }
+    for (x0, x1, x2, x3) in izip!(x0, x1, x2, x3) {
+        (*x0, *x1, *x2, *x3) = last_butterfly(simd, fwd, *x0, *x1, *x2, *x3);
+    }
+}
+
+/// Out-of-place version of [`stockham_dit4_end`], reading from `y` and writing to `x`.
+#[inline(always)]
+pub fn stockham_dit4_end_out_of_place<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    fwd: bool,
+    s: usize,
+    x: &mut [c64xN],
+    y: &[c64xN],
+) {
+    assert_eq!(s % simd.lane_count(), 0);
+    let (x0, x1, x2, x3) = split_mut_4(x);
+    let (y0, y1, y2, y3) = split_4(y);
+
+    // we create a fn pointer that will be force-inlined in release builds
+    // but not in debug builds. this helps keep compile times low, since dead code
+    // elimination handles this well in release builds. and the function pointer indirection
+    // prevents inlining in debug builds.
+    let last_butterfly: fn(_, _, _, _, _, _) -> _ = last_butterfly;
+
+    for (x0, x1, x2, x3, y0, y1, y2, y3) in izip!(x0, x1, x2, x3, y0, y1, y2, y3) {
+        (*x0, *x1, *x2, *x3) = last_butterfly(simd, fwd, *y0, *y1, *y2, *y3);
     }
 }
 
@@ -236,10 +314,24 @@ impl RecursiveFft for Dit4<nat::N0> {
         x: &mut [c64xN],
         y: &mut [c64xN],
         _w_init: &[c64xN],
-        _w: &[c64],
+        _w: &[c64xN::Lane],
     ) {
         crate::dit2::stockham_dit2_end(simd, fwd, read_from_x, s, x, y);
     }
+
+    #[inline(always)]
+    fn fft_recurse_out_of_place_impl<c64xN: Pod>(
+        simd: impl FftSimd<c64xN>,
+        fwd: bool,
+        s: usize,
+        src: &[c64xN],
+        x: &mut [c64xN],
+        _y: &mut [c64xN],
+        _w_init: &[c64xN],
+        _w: &[c64xN::Lane],
+    ) {
+        crate::dit2::stockham_dit2_end_out_of_place(simd, fwd, s, x, src);
+    }
 }
 
 // size 4
@@ -253,10 +345,24 @@ impl RecursiveFft for Dit4<nat::N1> {
         x: &mut [c64xN],
         y: &mut [c64xN],
         _w_init: &[c64xN],
-        _w: &[c64],
+        _w: &[c64xN::Lane],
     ) {
         stockham_dit4_end(simd, fwd, read_from_x, s, x, y);
     }
+
+    #[inline(always)]
+    fn fft_recurse_out_of_place_impl<c64xN: Pod>(
+        simd: impl FftSimd<c64xN>,
+        fwd: bool,
+        s: usize,
+        src: &[c64xN],
+        x: &mut [c64xN],
+        _y: &mut [c64xN],
+        _w_init: &[c64xN],
+        _w: &[c64xN::Lane],
+    ) {
+        stockham_dit4_end_out_of_place(simd, fwd, s, x, src);
+    }
 }
 
 impl<N: nat::Nat> RecursiveFft for Dit4<nat::Plus2<N>>
@@ -272,14 +378,29 @@ where
         x: &mut [c64xN],
         y: &mut [c64xN],
         w_init: &[c64xN],
-        w: &[c64],
+        w: &[c64xN::Lane],
     ) {
         Dit4::<N>::fft_recurse_impl(simd, fwd, !read_from_x, s * 4, y, x, w_init, w);
         stockham_core(simd, fwd, s, x, y, w_init, w);
     }
+
+    #[inline(always)]
+    fn fft_recurse_out_of_place_impl<c64xN: Pod>(
+        simd: impl FftSimd<c64xN>,
+        fwd: bool,
+        s: usize,
+        src: &[c64xN],
+        x: &mut [c64xN],
+        y: &mut [c64xN],
+        w_init: &[c64xN],
+        w: &[c64xN::Lane],
+    ) {
+        Dit4::<N>::fft_recurse_out_of_place_impl(simd, fwd, s * 4, src, y, x, w_init, w);
+        stockham_core(simd, fwd, s, x, y, w_init, w);
+    }
 }
 
-pub(crate) fn fft_impl<c64xN: Pod>(simd: impl FftSimd<c64xN>) -> crate::FftImpl {
+pub(crate) fn fft_impl<c64xN: Pod>(simd: impl FftSimd<c64xN>) -> crate::FftImpl<c64xN::Lane> {
     let fwd = [
         fn_ptr::<true, Dit4<nat::N0>, _, _>(simd),
         fn_ptr::<true, Dit4<nat::N1>, _, _>(simd),
@@ -307,20 +428,68 @@ pub(crate) fn fft_impl<c64xN: Pod>(simd: impl FftSimd<c64xN>) -> crate::FftImpl
     crate::FftImpl { fwd, inv }
 }
 
-pub fn fft_impl_dispatch(n: usize) -> [fn(&mut [c64], &mut [c64], &[c64], &[c64]); 2] {
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")),
+    allow(unused_variables)
+)]
+pub fn fft_impl_dispatch(
+    n: usize,
+    level: crate::SimdLevel,
+) -> [fn(Option<&[c64]>, &mut [c64], &mut [c64], &[c64], &[c64]); 2] {
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     {
-        #[cfg(feature = "nightly")]
-        if let Some(simd) = pulp::x86::V4::try_new() {
-            if n >= 4 * simd.lane_count() {
-                return fft_impl(simd).make_fn_ptr(n);
+        #[cfg(feature = "avx512")]
+        if let Some(simd) = level.v4() {
+            if n >= 4 * FftSimd::<c64x4>::lane_count(simd) {
+                return fft_impl::<c64x4>(simd).make_fn_ptr(n);
             }
         }
-        if let Some(simd) = pulp::x86::V3::try_new() {
-            if n >= 4 * simd.lane_count() {
-                return fft_impl(simd).make_fn_ptr(n);
+        if let Some(simd) = level.v3() {
+            if n >= 4 * FftSimd::<c64x2>::lane_count(simd) {
+                return fft_impl::<c64x2>(simd).make_fn_ptr(n);
+            }
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if let Some(simd) = level.neon() {
+            if n >= 4 * FftSimd::<c64x1>::lane_count(simd) {
+                return fft_impl::<c64x1>(simd).make_fn_ptr(n);
+            }
+        }
+    }
+    fft_impl::<c64>(crate::fft_simd::Scalar).make_fn_ptr(n)
+}
+
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")),
+    allow(unused_variables)
+)]
+pub fn fft_impl_dispatch_c32(
+    n: usize,
+    level: crate::SimdLevel,
+) -> [fn(Option<&[c32]>, &mut [c32], &mut [c32], &[c32], &[c32]); 2] {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        #[cfg(feature = "avx512")]
+        if let Some(simd) = level.v4() {
+            if n >= 4 * FftSimd::<c32x8>::lane_count(simd) {
+                return fft_impl::<c32x8>(simd).make_fn_ptr(n);
+            }
+        }
+        if let Some(simd) = level.v3() {
+            if n >= 4 * FftSimd::<c32x4>::lane_count(simd) {
+                return fft_impl::<c32x4>(simd).make_fn_ptr(n);
+            }
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if let Some(simd) = level.neon() {
+            if n >= 4 * FftSimd::<c32x2>::lane_count(simd) {
+                return fft_impl::<c32x2>(simd).make_fn_ptr(n);
             }
         }
     }
-    fft_impl(crate::fft_simd::Scalar).make_fn_ptr(n)
+    fft_impl::<c32>(crate::fft_simd::Scalar).make_fn_ptr(n)
 }
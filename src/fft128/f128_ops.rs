@@ -39,6 +39,35 @@ fn two_prod(a: f64, b: f64) -> (f64, f64) {
     (p, f64::mul_add(a, b, -p))
 }
 
+/// Converts an integer valued `f64` to an integer modulo $2^{128}$.
+#[inline(always)]
+fn f64_to_u128_wrapping(x: f64) -> u128 {
+    let bits = x.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    if biased_exponent == 0 {
+        // zero or subnormal, which can only be zero since `x` is integer valued
+        return 0;
+    }
+    let mantissa = (bits & ((1u64 << 52) - 1)) | (1u64 << 52);
+    let exponent = biased_exponent - 1075;
+
+    let abs = if exponent >= 128 {
+        0
+    } else if exponent >= 0 {
+        (mantissa as u128) << exponent
+    } else if exponent > -64 {
+        (mantissa >> -exponent) as u128
+    } else {
+        0
+    };
+
+    if x < 0.0 {
+        abs.wrapping_neg()
+    } else {
+        abs
+    }
+}
+
 use core::{
     cmp::Ordering,
     convert::From,
@@ -511,6 +540,48 @@ impl f128 {
         }
     }
 
+    /// Converts `value` to the nearest `f128`.
+    #[inline(always)]
+    pub fn from_i128(value: i128) -> Self {
+        let x0 = value as f64;
+        // `x0` may be equal to `2^127` which is out of range for `i128`, so we compute the
+        // remainder modulo `2^128` instead. the result is small enough to fit in an `i128`.
+        let x1 = (value as u128).wrapping_sub(f64_to_u128_wrapping(x0)) as i128 as f64;
+        let (x0, x1) = quick_two_sum(x0, x1);
+        Self(x0, x1)
+    }
+
+    /// Converts `value` to the nearest `f128`.
+    #[inline(always)]
+    pub fn from_u128(value: u128) -> Self {
+        let x0 = value as f64;
+        let x1 = value.wrapping_sub(f64_to_u128_wrapping(x0)) as i128 as f64;
+        let (x0, x1) = quick_two_sum(x0, x1);
+        Self(x0, x1)
+    }
+
+    /// Rounds `self` to the nearest integer, and returns the result modulo $2^{128}$.
+    #[inline(always)]
+    pub fn round_to_u128_wrapping(self) -> u128 {
+        let x0 = self.0.round();
+        // if `self.0` is not an integer, then it's small enough for the subtraction to be exact
+        let r = (self.0 - x0) + self.1;
+        // `x0 + r` is halfway between two integers when `|r| == 0.5`, in which case we round away
+        // from zero like `f64::round`
+        let x1 = if r.abs() == 0.5 && x0 != 0.0 && (r < 0.0) != (x0 < 0.0) {
+            0.0
+        } else {
+            r.round()
+        };
+        f64_to_u128_wrapping(x0).wrapping_add(f64_to_u128_wrapping(x1))
+    }
+
+    /// Rounds `self` to the nearest integer, and returns the result modulo $2^{128}$.
+    #[inline(always)]
+    pub fn round_to_i128_wrapping(self) -> i128 {
+        self.round_to_u128_wrapping() as i128
+    }
+
     fn sincospi_taylor(self) -> (Self, Self) {
         let mut sinc = Self::PI;
         let mut cos = f128(1.0, 0.0);
@@ -618,10 +689,213 @@ impl f128 {
     ];
 }
 
+/// 128-bit complex floating point number.
+///
+/// This type can be used for arithmetic in the Fourier domain of the 128-bit FFT plans, whose
+/// buffers store the high and low parts of the real and imaginary parts of each term separately.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct c128 {
+    /// Real part.
+    pub re: f128,
+    /// Imaginary part.
+    pub im: f128,
+}
+
+impl c128 {
+    /// Returns the complex number `re + i * im`.
+    #[inline(always)]
+    pub const fn new(re: f128, im: f128) -> Self {
+        Self { re, im }
+    }
+
+    /// Returns the complex conjugate of `self`.
+    #[inline(always)]
+    pub fn conj(self) -> Self {
+        Self {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+
+    /// Returns the squared norm of `self`, $\operatorname{re}^2 + \operatorname{im}^2$.
+    #[inline(always)]
+    pub fn norm_sqr(self) -> f128 {
+        self.re.sqr() + self.im.sqr()
+    }
+
+    /// Returns $\exp(i \pi \theta)$, where $\theta$ is `theta_over_pi`.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `theta_over_pi` is not in `[-1, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::fft128::{c128, f128};
+    ///
+    /// let z = c128::exp_i_pi(f128(0.5, 0.0));
+    /// assert!((z - c128::new(f128(0.0, 0.0), f128(1.0, 0.0))).norm_sqr() < 1e-60);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn exp_i_pi(theta_over_pi: f128) -> Self {
+        let (s, c) = theta_over_pi.sincospi();
+        Self { re: c, im: s }
+    }
+
+    /// Returns the complex number with modulus `r` and argument $\pi \theta$, where $\theta$ is
+    /// `theta_over_pi`.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `theta_over_pi` is not in `[-1, 1]`.
+    #[inline]
+    #[track_caller]
+    pub fn from_polar(r: f128, theta_over_pi: f128) -> Self {
+        Self::exp_i_pi(theta_over_pi) * r
+    }
+}
+
+impl From<f128> for c128 {
+    #[inline(always)]
+    fn from(value: f128) -> Self {
+        Self {
+            re: value,
+            im: f128(0.0, 0.0),
+        }
+    }
+}
+
+impl Add<c128> for c128 {
+    type Output = c128;
+
+    #[inline(always)]
+    fn add(self, rhs: c128) -> Self::Output {
+        c128 {
+            re: self.re + rhs.re,
+            im: self.im + rhs.im,
+        }
+    }
+}
+
+impl AddAssign<c128> for c128 {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: c128) {
+        *self = *self + rhs
+    }
+}
+
+impl Sub<c128> for c128 {
+    type Output = c128;
+
+    #[inline(always)]
+    fn sub(self, rhs: c128) -> Self::Output {
+        c128 {
+            re: self.re - rhs.re,
+            im: self.im - rhs.im,
+        }
+    }
+}
+
+impl SubAssign<c128> for c128 {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: c128) {
+        *self = *self - rhs
+    }
+}
+
+impl Mul<c128> for c128 {
+    type Output = c128;
+
+    #[inline(always)]
+    fn mul(self, rhs: c128) -> Self::Output {
+        c128 {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re,
+        }
+    }
+}
+
+impl Mul<f128> for c128 {
+    type Output = c128;
+
+    #[inline(always)]
+    fn mul(self, rhs: f128) -> Self::Output {
+        c128 {
+            re: self.re * rhs,
+            im: self.im * rhs,
+        }
+    }
+}
+
+impl MulAssign<c128> for c128 {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: c128) {
+        *self = *self * rhs
+    }
+}
+
+impl MulAssign<f128> for c128 {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: f128) {
+        *self = *self * rhs
+    }
+}
+
+impl Div<c128> for c128 {
+    type Output = c128;
+
+    #[inline(always)]
+    fn div(self, rhs: c128) -> Self::Output {
+        (self * rhs.conj()) / rhs.norm_sqr()
+    }
+}
+
+impl Div<f128> for c128 {
+    type Output = c128;
+
+    #[inline(always)]
+    fn div(self, rhs: f128) -> Self::Output {
+        c128 {
+            re: self.re / rhs,
+            im: self.im / rhs,
+        }
+    }
+}
+
+impl DivAssign<c128> for c128 {
+    #[inline(always)]
+    fn div_assign(&mut self, rhs: c128) {
+        *self = *self / rhs
+    }
+}
+
+impl DivAssign<f128> for c128 {
+    #[inline(always)]
+    fn div_assign(&mut self, rhs: f128) {
+        *self = *self / rhs
+    }
+}
+
+impl Neg for c128 {
+    type Output = c128;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        c128 {
+            re: -self.re,
+            im: -self.im,
+        }
+    }
+}
+
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 #[cfg_attr(docsrs, doc(cfg(any(target_arch = "x86", target_arch = "x86_64"))))]
 pub mod x86 {
-    #[cfg(feature = "nightly")]
+    #[cfg(feature = "avx512")]
     use pulp::{b8, f64x8, x86::V4};
     use pulp::{f64x4, x86::V3, Simd};
 
@@ -645,7 +919,7 @@ pub mod x86 {
 
     #[inline(always)]
     pub(crate) fn two_diff_f64x4(simd: V3, a: f64x4, b: f64x4) -> (f64x4, f64x4) {
-        two_sum_f64x4(simd, a, simd.f64s_neg(b))
+        two_sum_f64x4(simd, a, simd.neg_f64s(b))
     }
 
     #[inline(always)]
@@ -654,7 +928,7 @@ pub mod x86 {
         (p, simd.mul_sub_f64x4(a, b, p))
     }
 
-    #[cfg(feature = "nightly")]
+    #[cfg(feature = "avx512")]
     #[inline(always)]
     pub(crate) fn quick_two_sum_f64x8(simd: V4, a: f64x8, b: f64x8) -> (f64x8, f64x8) {
         let s = simd.add_f64x8(a, b);
@@ -662,7 +936,7 @@ pub mod x86 {
     }
 
     #[inline(always)]
-    #[cfg(feature = "nightly")]
+    #[cfg(feature = "avx512")]
     pub(crate) fn two_sum_f64x8(simd: V4, a: f64x8, b: f64x8) -> (f64x8, f64x8) {
         let sign_bit = simd.splat_f64x8(-0.0);
         let cmp = simd.cmp_gt_f64x8(
@@ -675,19 +949,19 @@ pub mod x86 {
     }
 
     #[inline(always)]
-    #[cfg(feature = "nightly")]
+    #[cfg(feature = "avx512")]
     pub(crate) fn two_diff_f64x8(simd: V4, a: f64x8, b: f64x8) -> (f64x8, f64x8) {
-        two_sum_f64x8(simd, a, simd.f64s_neg(b))
+        two_sum_f64x8(simd, a, simd.neg_f64s(b))
     }
 
-    #[cfg(feature = "nightly")]
+    #[cfg(feature = "avx512")]
     #[inline(always)]
     pub(crate) fn two_prod_f64x8(simd: V4, a: f64x8, b: f64x8) -> (f64x8, f64x8) {
         let p = simd.mul_f64x8(a, b);
         (p, simd.mul_sub_f64x8(a, b, p))
     }
 
-    #[cfg(feature = "nightly")]
+    #[cfg(feature = "avx512")]
     #[inline(always)]
     pub(crate) fn quick_two_sum_f64x16(simd: V4, a: f64x16, b: f64x16) -> (f64x16, f64x16) {
         let s = simd.add_f64x16(a, b);
@@ -695,7 +969,7 @@ pub mod x86 {
     }
 
     #[inline(always)]
-    #[cfg(feature = "nightly")]
+    #[cfg(feature = "avx512")]
     pub(crate) fn two_sum_f64x16(simd: V4, a: f64x16, b: f64x16) -> (f64x16, f64x16) {
         let sign_bit = simd.splat_f64x16(-0.0);
         let cmp = simd.cmp_gt_f64x16(
@@ -708,26 +982,26 @@ pub mod x86 {
     }
 
     #[inline(always)]
-    #[cfg(feature = "nightly")]
+    #[cfg(feature = "avx512")]
     pub(crate) fn two_diff_f64x16(simd: V4, a: f64x16, b: f64x16) -> (f64x16, f64x16) {
         two_sum_f64x16(
             simd,
             a,
             f64x16 {
-                lo: simd.f64s_neg(b.lo),
-                hi: simd.f64s_neg(b.hi),
+                lo: simd.neg_f64s(b.lo),
+                hi: simd.neg_f64s(b.hi),
             },
         )
     }
 
-    #[cfg(feature = "nightly")]
+    #[cfg(feature = "avx512")]
     #[inline(always)]
     pub(crate) fn two_prod_f64x16(simd: V4, a: f64x16, b: f64x16) -> (f64x16, f64x16) {
         let p = simd.mul_f64x16(a, b);
         (p, simd.mul_sub_f64x16(a, b, p))
     }
 
-    #[cfg(feature = "nightly")]
+    #[cfg(feature = "avx512")]
     #[derive(Copy, Clone, Debug)]
     #[repr(C)]
     pub struct f64x16 {
@@ -735,7 +1009,7 @@ pub mod x86 {
         pub hi: f64x8,
     }
 
-    #[cfg(feature = "nightly")]
+    #[cfg(feature = "avx512")]
     #[derive(Copy, Clone, Debug)]
     #[repr(C)]
     pub struct b16 {
@@ -743,9 +1017,9 @@ pub mod x86 {
         pub hi: b8,
     }
 
-    #[cfg(feature = "nightly")]
+    #[cfg(feature = "avx512")]
     unsafe impl bytemuck::Zeroable for f64x16 {}
-    #[cfg(feature = "nightly")]
+    #[cfg(feature = "avx512")]
     unsafe impl bytemuck::Pod for f64x16 {}
 
     pub trait V3F128Ext {
@@ -756,7 +1030,7 @@ pub mod x86 {
         fn mul_f128x4(self, a0: f64x4, a1: f64x4, b0: f64x4, b1: f64x4) -> (f64x4, f64x4);
     }
 
-    #[cfg(feature = "nightly")]
+    #[cfg(feature = "avx512")]
     pub trait V4F128Ext {
         fn add_estimate_f128x8(self, a0: f64x8, a1: f64x8, b0: f64x8, b1: f64x8) -> (f64x8, f64x8);
         fn sub_estimate_f128x8(self, a0: f64x8, a1: f64x8, b0: f64x8, b1: f64x8) -> (f64x8, f64x8);
@@ -841,7 +1115,7 @@ pub mod x86 {
         }
     }
 
-    #[cfg(feature = "nightly")]
+    #[cfg(feature = "avx512")]
     impl V4F128Ext for V4 {
         #[inline(always)]
         fn add_estimate_f128x8(self, a0: f64x8, a1: f64x8, b0: f64x8, b1: f64x8) -> (f64x8, f64x8) {
@@ -1271,3 +1545,66 @@ mod tests {
         assert_eq!(float_to_f128(&pi), f128::PI);
     }
 }
+
+#[cfg(test)]
+mod tests_c128 {
+    use super::*;
+    use rand::random;
+
+    fn random_c128() -> c128 {
+        c128::new(
+            f128::add_f64_f64(random::<f64>() - 0.5, (random::<f64>() - 0.5) * 1e-17),
+            f128::add_f64_f64(random::<f64>() - 0.5, (random::<f64>() - 0.5) * 1e-17),
+        )
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        for _ in 0..1000 {
+            let a = random_c128();
+            let b = random_c128();
+
+            let sum = a + b;
+            assert!((sum - b - a).norm_sqr() < 1e-60);
+            assert_eq!(-a + a, c128::from(f128(0.0, 0.0)));
+
+            let prod = a * b;
+            assert!((prod - b * a).norm_sqr() < 1e-60);
+            assert!((prod.re - (a.re * b.re - a.im * b.im)).abs() < 1e-30);
+
+            let quot = prod / b;
+            assert!((quot - a).norm_sqr() < 1e-56 * (1.0 + a.norm_sqr() / b.norm_sqr()).0);
+
+            let norm_sqr = a * a.conj();
+            assert!((norm_sqr.re - a.norm_sqr()).abs() < 1e-30);
+            assert!(norm_sqr.im.abs() < 1e-30);
+        }
+    }
+
+    #[test]
+    fn test_exp_i_pi() {
+        let one = f128(1.0, 0.0);
+        let zero = f128(0.0, 0.0);
+        for (theta_over_pi, expected) in [
+            (0.0, c128::new(one, zero)),
+            (0.5, c128::new(zero, one)),
+            (1.0, c128::new(-one, zero)),
+            (-0.5, c128::new(zero, -one)),
+        ] {
+            let z = c128::exp_i_pi(f128(theta_over_pi, 0.0));
+            assert!((z - expected).norm_sqr() < 1e-60);
+        }
+
+        for _ in 0..1000 {
+            let theta_over_pi = f128(random::<f64>() * 2.0 - 1.0, 0.0);
+            let r = f128(random::<f64>() * 4.0, 0.0);
+            let z = c128::from_polar(r, theta_over_pi);
+            assert!((z.norm_sqr() - r.sqr()).abs() <= 1e-30 * r.sqr());
+
+            // `exp(iπa) exp(iπb) = exp(iπ(a + b))`
+            let half = theta_over_pi / 2.0;
+            let z = c128::exp_i_pi(half) * c128::exp_i_pi(half);
+            assert!((z - c128::exp_i_pi(theta_over_pi)).norm_sqr() < 1e-60);
+        }
+    }
+}
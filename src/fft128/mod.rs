@@ -6,19 +6,23 @@ pub mod f128_ops;
 #[repr(C)]
 pub struct f128(pub f64, pub f64);
 
+pub use f128_ops::c128;
+
 use aligned_vec::{avec, ABox};
 use pulp::{as_arrays, as_arrays_mut, cast};
 
+use crate::{check_power_of_two, check_simd_level, PlanError, SimdLevel};
+
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 use crate::fft128::f128_ops::x86::V3F128Ext;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 use pulp::{f64x4, x86::V3};
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-#[cfg(feature = "nightly")]
+#[cfg(feature = "avx512")]
 use crate::fft128::f128_ops::x86::{f64x16, V4F128Ext};
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-#[cfg(feature = "nightly")]
+#[cfg(feature = "avx512")]
 use pulp::{f64x8, x86::V4};
 
 trait FftSimdF128: Copy {
@@ -39,7 +43,7 @@ trait V3InterleaveExt {
 }
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-#[cfg(feature = "nightly")]
+#[cfg(feature = "avx512")]
 trait V4InterleaveExt {
     fn interleave4_f64x8(self, z0z0z0z0z1z1z1z1: [f64x8; 2]) -> [f64x8; 2];
     fn permute4_f64x8(self, w: [f64; 2]) -> f64x8;
@@ -88,7 +92,7 @@ impl V3InterleaveExt for V3 {
 }
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-#[cfg(feature = "nightly")]
+#[cfg(feature = "avx512")]
 impl V4InterleaveExt for V4 {
     #[inline(always)]
     fn interleave4_f64x8(self, z0z0z0z0z1z1z1z1: [f64x8; 2]) -> [f64x8; 2] {
@@ -215,7 +219,7 @@ impl FftSimdF128 for V3 {
 }
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-#[cfg(feature = "nightly")]
+#[cfg(feature = "avx512")]
 impl FftSimdF128 for V4 {
     type Reg = f64x8;
 
@@ -244,12 +248,12 @@ impl FftSimdF128 for V4 {
 }
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-#[cfg(feature = "nightly")]
+#[cfg(feature = "avx512")]
 #[derive(Copy, Clone, Debug)]
 pub struct V4x2(pub V4);
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-#[cfg(feature = "nightly")]
+#[cfg(feature = "avx512")]
 impl FftSimdF128 for V4x2 {
     type Reg = f64x16;
 
@@ -662,7 +666,7 @@ pub fn negacyclic_fwd_fft_avxfma(
 }
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-#[cfg(feature = "nightly")]
+#[cfg(feature = "avx512")]
 #[doc(hidden)]
 pub fn negacyclic_fwd_fft_avx512(
     simd: V4,
@@ -1038,7 +1042,12 @@ pub fn negacyclic_fwd_fft_avx512(
 }
 
 #[doc(hidden)]
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64")),
+    allow(unused_variables)
+)]
 pub fn negacyclic_fwd_fft(
+    simd: SimdLevel,
     data_re0: &mut [f64],
     data_re1: &mut [f64],
     data_im0: &mut [f64],
@@ -1050,14 +1059,14 @@ pub fn negacyclic_fwd_fft(
 ) {
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     {
-        #[cfg(feature = "nightly")]
-        if let Some(simd) = V4::try_new() {
+        #[cfg(feature = "avx512")]
+        if let Some(simd) = simd.v4() {
             return negacyclic_fwd_fft_avx512(
                 simd, data_re0, data_re1, data_im0, data_im1, twid_re0, twid_re1, twid_im0,
                 twid_im1,
             );
         }
-        if let Some(simd) = V3::try_new() {
+        if let Some(simd) = simd.v3() {
             return negacyclic_fwd_fft_avxfma(
                 simd, data_re0, data_re1, data_im0, data_im1, twid_re0, twid_re1, twid_im0,
                 twid_im1,
@@ -1070,7 +1079,12 @@ pub fn negacyclic_fwd_fft(
 }
 
 #[doc(hidden)]
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64")),
+    allow(unused_variables)
+)]
 pub fn negacyclic_inv_fft(
+    simd: SimdLevel,
     data_re0: &mut [f64],
     data_re1: &mut [f64],
     data_im0: &mut [f64],
@@ -1082,14 +1096,14 @@ pub fn negacyclic_inv_fft(
 ) {
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     {
-        #[cfg(feature = "nightly")]
-        if let Some(simd) = V4::try_new() {
+        #[cfg(feature = "avx512")]
+        if let Some(simd) = simd.v4() {
             return negacyclic_inv_fft_avx512(
                 simd, data_re0, data_re1, data_im0, data_im1, twid_re0, twid_re1, twid_im0,
                 twid_im1,
             );
         }
-        if let Some(simd) = V3::try_new() {
+        if let Some(simd) = simd.v3() {
             return negacyclic_inv_fft_avxfma(
                 simd, data_re0, data_re1, data_im0, data_im1, twid_re0, twid_re1, twid_im0,
                 twid_im1,
@@ -1404,7 +1418,7 @@ pub fn negacyclic_inv_fft_avxfma(
 }
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-#[cfg(feature = "nightly")]
+#[cfg(feature = "avx512")]
 #[doc(hidden)]
 pub fn negacyclic_inv_fft_avx512(
     simd: V4,
@@ -1827,6 +1841,52 @@ pub fn init_negacyclic_twiddles(
     }
 }
 
+#[doc(hidden)]
+pub fn init_cyclic_twiddles(
+    twid_re0: &mut [f64],
+    twid_re1: &mut [f64],
+    twid_im0: &mut [f64],
+    twid_im1: &mut [f64],
+) {
+    let n = twid_re0.len();
+    let mut m = 1_usize;
+
+    // the butterflies of the `i`-th block of the stage with `m` blocks split `X^(n/m) - w^2`
+    // into `X^(n/2m) - w` and `X^(n/2m) + w`, with `w = exp(-iπ bitreverse(i, m) / m)`
+    while m < n {
+        for i in 0..m {
+            let pos = m + i;
+
+            let theta_over_pi = f128(bitreverse(i, m) as f64 / m as f64, 0.0);
+            let (s, c) = theta_over_pi.sincospi();
+            twid_re0[pos] = c.0;
+            twid_re1[pos] = c.1;
+            twid_im0[pos] = -s.0;
+            twid_im1[pos] = -s.1;
+        }
+        m *= 2;
+    }
+}
+
+/// Permutes the terms of the buffers to or from bit-reversed order.
+fn bitreverse_permute(
+    buf_re0: &mut [f64],
+    buf_re1: &mut [f64],
+    buf_im0: &mut [f64],
+    buf_im1: &mut [f64],
+) {
+    let n = buf_re0.len();
+    for i in 0..n {
+        let j = bitreverse(i, n);
+        if i < j {
+            buf_re0.swap(i, j);
+            buf_re1.swap(i, j);
+            buf_im0.swap(i, j);
+            buf_im1.swap(i, j);
+        }
+    }
+}
+
 /// 128-bit negacyclic FFT plan.
 #[derive(Clone)]
 pub struct Plan {
@@ -1834,12 +1894,14 @@ pub struct Plan {
     twid_re1: ABox<[f64]>,
     twid_im0: ABox<[f64]>,
     twid_im1: ABox<[f64]>,
+    simd: SimdLevel,
 }
 
 impl core::fmt::Debug for Plan {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Plan")
             .field("fft_size", &self.fft_size())
+            .field("simd", &self.simd)
             .finish()
     }
 }
@@ -1862,8 +1924,81 @@ impl Plan {
     /// [paper]: https://eprint.iacr.org/2021/480
     #[track_caller]
     pub fn new(n: usize) -> Self {
-        assert!(n.is_power_of_two());
-        assert!(n >= 32);
+        match Self::try_new(n) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Returns a new negacyclic FFT plan for the given vector size, or an error if `n` is not a
+    /// power of two, or if it is less than `32`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{fft128::Plan, PlanError};
+    ///
+    /// assert!(Plan::try_new(32).is_ok());
+    /// assert_eq!(
+    ///     Plan::try_new(16).unwrap_err(),
+    ///     PlanError::TooSmall { size: 16, min: 32 },
+    /// );
+    /// ```
+    pub fn try_new(n: usize) -> Result<Self, PlanError> {
+        Self::try_new_with_simd(n, SimdLevel::detect())
+    }
+
+    /// Returns a new negacyclic FFT plan for the given vector size, whose kernels use the provided
+    /// SIMD instruction set instead of the best one available at runtime.
+    ///
+    /// The 128-bit FFT has no NEON kernels, so [`SimdLevel::Neon`] uses the scalar kernels.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `n` is not a power of two, or if it is less than `32`.
+    /// - Panics if `simd` is not available on the current machine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{fft128::Plan, SimdLevel};
+    ///
+    /// let plan = Plan::new_with_simd(32, SimdLevel::Scalar);
+    /// assert_eq!(plan.simd_level(), SimdLevel::Scalar);
+    /// ```
+    #[track_caller]
+    pub fn new_with_simd(n: usize, simd: SimdLevel) -> Self {
+        match Self::try_new_with_simd(n, simd) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Returns a new negacyclic FFT plan for the given vector size, whose kernels use the provided
+    /// SIMD instruction set, or an error if `n` is not a power of two, if it is less than `32`, or
+    /// if `simd` is not available on the current machine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{fft128::Plan, PlanError, SimdLevel};
+    ///
+    /// assert!(Plan::try_new_with_simd(32, SimdLevel::Scalar).is_ok());
+    /// if !SimdLevel::Avx512.is_available() {
+    ///     assert_eq!(
+    ///         Plan::try_new_with_simd(32, SimdLevel::Avx512).unwrap_err(),
+    ///         PlanError::SimdUnavailable {
+    ///             simd: SimdLevel::Avx512
+    ///         },
+    ///     );
+    /// }
+    /// ```
+    pub fn try_new_with_simd(n: usize, simd: SimdLevel) -> Result<Self, PlanError> {
+        check_power_of_two(n)?;
+        check_simd_level(simd)?;
+        if n < 32 {
+            return Err(PlanError::TooSmall { size: n, min: 32 });
+        }
 
         let mut twid_re0 = avec![0.0f64; n].into_boxed_slice();
         let mut twid_re1 = avec![0.0f64; n].into_boxed_slice();
@@ -1872,12 +2007,13 @@ impl Plan {
 
         init_negacyclic_twiddles(&mut twid_re0, &mut twid_re1, &mut twid_im0, &mut twid_im1);
 
-        Self {
+        Ok(Self {
             twid_re0,
             twid_re1,
             twid_im0,
             twid_im1,
-        }
+            simd,
+        })
     }
 
     /// Returns the vector size of the negacyclic FFT.
@@ -1893,6 +2029,20 @@ impl Plan {
         self.twid_re0.len()
     }
 
+    /// Returns the SIMD instruction set that's internally used by the negacyclic FFT.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{fft128::Plan, SimdLevel};
+    ///
+    /// let plan = Plan::new(32);
+    /// assert_eq!(plan.simd_level(), SimdLevel::detect());
+    /// ```
+    pub fn simd_level(&self) -> SimdLevel {
+        self.simd
+    }
+
     /// Performs a forward negacyclic FFT in place.
     ///
     /// # Note
@@ -1915,6 +2065,7 @@ impl Plan {
         assert_eq!(buf_im1.len(), self.fft_size());
 
         negacyclic_fwd_fft(
+            self.simd,
             buf_re0,
             buf_re1,
             buf_im0,
@@ -1948,6 +2099,430 @@ impl Plan {
         assert_eq!(buf_im1.len(), self.fft_size());
 
         negacyclic_inv_fft(
+            self.simd,
+            buf_re0,
+            buf_re1,
+            buf_im0,
+            buf_im1,
+            &self.twid_re0,
+            &self.twid_re1,
+            &self.twid_im0,
+            &self.twid_im1,
+        );
+    }
+
+    /// Performs a forward negacyclic FFT of the polynomial `poly`, whose coefficients are
+    /// interpreted as signed integers modulo $2^{128}$, and stores the result in `buf_re0`,
+    /// `buf_re1`, `buf_im0`, `buf_im1`.
+    ///
+    /// # Note
+    ///
+    /// The values in `poly` must be in standard order. When this function returns, the values in
+    /// `buf_re0`, `buf_re1`, `buf_im0`, `buf_im1` will contain the terms of the forward transform
+    /// in bit-reversed order.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `poly` is not equal to twice the FFT size.
+    /// - Panics if the length of any of the buffers is not equal to the FFT size.
+    #[track_caller]
+    pub fn fwd_u128(
+        &self,
+        buf_re0: &mut [f64],
+        buf_re1: &mut [f64],
+        buf_im0: &mut [f64],
+        buf_im1: &mut [f64],
+        poly: &[u128],
+    ) {
+        let n = self.fft_size();
+        assert_eq!(poly.len(), 2 * n);
+        let (poly_re, poly_im) = poly.split_at(n);
+
+        for (re0, re1, im0, im1, &re, &im) in izip!(
+            &mut *buf_re0,
+            &mut *buf_re1,
+            &mut *buf_im0,
+            &mut *buf_im1,
+            poly_re,
+            poly_im,
+        ) {
+            let re = f128::from_i128(re as i128);
+            let im = f128::from_i128(im as i128);
+            (*re0, *re1) = (re.0, re.1);
+            (*im0, *im1) = (im.0, im.1);
+        }
+
+        self.fwd(buf_re0, buf_re1, buf_im0, buf_im1);
+    }
+
+    /// Performs a forward negacyclic FFT of the polynomial `poly`, and stores the result in
+    /// `buf_re0`, `buf_re1`, `buf_im0`, `buf_im1`.
+    ///
+    /// # Note
+    ///
+    /// The values in `poly` must be in standard order. When this function returns, the values in
+    /// `buf_re0`, `buf_re1`, `buf_im0`, `buf_im1` will contain the terms of the forward transform
+    /// in bit-reversed order.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `poly` is not equal to twice the FFT size.
+    /// - Panics if the length of any of the buffers is not equal to the FFT size.
+    #[track_caller]
+    pub fn fwd_i128(
+        &self,
+        buf_re0: &mut [f64],
+        buf_re1: &mut [f64],
+        buf_im0: &mut [f64],
+        buf_im1: &mut [f64],
+        poly: &[i128],
+    ) {
+        self.fwd_u128(
+            buf_re0,
+            buf_re1,
+            buf_im0,
+            buf_im1,
+            bytemuck::cast_slice(poly),
+        );
+    }
+
+    /// Performs a normalized inverse negacyclic FFT of `buf_re0`, `buf_re1`, `buf_im0`,
+    /// `buf_im1`, rounds the result to the nearest integers, and adds it to `poly` modulo
+    /// $2^{128}$. The values of the buffers are used as temporary storage and are unspecified
+    /// when this function returns.
+    ///
+    /// # Note
+    ///
+    /// The values in `buf_re0`, `buf_re1`, `buf_im0`, `buf_im1` must be in bit-reversed order.
+    /// The values in `poly` are in standard order.
+    ///
+    /// The rounding is exact as long as the coefficients of the result are small enough to be
+    /// represented by an [`struct@f128`] with an absolute error less than `0.5`. For larger values,
+    /// the low bits of the result are only approximate.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `poly` is not equal to twice the FFT size.
+    /// - Panics if the length of any of the buffers is not equal to the FFT size.
+    #[track_caller]
+    pub fn inv_u128_add_assign(
+        &self,
+        poly: &mut [u128],
+        buf_re0: &mut [f64],
+        buf_re1: &mut [f64],
+        buf_im0: &mut [f64],
+        buf_im1: &mut [f64],
+    ) {
+        let n = self.fft_size();
+        assert_eq!(poly.len(), 2 * n);
+
+        self.inv(buf_re0, buf_re1, buf_im0, buf_im1);
+
+        // `n` is a power of two, so the normalization is exact
+        let normalization = 1.0 / n as f64;
+        let (poly_re, poly_im) = poly.split_at_mut(n);
+        for (&re0, &re1, &im0, &im1, re, im) in
+            izip!(&*buf_re0, &*buf_re1, &*buf_im0, &*buf_im1, poly_re, poly_im)
+        {
+            let value_re = f128(re0 * normalization, re1 * normalization);
+            let value_im = f128(im0 * normalization, im1 * normalization);
+            *re = re.wrapping_add(value_re.round_to_u128_wrapping());
+            *im = im.wrapping_add(value_im.round_to_u128_wrapping());
+        }
+    }
+
+    /// Performs a normalized inverse negacyclic FFT of `buf_re0`, `buf_re1`, `buf_im0`,
+    /// `buf_im1`, rounds the result to the nearest integers, and adds it to `poly` modulo
+    /// $2^{128}$. The values of the buffers are used as temporary storage and are unspecified
+    /// when this function returns.
+    ///
+    /// # Note
+    ///
+    /// The values in `buf_re0`, `buf_re1`, `buf_im0`, `buf_im1` must be in bit-reversed order.
+    /// The values in `poly` are in standard order.
+    ///
+    /// The rounding is exact as long as the coefficients of the result are small enough to be
+    /// represented by an [`struct@f128`] with an absolute error less than `0.5`. For larger values,
+    /// the low bits of the result are only approximate.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `poly` is not equal to twice the FFT size.
+    /// - Panics if the length of any of the buffers is not equal to the FFT size.
+    #[track_caller]
+    pub fn inv_i128_add_assign(
+        &self,
+        poly: &mut [i128],
+        buf_re0: &mut [f64],
+        buf_re1: &mut [f64],
+        buf_im0: &mut [f64],
+        buf_im1: &mut [f64],
+    ) {
+        self.inv_u128_add_assign(
+            bytemuck::cast_slice_mut(poly),
+            buf_re0,
+            buf_re1,
+            buf_im0,
+            buf_im1,
+        );
+    }
+}
+
+// configuration of a plan that is sent over the wire. the twiddles are always rebuilt from it when
+// deserializing
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Plan")]
+struct PlanConfig {
+    n: usize,
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for Plan {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        PlanConfig { n: self.fft_size() }.serialize(serializer)
+    }
+}
+
+/// The SIMD instruction set of the plan is not serialized, since the plan may be deserialized on a
+/// different machine. The deserialized plan uses the best instruction set available at runtime.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for Plan {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let PlanConfig { n } = PlanConfig::deserialize(deserializer)?;
+        Self::try_new(n).map_err(serde::de::Error::custom)
+    }
+}
+
+/// 128-bit cyclic FFT plan.
+///
+/// This plan computes the forward or inverse discrete Fourier transform in standard order, with
+/// the same definitions as the [`ordered`](crate::ordered) module, using 128-bit floating point
+/// arithmetic. The real and imaginary parts of each term are stored as [`f128`](struct@f128)
+/// values, whose high and low parts are split across four buffers.
+#[derive(Clone)]
+pub struct CyclicPlan {
+    twid_re0: ABox<[f64]>,
+    twid_re1: ABox<[f64]>,
+    twid_im0: ABox<[f64]>,
+    twid_im1: ABox<[f64]>,
+    simd: SimdLevel,
+}
+
+impl core::fmt::Debug for CyclicPlan {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CyclicPlan")
+            .field("fft_size", &self.fft_size())
+            .field("simd", &self.simd)
+            .finish()
+    }
+}
+
+impl CyclicPlan {
+    /// Returns a new cyclic FFT plan for the given vector size.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `n` is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::fft128::CyclicPlan;
+    /// let plan = CyclicPlan::new(32);
+    /// ```
+    #[track_caller]
+    pub fn new(n: usize) -> Self {
+        match Self::try_new(n) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Returns a new cyclic FFT plan for the given vector size, or an error if `n` is not a power
+    /// of two.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{fft128::CyclicPlan, PlanError};
+    ///
+    /// assert!(CyclicPlan::try_new(32).is_ok());
+    /// assert_eq!(
+    ///     CyclicPlan::try_new(24).unwrap_err(),
+    ///     PlanError::NotPowerOfTwo { size: 24 },
+    /// );
+    /// ```
+    pub fn try_new(n: usize) -> Result<Self, PlanError> {
+        Self::try_new_with_simd(n, SimdLevel::detect())
+    }
+
+    /// Returns a new cyclic FFT plan for the given vector size, whose kernels use the provided
+    /// SIMD instruction set instead of the best one available at runtime.
+    ///
+    /// The vectorized kernels are only used for sizes of at least `32`, smaller sizes and
+    /// [`SimdLevel::Neon`] use the scalar kernels.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `n` is not a power of two.
+    /// - Panics if `simd` is not available on the current machine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{fft128::CyclicPlan, SimdLevel};
+    ///
+    /// let plan = CyclicPlan::new_with_simd(32, SimdLevel::Scalar);
+    /// assert_eq!(plan.simd_level(), SimdLevel::Scalar);
+    /// ```
+    #[track_caller]
+    pub fn new_with_simd(n: usize, simd: SimdLevel) -> Self {
+        match Self::try_new_with_simd(n, simd) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Returns a new cyclic FFT plan for the given vector size, whose kernels use the provided
+    /// SIMD instruction set, or an error if `n` is not a power of two, or if `simd` is not
+    /// available on the current machine.
+    pub fn try_new_with_simd(n: usize, simd: SimdLevel) -> Result<Self, PlanError> {
+        check_power_of_two(n)?;
+        check_simd_level(simd)?;
+
+        let mut twid_re0 = avec![0.0f64; n].into_boxed_slice();
+        let mut twid_re1 = avec![0.0f64; n].into_boxed_slice();
+        let mut twid_im0 = avec![0.0f64; n].into_boxed_slice();
+        let mut twid_im1 = avec![0.0f64; n].into_boxed_slice();
+
+        init_cyclic_twiddles(&mut twid_re0, &mut twid_re1, &mut twid_im0, &mut twid_im1);
+
+        Ok(Self {
+            twid_re0,
+            twid_re1,
+            twid_im0,
+            twid_im1,
+            simd,
+        })
+    }
+
+    /// Returns the vector size of the cyclic FFT.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::fft128::CyclicPlan;
+    /// let plan = CyclicPlan::new(32);
+    /// assert_eq!(plan.fft_size(), 32);
+    /// ```
+    pub fn fft_size(&self) -> usize {
+        self.twid_re0.len()
+    }
+
+    /// Returns the SIMD instruction set that's internally used by the cyclic FFT.
+    pub fn simd_level(&self) -> SimdLevel {
+        self.simd
+    }
+
+    /// The vectorized kernels need at least `32` terms.
+    fn kernel_simd(&self) -> SimdLevel {
+        if self.fft_size() < 32 {
+            SimdLevel::Scalar
+        } else {
+            self.simd
+        }
+    }
+
+    /// Performs a forward cyclic FFT in place.
+    ///
+    /// # Note
+    ///
+    /// The values in `buf_re0`, `buf_re1`, `buf_im0`, `buf_im1` must be in standard order prior to
+    /// calling this function. When this function returns, the values in `buf_re0`, `buf_re1`,
+    /// `buf_im0`, `buf_im1` will contain the terms of the forward transform in standard order.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of any of the buffers is not equal to the FFT size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::fft128::CyclicPlan;
+    ///
+    /// let plan = CyclicPlan::new(4);
+    ///
+    /// let mut re0 = [0.0, 1.0, 0.0, 0.0];
+    /// let mut re1 = [0.0; 4];
+    /// let mut im0 = [0.0; 4];
+    /// let mut im1 = [0.0; 4];
+    /// plan.fwd(&mut re0, &mut re1, &mut im0, &mut im1);
+    ///
+    /// // `exp(-2iπk/4)`
+    /// assert_eq!(re0, [1.0, 0.0, -1.0, 0.0]);
+    /// assert_eq!(im0, [0.0, -1.0, 0.0, 1.0]);
+    /// ```
+    #[track_caller]
+    pub fn fwd(
+        &self,
+        buf_re0: &mut [f64],
+        buf_re1: &mut [f64],
+        buf_im0: &mut [f64],
+        buf_im1: &mut [f64],
+    ) {
+        assert_eq!(buf_re0.len(), self.fft_size());
+        assert_eq!(buf_re1.len(), self.fft_size());
+        assert_eq!(buf_im0.len(), self.fft_size());
+        assert_eq!(buf_im1.len(), self.fft_size());
+
+        negacyclic_fwd_fft(
+            self.kernel_simd(),
+            buf_re0,
+            buf_re1,
+            buf_im0,
+            buf_im1,
+            &self.twid_re0,
+            &self.twid_re1,
+            &self.twid_im0,
+            &self.twid_im1,
+        );
+        bitreverse_permute(buf_re0, buf_re1, buf_im0, buf_im1);
+    }
+
+    /// Performs an inverse cyclic FFT in place.
+    ///
+    /// Like [`ordered::Plan::inv`](crate::ordered::Plan::inv), the result is not normalized, so
+    /// that applying [`CyclicPlan::fwd`] followed by [`CyclicPlan::inv`] multiplies the vector by
+    /// `n`.
+    ///
+    /// # Note
+    ///
+    /// The values in `buf_re0`, `buf_re1`, `buf_im0`, `buf_im1` must be in standard order prior to
+    /// calling this function. When this function returns, the values in `buf_re0`, `buf_re1`,
+    /// `buf_im0`, `buf_im1` will contain the terms of the inverse transform in standard order.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of any of the buffers is not equal to the FFT size.
+    #[track_caller]
+    pub fn inv(
+        &self,
+        buf_re0: &mut [f64],
+        buf_re1: &mut [f64],
+        buf_im0: &mut [f64],
+        buf_im1: &mut [f64],
+    ) {
+        assert_eq!(buf_re0.len(), self.fft_size());
+        assert_eq!(buf_re1.len(), self.fft_size());
+        assert_eq!(buf_im0.len(), self.fft_size());
+        assert_eq!(buf_im1.len(), self.fft_size());
+
+        bitreverse_permute(buf_re0, buf_re1, buf_im0, buf_im1);
+        negacyclic_inv_fft(
+            self.kernel_simd(),
             buf_re0,
             buf_re1,
             buf_im0,
@@ -1960,6 +2535,32 @@ impl Plan {
     }
 }
 
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "CyclicPlan")]
+struct CyclicPlanConfig {
+    n: usize,
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for CyclicPlan {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CyclicPlanConfig { n: self.fft_size() }.serialize(serializer)
+    }
+}
+
+/// The SIMD instruction set of the plan is not serialized, since the plan may be deserialized on a
+/// different machine. The deserialized plan uses the best instruction set available at runtime.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for CyclicPlan {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let CyclicPlanConfig { n } = CyclicPlanConfig::deserialize(deserializer)?;
+        Self::try_new(n).map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2177,6 +2778,398 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_simd_level() {
+        let levels = [
+            SimdLevel::Scalar,
+            SimdLevel::Avx2,
+            SimdLevel::Avx512,
+            SimdLevel::Neon,
+        ];
+        for n in [32, 64, 1024] {
+            let mut re0 = vec![0.0; n];
+            let re1 = vec![0.0; n];
+            let mut im0 = vec![0.0; n];
+            let im1 = vec![0.0; n];
+            for x in re0.iter_mut().chain(&mut im0) {
+                *x = random();
+            }
+
+            let scalar = Plan::new_with_simd(n, SimdLevel::Scalar);
+            let mut expected = [re0.clone(), re1.clone(), im0.clone(), im1.clone()];
+            let [e_re0, e_re1, e_im0, e_im1] = &mut expected;
+            scalar.fwd(e_re0, e_re1, e_im0, e_im1);
+
+            for simd in levels {
+                match Plan::try_new_with_simd(n, simd) {
+                    Ok(plan) => {
+                        assert!(simd.is_available());
+                        assert_eq!(plan.simd_level(), simd);
+                        let mut actual = [re0.clone(), re1.clone(), im0.clone(), im1.clone()];
+                        let [re0, re1, im0, im1] = &mut actual;
+                        plan.fwd(re0, re1, im0, im1);
+                        for (actual, expected) in actual.iter().zip(&expected) {
+                            for (actual, expected) in actual.iter().zip(expected) {
+                                assert!((actual - expected).abs() < 1e-10 * n as f64);
+                            }
+                        }
+                    }
+                    Err(err) => {
+                        assert!(!simd.is_available());
+                        assert_eq!(err, PlanError::SimdUnavailable { simd });
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cyclic() {
+        for n in [1, 2, 4, 8, 16, 32, 64, 128, 256] {
+            let mut re = vec![f128(0.0, 0.0); n];
+            let mut im = vec![f128(0.0, 0.0); n];
+            for x in re.iter_mut().chain(&mut im) {
+                *x = f128::add_f64_f64(random(), random::<f64>() * 1e-17);
+            }
+
+            // naive DFT, with `exp(-2iπjk/n)` computed from the exact angle
+            let mut expected_re = vec![f128(0.0, 0.0); n];
+            let mut expected_im = vec![f128(0.0, 0.0); n];
+            for k in 0..n {
+                for j in 0..n {
+                    let mut theta_over_pi = -2.0 * ((j * k) % n) as f64 / n as f64;
+                    if theta_over_pi < -1.0 {
+                        theta_over_pi += 2.0;
+                    }
+                    let theta_over_pi = f128(theta_over_pi, 0.0);
+                    let (s, c) = theta_over_pi.sincospi();
+                    expected_re[k] += re[j] * c - im[j] * s;
+                    expected_im[k] += re[j] * s + im[j] * c;
+                }
+            }
+
+            let mut re0: vec::Vec<f64> = re.iter().map(|x| x.0).collect();
+            let mut re1: vec::Vec<f64> = re.iter().map(|x| x.1).collect();
+            let mut im0: vec::Vec<f64> = im.iter().map(|x| x.0).collect();
+            let mut im1: vec::Vec<f64> = im.iter().map(|x| x.1).collect();
+
+            let plan = CyclicPlan::new(n);
+            plan.fwd(&mut re0, &mut re1, &mut im0, &mut im1);
+            for k in 0..n {
+                assert!((f128(re0[k], re1[k]) - expected_re[k]).abs() < 1e-30 * n as f64);
+                assert!((f128(im0[k], im1[k]) - expected_im[k]).abs() < 1e-30 * n as f64);
+            }
+
+            plan.inv(&mut re0, &mut re1, &mut im0, &mut im1);
+            for j in 0..n {
+                let actual_re = f128(re0[j], re1[j]) / n as f64;
+                let actual_im = f128(im0[j], im1[j]) / n as f64;
+                assert!((actual_re - re[j]).abs() < 1e-30 * n as f64);
+                assert!((actual_im - im[j]).abs() < 1e-30 * n as f64);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cyclic_convolution_c128() {
+        for n in [32, 256] {
+            let plan = CyclicPlan::new(n);
+
+            let lhs: vec::Vec<c128> = (0..n)
+                .map(|_| c128::new(f128(random(), 0.0), f128(random(), 0.0)))
+                .collect();
+            let rhs: vec::Vec<c128> = (0..n)
+                .map(|_| c128::new(f128(random(), 0.0), f128(random(), 0.0)))
+                .collect();
+
+            let mut expected = vec![c128::from(f128(0.0, 0.0)); n];
+            for i in 0..n {
+                for j in 0..n {
+                    expected[(i + j) % n] += lhs[i] * rhs[j];
+                }
+            }
+
+            let fwd = |z: &[c128]| {
+                let mut re0: vec::Vec<f64> = z.iter().map(|z| z.re.0).collect();
+                let mut re1: vec::Vec<f64> = z.iter().map(|z| z.re.1).collect();
+                let mut im0: vec::Vec<f64> = z.iter().map(|z| z.im.0).collect();
+                let mut im1: vec::Vec<f64> = z.iter().map(|z| z.im.1).collect();
+                plan.fwd(&mut re0, &mut re1, &mut im0, &mut im1);
+                [re0, re1, im0, im1]
+            };
+            let [mut re0, mut re1, mut im0, mut im1] = fwd(&lhs);
+            let [rhs_re0, rhs_re1, rhs_im0, rhs_im1] = fwd(&rhs);
+
+            for k in 0..n {
+                let lhs = c128::new(f128(re0[k], re1[k]), f128(im0[k], im1[k]));
+                let rhs = c128::new(f128(rhs_re0[k], rhs_re1[k]), f128(rhs_im0[k], rhs_im1[k]));
+                let prod = lhs * rhs / f128(n as f64, 0.0);
+                (re0[k], re1[k], im0[k], im1[k]) = (prod.re.0, prod.re.1, prod.im.0, prod.im.1);
+            }
+
+            plan.inv(&mut re0, &mut re1, &mut im0, &mut im1);
+            for k in 0..n {
+                let actual = c128::new(f128(re0[k], re1[k]), f128(im0[k], im1[k]));
+                assert!((actual - expected[k]).norm_sqr() < 1e-56 * (n * n) as f64);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cyclic_simd_level() {
+        let levels = [
+            SimdLevel::Scalar,
+            SimdLevel::Avx2,
+            SimdLevel::Avx512,
+            SimdLevel::Neon,
+        ];
+        for n in [16, 32, 64, 1024] {
+            let mut re0 = vec![0.0; n];
+            let re1 = vec![0.0; n];
+            let mut im0 = vec![0.0; n];
+            let im1 = vec![0.0; n];
+            for x in re0.iter_mut().chain(&mut im0) {
+                *x = random();
+            }
+
+            // compare with rustfft
+            let mut expected: vec::Vec<num_complex::Complex64> = re0
+                .iter()
+                .zip(&im0)
+                .map(|(&re, &im)| num_complex::Complex64::new(re, im))
+                .collect();
+            rustfft::FftPlanner::new()
+                .plan_fft_forward(n)
+                .process(&mut expected);
+
+            for simd in levels {
+                match CyclicPlan::try_new_with_simd(n, simd) {
+                    Ok(plan) => {
+                        assert!(simd.is_available());
+                        assert_eq!(plan.simd_level(), simd);
+                        let mut actual = [re0.clone(), re1.clone(), im0.clone(), im1.clone()];
+                        let [re0, re1, im0, im1] = &mut actual;
+                        plan.fwd(re0, re1, im0, im1);
+                        for (k, expected) in expected.iter().enumerate() {
+                            assert!((re0[k] - expected.re).abs() < 1e-12 * n as f64);
+                            assert!((im0[k] - expected.im).abs() < 1e-12 * n as f64);
+                        }
+                    }
+                    Err(err) => {
+                        assert!(!simd.is_available());
+                        assert_eq!(err, PlanError::SimdUnavailable { simd });
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_plan_serde() {
+        for n in [32, 64, 1024] {
+            let plan = Plan::new(n);
+            let json = serde_json::to_string(&plan).unwrap();
+            assert_eq!(serde_json::from_str::<Plan>(&json).unwrap().fft_size(), n);
+        }
+
+        assert!(serde_json::from_str::<Plan>(r#"{"n":16}"#).is_err());
+        assert!(serde_json::from_str::<Plan>(r#"{"n":48}"#).is_err());
+
+        for n in [1, 32, 1024] {
+            let plan = CyclicPlan::new(n);
+            let json = serde_json::to_string(&plan).unwrap();
+            assert_eq!(
+                serde_json::from_str::<CyclicPlan>(&json)
+                    .unwrap()
+                    .fft_size(),
+                n
+            );
+        }
+        assert!(serde_json::from_str::<CyclicPlan>(r#"{"n":48}"#).is_err());
+    }
+
+    #[test]
+    fn test_integer_conversion() {
+        for _ in 0..1000 {
+            let x = random::<i128>() >> 28;
+            assert_eq!(f128::from_i128(x).round_to_i128_wrapping(), x);
+            let x = random::<u128>() >> 28;
+            assert_eq!(f128::from_u128(x).round_to_u128_wrapping(), x);
+        }
+
+        assert_eq!(
+            f128::from_i128(i128::MIN).round_to_i128_wrapping(),
+            i128::MIN
+        );
+        assert_eq!(f128(-3.0, 0.0).round_to_u128_wrapping(), u128::MAX - 2);
+        assert_eq!(f128(2.5, 0.0).round_to_u128_wrapping(), 3);
+        assert_eq!(f128(-2.5, 0.0).round_to_i128_wrapping(), -3);
+        assert_eq!(f128(3.0, -0.5).round_to_u128_wrapping(), 3);
+        assert_eq!(f128(-3.0, 0.5).round_to_i128_wrapping(), -3);
+        assert_eq!(f128(2.0f64.powi(128), 5.0).round_to_u128_wrapping(), 5);
+        assert_eq!(
+            f128(2.0f64.powi(127), -1.0).round_to_u128_wrapping(),
+            (1u128 << 127) - 1,
+        );
+    }
+
+    #[test]
+    fn test_product_i128() {
+        for n in [64, 128, 256, 512, 1024, 2048] {
+            let mut lhs = vec![0i128; n];
+            let mut rhs = vec![0i128; n];
+            for x in &mut lhs {
+                *x = (random::<i64>() >> 14) as i128;
+            }
+            for x in &mut rhs {
+                *x = (random::<i32>() >> 12) as i128;
+            }
+
+            let mut negacyclic_convolution = vec![0i128; n];
+            for i in 0..n {
+                for j in 0..n {
+                    if i + j < n {
+                        negacyclic_convolution[i + j] += lhs[i] * rhs[j];
+                    } else {
+                        negacyclic_convolution[i + j - n] -= lhs[i] * rhs[j];
+                    }
+                }
+            }
+
+            let plan = Plan::new(n / 2);
+
+            let mut lhs_fourier_re0 = vec![0.0; n / 2];
+            let mut lhs_fourier_re1 = vec![0.0; n / 2];
+            let mut lhs_fourier_im0 = vec![0.0; n / 2];
+            let mut lhs_fourier_im1 = vec![0.0; n / 2];
+
+            let mut rhs_fourier_re0 = vec![0.0; n / 2];
+            let mut rhs_fourier_re1 = vec![0.0; n / 2];
+            let mut rhs_fourier_im0 = vec![0.0; n / 2];
+            let mut rhs_fourier_im1 = vec![0.0; n / 2];
+
+            plan.fwd_i128(
+                &mut lhs_fourier_re0,
+                &mut lhs_fourier_re1,
+                &mut lhs_fourier_im0,
+                &mut lhs_fourier_im1,
+                &lhs,
+            );
+            plan.fwd_i128(
+                &mut rhs_fourier_re0,
+                &mut rhs_fourier_re1,
+                &mut rhs_fourier_im0,
+                &mut rhs_fourier_im1,
+                &rhs,
+            );
+
+            let simd = Scalar;
+            for i in 0..n / 2 {
+                let (prod_re, prod_im) = simd.cplx_mul(
+                    (lhs_fourier_re0[i], lhs_fourier_re1[i]),
+                    (lhs_fourier_im0[i], lhs_fourier_im1[i]),
+                    (rhs_fourier_re0[i], rhs_fourier_re1[i]),
+                    (rhs_fourier_im0[i], rhs_fourier_im1[i]),
+                );
+
+                (lhs_fourier_re0[i], lhs_fourier_re1[i]) = prod_re;
+                (lhs_fourier_im0[i], lhs_fourier_im1[i]) = prod_im;
+            }
+
+            let mut result = vec![0i128; n];
+            plan.inv_i128_add_assign(
+                &mut result,
+                &mut lhs_fourier_re0,
+                &mut lhs_fourier_re1,
+                &mut lhs_fourier_im0,
+                &mut lhs_fourier_im1,
+            );
+
+            assert_eq!(result, negacyclic_convolution);
+        }
+    }
+
+    #[test]
+    fn test_product_u128_add_assign() {
+        for n in [64, 128, 256, 512, 1024, 2048] {
+            let mut lhs = vec![0u128; n];
+            let mut rhs = vec![0u128; n];
+            let mut acc = vec![0u128; n];
+            for x in &mut lhs {
+                *x = (random::<i64>() >> 14) as u128;
+            }
+            for x in &mut rhs {
+                *x = (random::<i32>() >> 12) as u128;
+            }
+            for x in &mut acc {
+                *x = random();
+            }
+
+            let mut expected = acc.clone();
+            for i in 0..n {
+                for j in 0..n {
+                    let prod = lhs[i].wrapping_mul(rhs[j]);
+                    if i + j < n {
+                        expected[i + j] = expected[i + j].wrapping_add(prod);
+                    } else {
+                        expected[i + j - n] = expected[i + j - n].wrapping_sub(prod);
+                    }
+                }
+            }
+
+            let plan = Plan::new(n / 2);
+
+            let mut lhs_fourier_re0 = vec![0.0; n / 2];
+            let mut lhs_fourier_re1 = vec![0.0; n / 2];
+            let mut lhs_fourier_im0 = vec![0.0; n / 2];
+            let mut lhs_fourier_im1 = vec![0.0; n / 2];
+
+            let mut rhs_fourier_re0 = vec![0.0; n / 2];
+            let mut rhs_fourier_re1 = vec![0.0; n / 2];
+            let mut rhs_fourier_im0 = vec![0.0; n / 2];
+            let mut rhs_fourier_im1 = vec![0.0; n / 2];
+
+            plan.fwd_u128(
+                &mut lhs_fourier_re0,
+                &mut lhs_fourier_re1,
+                &mut lhs_fourier_im0,
+                &mut lhs_fourier_im1,
+                &lhs,
+            );
+            plan.fwd_u128(
+                &mut rhs_fourier_re0,
+                &mut rhs_fourier_re1,
+                &mut rhs_fourier_im0,
+                &mut rhs_fourier_im1,
+                &rhs,
+            );
+
+            let simd = Scalar;
+            for i in 0..n / 2 {
+                let (prod_re, prod_im) = simd.cplx_mul(
+                    (lhs_fourier_re0[i], lhs_fourier_re1[i]),
+                    (lhs_fourier_im0[i], lhs_fourier_im1[i]),
+                    (rhs_fourier_re0[i], rhs_fourier_re1[i]),
+                    (rhs_fourier_im0[i], rhs_fourier_im1[i]),
+                );
+
+                (lhs_fourier_re0[i], lhs_fourier_re1[i]) = prod_re;
+                (lhs_fourier_im0[i], lhs_fourier_im1[i]) = prod_im;
+            }
+
+            plan.inv_u128_add_assign(
+                &mut acc,
+                &mut lhs_fourier_re0,
+                &mut lhs_fourier_re1,
+                &mut lhs_fourier_im0,
+                &mut lhs_fourier_im1,
+            );
+
+            assert_eq!(acc, expected);
+        }
+    }
+
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     #[test]
     fn test_product_avxfma() {
@@ -2302,7 +3295,7 @@ mod tests {
     }
 
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    #[cfg(feature = "nightly")]
+    #[cfg(feature = "avx512")]
     #[test]
     fn test_product_avx512() {
         if let Some(simd) = V4::try_new() {
@@ -2463,7 +3456,7 @@ mod x86_tests {
         }
     }
 
-    #[cfg(feature = "nightly")]
+    #[cfg(feature = "avx512")]
     #[test]
     fn test_interleaves_and_permutes_f64x8() {
         if let Some(simd) = V4::try_new() {
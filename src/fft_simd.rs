@@ -1,4 +1,4 @@
-use crate::c64;
+use crate::{c32, c64};
 use core::{fmt::Debug, marker::PhantomData};
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
@@ -7,24 +7,82 @@ use core::{fmt::Debug, marker::PhantomData};
 pub struct c64x2(c64, c64);
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-#[cfg(feature = "nightly")]
+#[cfg(feature = "avx512")]
 #[derive(Copy, Clone, Debug)]
 #[repr(C)]
 pub struct c64x4(c64, c64, c64, c64);
 
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct c32x4(c32, c32, c32, c32);
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(feature = "avx512")]
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct c32x8(c32, c32, c32, c32, c32, c32, c32, c32);
+
+#[cfg(target_arch = "aarch64")]
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct c64x1(c64);
+
+#[cfg(target_arch = "aarch64")]
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct c32x2(c32, c32);
+
+#[cfg(target_arch = "aarch64")]
+const __ASSERT_POD_AARCH64: () = {
+    // no padding
+    assert!(core::mem::size_of::<c64x1>() == core::mem::size_of::<c64>());
+    assert!(core::mem::size_of::<c32x2>() == core::mem::size_of::<c32>() * 2);
+};
+
+// SAFETY: c64 is Zeroable
+#[cfg(target_arch = "aarch64")]
+unsafe impl bytemuck::Zeroable for c64x1 {}
+
+// SAFETY: c32 is Zeroable
+#[cfg(target_arch = "aarch64")]
+unsafe impl bytemuck::Zeroable for c32x2 {}
+
+// SAFETY: c64 is Pod, c64x1 is repr(C) and has no padding
+#[cfg(target_arch = "aarch64")]
+unsafe impl bytemuck::Pod for c64x1 {}
+
+// SAFETY: c32 is Pod, c32x2 is repr(C) and has no padding
+#[cfg(target_arch = "aarch64")]
+unsafe impl bytemuck::Pod for c32x2 {}
+
+#[cfg(target_arch = "aarch64")]
+impl Pod for c64x1 {
+    type Lane = c64;
+}
+
+#[cfg(target_arch = "aarch64")]
+impl Pod for c32x2 {
+    type Lane = c32;
+}
+
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 const __ASSERT_POD: () = {
     #[allow(unknown_lints)]
     #[allow(clippy::extra_unused_type_parameters)]
     const fn assert_pod_zeroable<T: bytemuck::Pod + bytemuck::Zeroable>() {}
 
-    // c64 is Pod and Zeroable
+    // c64 and c32 are Pod and Zeroable
     assert_pod_zeroable::<c64>();
+    assert_pod_zeroable::<c32>();
 
     // no padding
     assert!(core::mem::size_of::<c64x2>() == core::mem::size_of::<c64>() * 2);
-    #[cfg(feature = "nightly")]
+    #[cfg(feature = "avx512")]
     assert!(core::mem::size_of::<c64x4>() == core::mem::size_of::<c64>() * 4);
+    assert!(core::mem::size_of::<c32x4>() == core::mem::size_of::<c32>() * 4);
+    #[cfg(feature = "avx512")]
+    assert!(core::mem::size_of::<c32x8>() == core::mem::size_of::<c32>() * 8);
 };
 
 // SAFETY: c64 is Zeroable
@@ -32,29 +90,96 @@ const __ASSERT_POD: () = {
 unsafe impl bytemuck::Zeroable for c64x2 {}
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-#[cfg(feature = "nightly")]
+#[cfg(feature = "avx512")]
 unsafe impl bytemuck::Zeroable for c64x4 {}
 
+// SAFETY: c32 is Zeroable
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+unsafe impl bytemuck::Zeroable for c32x4 {}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(feature = "avx512")]
+unsafe impl bytemuck::Zeroable for c32x8 {}
+
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 // SAFETY: c64 is Pod, c64x2, c64x4 are all repr(C) and have no padding
 unsafe impl bytemuck::Pod for c64x2 {}
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-#[cfg(feature = "nightly")]
+#[cfg(feature = "avx512")]
 unsafe impl bytemuck::Pod for c64x4 {}
 
-pub trait Pod: Copy + Debug + bytemuck::Pod {}
-impl<T: Copy + Debug + bytemuck::Pod> Pod for T {}
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+// SAFETY: c32 is Pod, c32x4, c32x8 are all repr(C) and have no padding
+unsafe impl bytemuck::Pod for c32x4 {}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(feature = "avx512")]
+unsafe impl bytemuck::Pod for c32x8 {}
+
+/// Vector of complex numbers, whose lanes all have the type `Lane`.
+pub trait Pod: Copy + Debug + bytemuck::Pod {
+    type Lane: Complex;
+}
+
+/// Complex scalar type, which can be processed by the FFT kernels.
+pub trait Complex: Pod<Lane = Self> + Default + PartialEq {
+    /// Converts a 64-bit complex number to `Self`, possibly losing precision.
+    fn from_c64(value: c64) -> Self;
+}
+
+impl Complex for c64 {
+    #[inline(always)]
+    fn from_c64(value: c64) -> Self {
+        value
+    }
+}
+
+impl Complex for c32 {
+    #[inline(always)]
+    fn from_c64(value: c64) -> Self {
+        c32 {
+            re: value.re as f32,
+            im: value.im as f32,
+        }
+    }
+}
+
+impl Pod for c64 {
+    type Lane = c64;
+}
+impl Pod for c32 {
+    type Lane = c32;
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl Pod for c64x2 {
+    type Lane = c64;
+}
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(feature = "avx512")]
+impl Pod for c64x4 {
+    type Lane = c64;
+}
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl Pod for c32x4 {
+    type Lane = c32;
+}
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(feature = "avx512")]
+impl Pod for c32x8 {
+    type Lane = c32;
+}
 
 // cos(-pi/8)
 pub const H1X: f64 = 0.9238795325112867f64;
 // sin(-pi/8)
 pub const H1Y: f64 = -0.38268343236508984f64;
 
-struct AssertC64Vec<T>(PhantomData<T>);
-impl<T> AssertC64Vec<T> {
+struct AssertComplexVec<T>(PhantomData<T>);
+impl<T: Pod> AssertComplexVec<T> {
     pub const VALID: () = {
-        assert!(core::mem::size_of::<T>() % core::mem::size_of::<c64>() == 0);
+        assert!(core::mem::size_of::<T>().is_multiple_of(core::mem::size_of::<T::Lane>()));
     };
 }
 
@@ -68,12 +193,12 @@ pub trait FftSimd<c64xN: Pod>: Copy + Debug {
     #[inline(always)]
     fn lane_count(self) -> usize {
         #[allow(clippy::let_unit_value)]
-        let _ = AssertC64Vec::<c64xN>::VALID;
-        core::mem::size_of::<c64xN>() / core::mem::size_of::<c64>()
+        let _ = AssertComplexVec::<c64xN>::VALID;
+        core::mem::size_of::<c64xN>() / core::mem::size_of::<c64xN::Lane>()
     }
 
     fn splat_f64(self, value: f64) -> c64xN;
-    fn splat(self, value: c64) -> c64xN;
+    fn splat(self, value: c64xN::Lane) -> c64xN;
     fn xor(self, a: c64xN, b: c64xN) -> c64xN;
     fn swap_re_im(self, xy: c64xN) -> c64xN;
     fn add(self, a: c64xN, b: c64xN) -> c64xN;
@@ -81,7 +206,8 @@ pub trait FftSimd<c64xN: Pod>: Copy + Debug {
     fn real_mul(self, a: c64xN, b: c64xN) -> c64xN;
     fn mul(self, a: c64xN, b: c64xN) -> c64xN;
 
-    // implemented only when `self.lane_count() == 2`
+    // implemented only when `self.lane_count()` is 2 or 8. concatenates the low (resp. high) halves
+    // of `a` and `b`
     fn catlo(self, a: c64xN, b: c64xN) -> c64xN {
         let _ = a;
         let _ = b;
@@ -93,7 +219,8 @@ pub trait FftSimd<c64xN: Pod>: Copy + Debug {
         unimplemented!()
     }
 
-    // implemented only when `self.lane_count() == 4`
+    // implemented only when `self.lane_count()` is 4 or 8. interleaves the lanes of `a`, `b`, `c`
+    // and `d`, which is a 4x4 transpose when there are 4 lanes
     fn transpose(self, a: c64xN, b: c64xN, c: c64xN, d: c64xN) -> (c64xN, c64xN, c64xN, c64xN) {
         let _ = a;
         let _ = b;
@@ -101,12 +228,25 @@ pub trait FftSimd<c64xN: Pod>: Copy + Debug {
         let _ = d;
         unimplemented!()
     }
+
+    // inverse of `transpose`. the 4x4 transpose is its own inverse, so this only needs to be
+    // overridden when there are 8 lanes
+    #[inline(always)]
+    fn untranspose(self, a: c64xN, b: c64xN, c: c64xN, d: c64xN) -> (c64xN, c64xN, c64xN, c64xN) {
+        self.transpose(a, b, c, d)
+    }
+
+    // implemented only when `self.lane_count() == 8`
+    fn transpose_8x8(self, x: [c64xN; 8]) -> [c64xN; 8] {
+        let _ = x;
+        unimplemented!()
+    }
 }
 
 pub trait FftSimdExt<c64xN: Pod>: FftSimd<c64xN> {
     #[inline(always)]
     fn conj(self, xy: c64xN) -> c64xN {
-        let mask = self.splat(c64 { re: 0.0, im: -0.0 });
+        let mask = self.splat(c64xN::Lane::from_c64(c64 { re: 0.0, im: -0.0 }));
         self.xor(xy, mask)
     }
 
@@ -133,18 +273,30 @@ pub trait FftSimdExt<c64xN: Pod>: FftSimd<c64xN> {
     #[inline(always)]
     fn mul_exp_pi_over_16(self, fwd: bool, xy: c64xN) -> c64xN {
         if fwd {
-            self.mul(self.splat(c64 { re: H1X, im: H1Y }), xy)
+            self.mul(
+                self.splat(c64xN::Lane::from_c64(c64 { re: H1X, im: H1Y })),
+                xy,
+            )
         } else {
-            self.mul(self.splat(c64 { re: H1X, im: -H1Y }), xy)
+            self.mul(
+                self.splat(c64xN::Lane::from_c64(c64 { re: H1X, im: -H1Y })),
+                xy,
+            )
         }
     }
 
     #[inline(always)]
     fn mul_exp_17pi_over_16(self, fwd: bool, xy: c64xN) -> c64xN {
         if fwd {
-            self.mul(self.splat(c64 { re: -H1Y, im: -H1X }), xy)
+            self.mul(
+                self.splat(c64xN::Lane::from_c64(c64 { re: -H1Y, im: -H1X })),
+                xy,
+            )
         } else {
-            self.mul(self.splat(c64 { re: -H1Y, im: H1X }), xy)
+            self.mul(
+                self.splat(c64xN::Lane::from_c64(c64 { re: -H1Y, im: H1X })),
+                xy,
+            )
         }
     }
 
@@ -233,6 +385,177 @@ impl FftSimd<c64> for Scalar {
     }
 }
 
+impl FftSimd<c32> for Scalar {
+    #[inline(always)]
+    fn try_new() -> Option<Self> {
+        Some(Scalar)
+    }
+
+    #[inline(always)]
+    fn splat_f64(self, value: f64) -> c32 {
+        c32 {
+            re: value as f32,
+            im: value as f32,
+        }
+    }
+
+    #[inline(always)]
+    fn splat(self, value: c32) -> c32 {
+        value
+    }
+
+    #[inline(always)]
+    fn xor(self, a: c32, b: c32) -> c32 {
+        let a: u64 = pulp::cast(a);
+        let b: u64 = pulp::cast(b);
+        pulp::cast(a ^ b)
+    }
+
+    #[inline(always)]
+    fn swap_re_im(self, xy: c32) -> c32 {
+        c32 {
+            re: xy.im,
+            im: xy.re,
+        }
+    }
+
+    #[inline(always)]
+    fn add(self, a: c32, b: c32) -> c32 {
+        a + b
+    }
+
+    #[inline(always)]
+    fn sub(self, a: c32, b: c32) -> c32 {
+        a - b
+    }
+
+    #[inline(always)]
+    fn real_mul(self, a: c32, b: c32) -> c32 {
+        c32 {
+            re: a.re * b.re,
+            im: a.im * b.im,
+        }
+    }
+
+    #[inline(always)]
+    fn mul(self, a: c32, b: c32) -> c32 {
+        let ab = a;
+        let xy = b;
+
+        let a = ab.re;
+        let b = ab.im;
+        let x = xy.re;
+        let y = xy.im;
+
+        c32 {
+            re: f32::mul_add(a, x, -b * y),
+            im: f32::mul_add(a, y, b * x),
+        }
+    }
+}
+
+/// Computes `z[i] *= w[i]` for all `i`.
+#[inline(always)]
+pub fn mul_assign<c64xN: Pod>(simd: impl FftSimd<c64xN>, z: &mut [c64xN::Lane], w: &[c64xN::Lane])
+where
+    Scalar: FftSimd<c64xN::Lane>,
+{
+    assert_eq!(z.len(), w.len());
+    let split = z.len() - z.len() % simd.lane_count();
+
+    let (z_head, z_tail) = z.split_at_mut(split);
+    let (w_head, w_tail) = w.split_at(split);
+
+    let z_head: &mut [c64xN] = bytemuck::cast_slice_mut(z_head);
+    let w_head: &[c64xN] = bytemuck::cast_slice(w_head);
+    for (z, w) in z_head.iter_mut().zip(w_head) {
+        *z = simd.mul(*z, *w);
+    }
+    for (z, w) in z_tail.iter_mut().zip(w_tail) {
+        *z = Scalar.mul(*z, *w);
+    }
+}
+
+/// Computes `z[i] += w[i]` for all `i`.
+#[inline(always)]
+pub fn add_assign<c64xN: Pod>(simd: impl FftSimd<c64xN>, z: &mut [c64xN::Lane], w: &[c64xN::Lane])
+where
+    Scalar: FftSimd<c64xN::Lane>,
+{
+    assert_eq!(z.len(), w.len());
+    let split = z.len() - z.len() % simd.lane_count();
+
+    let (z_head, z_tail) = z.split_at_mut(split);
+    let (w_head, w_tail) = w.split_at(split);
+
+    let z_head: &mut [c64xN] = bytemuck::cast_slice_mut(z_head);
+    let w_head: &[c64xN] = bytemuck::cast_slice(w_head);
+    for (z, w) in z_head.iter_mut().zip(w_head) {
+        *z = simd.add(*z, *w);
+    }
+    for (z, w) in z_tail.iter_mut().zip(w_tail) {
+        *z = Scalar.add(*z, *w);
+    }
+}
+
+/// Computes `acc[i] += a[i] * b[i]` for all `i`.
+#[inline(always)]
+pub fn mul_add_assign<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    acc: &mut [c64xN::Lane],
+    a: &[c64xN::Lane],
+    b: &[c64xN::Lane],
+) where
+    Scalar: FftSimd<c64xN::Lane>,
+{
+    assert_eq!(acc.len(), a.len());
+    assert_eq!(acc.len(), b.len());
+    let split = acc.len() - acc.len() % simd.lane_count();
+
+    let (acc_head, acc_tail) = acc.split_at_mut(split);
+    let (a_head, a_tail) = a.split_at(split);
+    let (b_head, b_tail) = b.split_at(split);
+
+    let acc_head: &mut [c64xN] = bytemuck::cast_slice_mut(acc_head);
+    let a_head: &[c64xN] = bytemuck::cast_slice(a_head);
+    let b_head: &[c64xN] = bytemuck::cast_slice(b_head);
+    for (acc, a, b) in izip!(acc_head, a_head, b_head) {
+        *acc = simd.add(*acc, simd.mul(*a, *b));
+    }
+    for (acc, a, b) in izip!(acc_tail, a_tail, b_tail) {
+        *acc = Scalar.add(*acc, Scalar.mul(*a, *b));
+    }
+}
+
+/// Computes `acc[i] += a[i] * conj(b[i])` for all `i`.
+#[inline(always)]
+pub fn mul_conj_add_assign<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    acc: &mut [c64xN::Lane],
+    a: &[c64xN::Lane],
+    b: &[c64xN::Lane],
+) where
+    Scalar: FftSimd<c64xN::Lane>,
+{
+    assert_eq!(acc.len(), a.len());
+    assert_eq!(acc.len(), b.len());
+    let split = acc.len() - acc.len() % simd.lane_count();
+
+    let (acc_head, acc_tail) = acc.split_at_mut(split);
+    let (a_head, a_tail) = a.split_at(split);
+    let (b_head, b_tail) = b.split_at(split);
+
+    let acc_head: &mut [c64xN] = bytemuck::cast_slice_mut(acc_head);
+    let a_head: &[c64xN] = bytemuck::cast_slice(a_head);
+    let b_head: &[c64xN] = bytemuck::cast_slice(b_head);
+    for (acc, a, b) in izip!(acc_head, a_head, b_head) {
+        *acc = simd.add(*acc, simd.mul(*a, simd.conj(*b)));
+    }
+    for (acc, a, b) in izip!(acc_tail, a_tail, b_tail) {
+        *acc = Scalar.add(*acc, Scalar.mul(*a, Scalar.conj(*b)));
+    }
+}
+
 // https://stackoverflow.com/a/42792940
 pub fn sincospi64(mut a: f64) -> (f64, f64) {
     let fma = f64::mul_add;
@@ -295,7 +618,7 @@ pub fn sincospi64(mut a: f64) -> (f64, f64) {
     (s, c)
 }
 
-pub fn init_wt(r: usize, n: usize, w: &mut [c64], w_inv: &mut [c64]) {
+pub fn init_wt<T: Complex>(r: usize, n: usize, w: &mut [T], w_inv: &mut [T]) {
     if n < r {
         return;
     }
@@ -304,18 +627,20 @@ pub fn init_wt(r: usize, n: usize, w: &mut [c64], w_inv: &mut [c64]) {
     let theta = -2.0 / n as f64;
 
     for wi in w.iter_mut() {
-        wi.re = f64::NAN;
-        wi.im = f64::NAN;
+        *wi = T::from_c64(c64 {
+            re: f64::NAN,
+            im: f64::NAN,
+        });
     }
 
     for p in 0..nr {
         for k in 1..r {
             let (s, c) = sincospi64(theta * (k * p) as f64);
             let z = c64::new(c, s);
-            w[p + k * nr] = z;
-            w[n + r * p + k] = z;
-            w_inv[p + k * nr] = z.conj();
-            w_inv[n + r * p + k] = z.conj();
+            w[p + k * nr] = T::from_c64(z);
+            w[n + r * p + k] = T::from_c64(z);
+            w_inv[p + k * nr] = T::from_c64(z.conj());
+            w_inv[n + r * p + k] = T::from_c64(z.conj());
         }
     }
 }
@@ -1,7 +1,8 @@
 //! Concrete-FFT is a pure Rust high performance fast Fourier transform library that processes
-//! vectors of sizes that are powers of two.
+//! vectors of sizes that are powers of two, while the ordered module also supports arbitrary
+//! sizes.
 //!
-//! This library provides two FFT modules:
+//! This library provides three FFT modules:
 //!  - The ordered module FFT applies a forward/inverse FFT that takes its input in standard
 //!  order, and outputs the result in standard order. For more detail on what the FFT
 //!  computes, check the ordered module-level documentation.
@@ -12,8 +13,26 @@
 //!  Fourier domain is not important. An example is using the Fourier transform for vector
 //!  convolution. The only operations that are performed in the Fourier domain are elementwise, and
 //!  so the order of the coefficients does not affect the results.
+//!  - The negacyclic module FFT applies a forward/inverse negacyclic FFT to real polynomials,
+//!  which is useful for computing products of polynomials modulo $X^n + 1$. It is built on top
+//!  of the unordered module, and so it outputs its result in the same permuted order.
 //!
-//! Additionally, an optional 128-bit negacyclic FFT module is provided.
+//! The polymul module computes cyclic and negacyclic products of polynomials. It is built on top
+//! of the unordered and negacyclic modules, and handles the zero-padding and the normalization of
+//! the result.
+//!
+//! The ordered and unordered modules also provide single precision plans, `Plan32`, which operate
+//! on [`c32`] values instead of [`c64`], and `SplitPlan`, which operates on split-complex
+//! vectors whose real and imaginary parts are stored in two separate slices.
+//!
+//! The dct module computes type-II and type-III discrete cosine and sine transforms of real
+//! vectors. It is built on top of the ordered module.
+//!
+//! The ntt module computes number-theoretic transforms modulo 64-bit primes, for exact integer
+//! convolutions. Its Fourier domain is in bit-reversed order, which differs from the order used
+//! by the unordered module.
+//!
+//! Additionally, an optional 128-bit FFT module is provided, with negacyclic and cyclic plans.
 //!
 //! # Features
 //!
@@ -21,15 +40,22 @@
 //!  an FFT plan that measures the various implementations to choose the fastest one at runtime.
 //!  - `fft128`: This flag provides access to the 128-bit FFT, which is accessible in the
 //!  `fft128` module.
-//!  - `nightly`: This enables unstable Rust features to further speed up the FFT, by enabling
-//!  AVX512F instructions on CPUs that support them. This feature requires a nightly Rust
-//!  toolchain.
+//!  - `avx512`: This further speeds up the FFT by enabling AVX512F instructions on CPUs that
+//!  support them. The instruction set is still detected at runtime, so the resulting binary also
+//!  runs on CPUs without AVX512F.
+//!  - `nightly`: Kept for backward compatibility, this is an alias for `avx512` and no longer
+//!  requires a nightly Rust toolchain.
 //!  - `serde`: This enables serialization and deserialization functions for the unordered plan.
 //!  These allow for data in the Fourier domain to be serialized from the permuted order to the
 //!  standard order, and deserialized from the standard order to the permuted order.
 //!  This is needed since the inverse transform must be used with the same plan that
 //!  computed/deserialized the forward transform (or more specifically, a plan with the same
-//!  internal base FFT size).
+//!  internal base FFT size). It also implements `Serialize` and `Deserialize` for the ordered,
+//!  unordered and 128-bit plans, which only store their configuration and rebuild their tables
+//!  when deserialized. The SIMD instruction set of a plan is not serialized, and deserialized
+//!  plans use the best one available at runtime.
+//!  - `rayon`: This enables multithreading of the large unordered FFTs using a `rayon` thread
+//!  pool. This feature implies `std`.
 //!
 //! # Example
 #![cfg_attr(feature = "std", doc = "```")]
@@ -78,11 +104,14 @@
 use core::marker::PhantomData;
 
 use fft_simd::{FftSimd, Pod};
-use num_complex::Complex64;
+use num_complex::{Complex32, Complex64};
 
 /// 64-bit complex floating point type.
 pub type c64 = Complex64;
 
+/// 32-bit complex floating point type.
+pub type c32 = Complex32;
+
 macro_rules! izip {
     // implemented this way to avoid a bug with type hints in rust-analyzer
     // https://github.com/rust-lang/rust-analyzer/issues/13526
@@ -117,8 +146,152 @@ macro_rules! izip {
     };
 }
 
+// generates a function that returns a function pointer to `$impl`, instantiated with the best
+// simd instruction set that is allowed by `level` and available at runtime
+macro_rules! dispatcher {
+    ($name: ident, $impl: ident) => {
+        #[cfg_attr(
+            not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")),
+            allow(unused_variables)
+        )]
+        fn $name(level: crate::SimdLevel) -> fn(&mut [c64], &[c64]) {
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            {
+                #[cfg(feature = "avx512")]
+                if level.v4().is_some() {
+                    return |z, w| {
+                        let simd = pulp::x86::V4::try_new().unwrap();
+                        simd.vectorize(
+                            #[inline(always)]
+                            || $impl::<crate::fft_simd::c64x4>(simd, z, w),
+                        );
+                    };
+                }
+
+                if level.v3().is_some() {
+                    return |z, w| {
+                        let simd = pulp::x86::V3::try_new().unwrap();
+                        simd.vectorize(
+                            #[inline(always)]
+                            || $impl::<crate::fft_simd::c64x2>(simd, z, w),
+                        );
+                    };
+                }
+            }
+
+            #[cfg(target_arch = "aarch64")]
+            {
+                if level.neon().is_some() {
+                    return |z, w| {
+                        let simd = pulp::aarch64::Neon::try_new().unwrap();
+                        simd.vectorize(
+                            #[inline(always)]
+                            || $impl::<crate::fft_simd::c64x1>(simd, z, w),
+                        );
+                    };
+                }
+            }
+
+            |z, w| $impl::<c64>(crate::fft_simd::Scalar, z, w)
+        }
+    };
+    (c32, $name: ident, $impl: ident) => {
+        #[cfg_attr(
+            not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")),
+            allow(unused_variables)
+        )]
+        fn $name(level: crate::SimdLevel) -> fn(&mut [c32], &[c32]) {
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            {
+                #[cfg(feature = "avx512")]
+                if level.v4().is_some() {
+                    return |z, w| {
+                        let simd = pulp::x86::V4::try_new().unwrap();
+                        simd.vectorize(
+                            #[inline(always)]
+                            || $impl::<crate::fft_simd::c32x8>(simd, z, w),
+                        );
+                    };
+                }
+
+                if level.v3().is_some() {
+                    return |z, w| {
+                        let simd = pulp::x86::V3::try_new().unwrap();
+                        simd.vectorize(
+                            #[inline(always)]
+                            || $impl::<crate::fft_simd::c32x4>(simd, z, w),
+                        );
+                    };
+                }
+            }
+
+            #[cfg(target_arch = "aarch64")]
+            {
+                if level.neon().is_some() {
+                    return |z, w| {
+                        let simd = pulp::aarch64::Neon::try_new().unwrap();
+                        simd.vectorize(
+                            #[inline(always)]
+                            || $impl::<crate::fft_simd::c32x2>(simd, z, w),
+                        );
+                    };
+                }
+            }
+
+            |z, w| $impl::<c32>(crate::fft_simd::Scalar, z, w)
+        }
+    };
+    (acc, $name: ident, $impl: ident) => {
+        #[cfg_attr(
+            not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")),
+            allow(unused_variables)
+        )]
+        fn $name(level: crate::SimdLevel) -> fn(&mut [c64], &[c64], &[c64]) {
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            {
+                #[cfg(feature = "avx512")]
+                if level.v4().is_some() {
+                    return |acc, a, b| {
+                        let simd = pulp::x86::V4::try_new().unwrap();
+                        simd.vectorize(
+                            #[inline(always)]
+                            || $impl::<crate::fft_simd::c64x4>(simd, acc, a, b),
+                        );
+                    };
+                }
+
+                if level.v3().is_some() {
+                    return |acc, a, b| {
+                        let simd = pulp::x86::V3::try_new().unwrap();
+                        simd.vectorize(
+                            #[inline(always)]
+                            || $impl::<crate::fft_simd::c64x2>(simd, acc, a, b),
+                        );
+                    };
+                }
+            }
+
+            #[cfg(target_arch = "aarch64")]
+            {
+                if level.neon().is_some() {
+                    return |acc, a, b| {
+                        let simd = pulp::aarch64::Neon::try_new().unwrap();
+                        simd.vectorize(
+                            #[inline(always)]
+                            || $impl::<crate::fft_simd::c64x1>(simd, acc, a, b),
+                        );
+                    };
+                }
+            }
+
+            |acc, a, b| $impl::<c64>(crate::fft_simd::Scalar, acc, a, b)
+        }
+    };
+}
+
 mod fft_simd;
 mod nat;
+mod split;
 
 #[cfg(feature = "std")]
 pub(crate) mod time;
@@ -126,17 +299,20 @@ pub(crate) mod time;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod x86;
 
-type FnArray = [fn(&mut [c64], &mut [c64], &[c64], &[c64]); 10];
+#[cfg(target_arch = "aarch64")]
+mod aarch64;
+
+type FnArray<T> = [fn(Option<&[T]>, &mut [T], &mut [T], &[T], &[T]); 10];
 
 #[derive(Copy, Clone)]
-struct FftImpl {
-    fwd: FnArray,
-    inv: FnArray,
+struct FftImpl<T> {
+    fwd: FnArray<T>,
+    inv: FnArray<T>,
 }
 
-impl FftImpl {
+impl<T> FftImpl<T> {
     #[inline]
-    pub fn make_fn_ptr(&self, n: usize) -> [fn(&mut [c64], &mut [c64], &[c64], &[c64]); 2] {
+    pub fn make_fn_ptr(&self, n: usize) -> [fn(Option<&[T]>, &mut [T], &mut [T], &[T], &[T]); 2] {
         let idx = n.trailing_zeros() as usize - 1;
         [self.fwd[idx], self.inv[idx]]
     }
@@ -152,27 +328,51 @@ trait RecursiveFft: nat::Nat {
         x: &mut [c64xN],
         y: &mut [c64xN],
         w_init: &[c64xN],
-        w: &[c64],
+        w: &[c64xN::Lane],
+    );
+
+    /// Computes the FFT of `src`, and stores the result in `x`, using `y` as scratch space.
+    /// `src` is only read by the first pass.
+    fn fft_recurse_out_of_place_impl<c64xN: Pod>(
+        simd: impl FftSimd<c64xN>,
+        fwd: bool,
+        s: usize,
+        src: &[c64xN],
+        x: &mut [c64xN],
+        y: &mut [c64xN],
+        w_init: &[c64xN],
+        w: &[c64xN::Lane],
     );
 }
 
 #[inline]
 fn fn_ptr<const FWD: bool, N: RecursiveFft, c64xN: Pod, Simd: FftSimd<c64xN>>(
     simd: Simd,
-) -> fn(&mut [c64], &mut [c64], &[c64], &[c64]) {
+) -> fn(
+    Option<&[c64xN::Lane]>,
+    &mut [c64xN::Lane],
+    &mut [c64xN::Lane],
+    &[c64xN::Lane],
+    &[c64xN::Lane],
+) {
     // we can't pass `simd` to the closure even though it's a zero-sized struct,
     // because we want the closure to be coercible to a function pointer.
     // so we ignore the passed parameter and reconstruct it inside the closure -------------
     let _ = simd;
 
     #[inline(never)]
-    |buf: &mut [c64], scratch: &mut [c64], w_init: &[c64], w: &[c64]| {
-        struct Impl<'a, const FWD: bool, N, c64xN, Simd> {
+    |src: Option<&[c64xN::Lane]>,
+     buf: &mut [c64xN::Lane],
+     scratch: &mut [c64xN::Lane],
+     w_init: &[c64xN::Lane],
+     w: &[c64xN::Lane]| {
+        struct Impl<'a, const FWD: bool, N, c64xN: Pod, Simd> {
             simd: Simd,
-            buf: &'a mut [c64],
-            scratch: &'a mut [c64],
-            w_init: &'a [c64],
-            w: &'a [c64],
+            src: Option<&'a [c64xN::Lane]>,
+            buf: &'a mut [c64xN::Lane],
+            scratch: &'a mut [c64xN::Lane],
+            w_init: &'a [c64xN::Lane],
+            w: &'a [c64xN::Lane],
             __marker: PhantomData<(N, c64xN)>,
         }
         // `simd` is reconstructed here. we know the unwrap can never fail because it was already
@@ -190,6 +390,7 @@ fn fn_ptr<const FWD: bool, N: RecursiveFft, c64xN: Pod, Simd: FftSimd<c64xN>>(
             fn call(self) -> Self::Output {
                 let Self {
                     simd,
+                    src,
                     buf,
                     scratch,
                     w_init,
@@ -201,21 +402,37 @@ fn fn_ptr<const FWD: bool, N: RecursiveFft, c64xN: Pod, Simd: FftSimd<c64xN>>(
                 assert_eq!(scratch.len(), n);
                 assert_eq!(w_init.len(), n);
                 assert_eq!(w.len(), n);
-                N::fft_recurse_impl(
-                    simd,
-                    FWD,
-                    true,
-                    1,
-                    bytemuck::cast_slice_mut(buf),
-                    bytemuck::cast_slice_mut(scratch),
-                    bytemuck::cast_slice(w_init),
-                    w,
-                );
+                match src {
+                    None => N::fft_recurse_impl(
+                        simd,
+                        FWD,
+                        true,
+                        1,
+                        bytemuck::cast_slice_mut(buf),
+                        bytemuck::cast_slice_mut(scratch),
+                        bytemuck::cast_slice(w_init),
+                        w,
+                    ),
+                    Some(src) => {
+                        assert_eq!(src.len(), n);
+                        N::fft_recurse_out_of_place_impl(
+                            simd,
+                            FWD,
+                            1,
+                            bytemuck::cast_slice(src),
+                            bytemuck::cast_slice_mut(buf),
+                            bytemuck::cast_slice_mut(scratch),
+                            bytemuck::cast_slice(w_init),
+                            w,
+                        )
+                    }
+                }
             }
         }
 
         simd.vectorize(Impl::<FWD, N, c64xN, Simd> {
             simd,
+            src,
             buf,
             scratch,
             w_init,
@@ -234,11 +451,257 @@ mod dit4;
 mod dif8;
 mod dit8;
 
+/// Error returned by the fallible plan constructors, when the requested plan is not supported.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PlanError {
+    /// The FFT size is not a power of two.
+    NotPowerOfTwo {
+        /// Requested size.
+        size: usize,
+    },
+    /// The FFT size is smaller than the smallest size supported by the plan.
+    TooSmall {
+        /// Requested size.
+        size: usize,
+        /// Smallest supported size.
+        min: usize,
+    },
+    /// The FFT size is larger than the largest size supported by the plan.
+    TooLarge {
+        /// Requested size.
+        size: usize,
+        /// Largest supported size.
+        max: usize,
+    },
+    /// The base FFT size is smaller than the smallest base size supported by the plan.
+    BaseTooSmall {
+        /// Requested base size.
+        base_n: usize,
+        /// Smallest supported base size.
+        min: usize,
+    },
+    /// The wisdom used to select the plan has no entry for the FFT size and the SIMD instruction
+    /// set of the current machine.
+    MissingWisdom {
+        /// Requested size.
+        size: usize,
+    },
+    /// The requested SIMD instruction set is not available on the current machine, or was not
+    /// enabled at compile time.
+    SimdUnavailable {
+        /// Requested instruction set.
+        simd: SimdLevel,
+    },
+    /// The modulus of a number-theoretic transform is not an odd prime such that the transform
+    /// size divides `modulus - 1`.
+    InvalidModulus {
+        /// Requested modulus.
+        modulus: u64,
+        /// Requested size.
+        size: usize,
+    },
+}
+
+impl core::fmt::Display for PlanError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            PlanError::NotPowerOfTwo { size } => {
+                write!(f, "FFT size {size} is not a power of two")
+            }
+            PlanError::TooSmall { size, min } => {
+                write!(f, "FFT size {size} is smaller than the minimum size {min}")
+            }
+            PlanError::TooLarge { size, max } => {
+                write!(f, "FFT size {size} is larger than the maximum size {max}")
+            }
+            PlanError::BaseTooSmall { base_n, min } => {
+                write!(
+                    f,
+                    "base FFT size {base_n} is smaller than the minimum base size {min}"
+                )
+            }
+            PlanError::MissingWisdom { size } => {
+                write!(f, "no wisdom is available for FFT size {size}")
+            }
+            PlanError::SimdUnavailable { simd } => {
+                write!(f, "SIMD instruction set {simd:?} is not available")
+            }
+            PlanError::InvalidModulus { modulus, size } => {
+                write!(
+                    f,
+                    "modulus {modulus} is not an odd prime such that {size} divides {modulus} - 1"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PlanError {}
+
+/// Returns an error if `size` is not a power of two.
+fn check_power_of_two(size: usize) -> Result<(), PlanError> {
+    if size.is_power_of_two() {
+        Ok(())
+    } else {
+        Err(PlanError::NotPowerOfTwo { size })
+    }
+}
+
+/// Returns an error if `simd` is not available on the current machine.
+fn check_simd_level(simd: SimdLevel) -> Result<(), PlanError> {
+    if simd.is_available() {
+        Ok(())
+    } else {
+        Err(PlanError::SimdUnavailable { simd })
+    }
+}
+
+/// SIMD instruction set used by the FFT kernels.
+///
+/// The plans use the best instruction set that is available at runtime by default. A specific one
+/// can be requested with constructors such as [`ordered::Plan::new_with_simd`], for example to get
+/// reproducible results across machines, or to avoid AVX512 frequency throttling.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum SimdLevel {
+    /// Portable scalar code.
+    Scalar,
+    /// x86 AVX2 and FMA instructions.
+    Avx2,
+    /// x86 AVX512F instructions.
+    Avx512,
+    /// aarch64 NEON instructions.
+    Neon,
+}
+
+impl SimdLevel {
+    /// Returns the instruction set that is selected by the FFT plans on the current machine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::SimdLevel;
+    ///
+    /// let simd = SimdLevel::detect();
+    /// ```
+    pub fn detect() -> Self {
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        {
+            #[cfg(feature = "avx512")]
+            if pulp::x86::V4::try_new().is_some() {
+                return SimdLevel::Avx512;
+            }
+
+            if pulp::x86::V3::try_new().is_some() {
+                return SimdLevel::Avx2;
+            }
+        }
+
+        #[cfg(target_arch = "aarch64")]
+        {
+            if pulp::aarch64::Neon::try_new().is_some() {
+                return SimdLevel::Neon;
+            }
+        }
+
+        SimdLevel::Scalar
+    }
+
+    /// Returns whether the instruction set can be used on the current machine.
+    ///
+    /// [`SimdLevel::Avx512`] is only available when the `avx512` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::SimdLevel;
+    ///
+    /// assert!(SimdLevel::Scalar.is_available());
+    /// assert!(SimdLevel::detect().is_available());
+    /// ```
+    pub fn is_available(self) -> bool {
+        match self {
+            SimdLevel::Scalar => true,
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            SimdLevel::Avx2 => pulp::x86::V3::try_new().is_some(),
+            #[cfg(all(feature = "avx512", any(target_arch = "x86_64", target_arch = "x86")))]
+            SimdLevel::Avx512 => pulp::x86::V4::try_new().is_some(),
+            #[cfg(target_arch = "aarch64")]
+            SimdLevel::Neon => pulp::aarch64::Neon::try_new().is_some(),
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+
+    // the kernels fall back to the next instruction set when the FFT is too small for the
+    // requested one, so `Avx512` also allows AVX2 instructions
+
+    #[cfg(all(feature = "avx512", any(target_arch = "x86_64", target_arch = "x86")))]
+    pub(crate) fn v4(self) -> Option<pulp::x86::V4> {
+        match self {
+            SimdLevel::Avx512 => pulp::x86::V4::try_new(),
+            _ => None,
+        }
+    }
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    pub(crate) fn v3(self) -> Option<pulp::x86::V3> {
+        match self {
+            SimdLevel::Avx2 | SimdLevel::Avx512 => pulp::x86::V3::try_new(),
+            _ => None,
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    pub(crate) fn neon(self) -> Option<pulp::aarch64::Neon> {
+        match self {
+            SimdLevel::Neon => pulp::aarch64::Neon::try_new(),
+            _ => None,
+        }
+    }
+
+    // returns the `pulp` architecture that corresponds to the instruction set
+    pub(crate) fn arch(self) -> pulp::Arch {
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        {
+            #[cfg(feature = "avx512")]
+            if let Some(simd) = self.v4() {
+                return pulp::Arch::V4(simd);
+            }
+            if let Some(simd) = self.v3() {
+                return pulp::Arch::V3(simd);
+            }
+            pulp::Arch::Scalar
+        }
+        #[cfg(target_arch = "aarch64")]
+        {
+            match self.neon() {
+                Some(simd) => pulp::Arch::Neon(simd),
+                None => pulp::Arch::Scalar,
+            }
+        }
+        #[cfg(not(any(target_arch = "x86_64", target_arch = "x86", target_arch = "aarch64")))]
+        {
+            pulp::Arch::new()
+        }
+    }
+}
+
 mod dif16;
 mod dit16;
 
+mod mixed_radix;
+
+pub mod dct;
+pub mod negacyclic;
+pub mod ntt;
 pub mod ordered;
+pub mod polymul;
 pub mod unordered;
+pub mod wisdom;
 
 #[cfg(feature = "fft128")]
 #[cfg_attr(docsrs, doc(cfg(feature = "fft128")))]
@@ -0,0 +1,479 @@
+#[cfg(target_arch = "aarch64")]
+use crate::fft_simd::{c32x2, c64x1};
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::fft_simd::{c32x4, c64x2};
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx512"))]
+use crate::fft_simd::{c32x8, c64x4};
+use crate::{
+    c32, c64,
+    dif4::split_4,
+    fft_simd::{Complex, FftSimd, FftSimdExt, Pod, Scalar},
+};
+
+// cos(2pi/3), sin(2pi/3)
+const W3X: f64 = -0.5f64;
+const W3Y: f64 = 0.8660254037844386f64;
+// cos(2pi/5), sin(2pi/5)
+const W5X1: f64 = 0.30901699437494745f64;
+const W5Y1: f64 = 0.9510565162951535f64;
+// cos(4pi/5), sin(4pi/5)
+const W5X2: f64 = -0.8090169943749475f64;
+const W5Y2: f64 = 0.5877852522924731f64;
+
+/// Returns `true` if the prime factors of `n` are all in `{2, 3, 5}`.
+pub fn is_supported(n: usize) -> bool {
+    if n == 0 {
+        return false;
+    }
+    let mut n = n;
+    for p in [2, 3, 5] {
+        while n.is_multiple_of(p) {
+            n /= p;
+        }
+    }
+    n == 1
+}
+
+/// Returns the radix of the next stage of an FFT of size `n`.
+///
+/// Radix-4 stages are applied first, followed by at most one radix-2 stage, then the radix-3 and
+/// radix-5 stages.
+#[inline(always)]
+fn next_radix(n: usize) -> usize {
+    if n.is_multiple_of(4) {
+        4
+    } else if n.is_multiple_of(2) {
+        2
+    } else if n.is_multiple_of(3) {
+        3
+    } else {
+        debug_assert_eq!(n % 5, 0);
+        5
+    }
+}
+
+#[inline(always)]
+fn butterfly<const R: usize, c64xN: Pod>(simd: impl FftSimd<c64xN>, fwd: bool, z: &mut [c64xN; R]) {
+    match R {
+        2 => {
+            let a = z[0];
+            let b = z[1];
+            z[0] = simd.add(a, b);
+            z[1] = simd.sub(a, b);
+        }
+        3 => {
+            let a = z[0];
+            let b = z[1];
+            let c = z[2];
+
+            let bpc = simd.add(b, c);
+            let t = simd.add(a, simd.real_mul(simd.splat_f64(W3X), bpc));
+            let jbmc = simd.mul_j(fwd, simd.real_mul(simd.splat_f64(W3Y), simd.sub(b, c)));
+
+            z[0] = simd.add(a, bpc);
+            z[1] = simd.sub(t, jbmc);
+            z[2] = simd.add(t, jbmc);
+        }
+        4 => {
+            let a = z[0];
+            let b = z[1];
+            let c = z[2];
+            let d = z[3];
+
+            let apc = simd.add(a, c);
+            let amc = simd.sub(a, c);
+            let bpd = simd.add(b, d);
+            let jbmd = simd.mul_j(fwd, simd.sub(b, d));
+
+            z[0] = simd.add(apc, bpd);
+            z[1] = simd.sub(amc, jbmd);
+            z[2] = simd.sub(apc, bpd);
+            z[3] = simd.add(amc, jbmd);
+        }
+        5 => {
+            let a = z[0];
+            let b = z[1];
+            let c = z[2];
+            let d = z[3];
+            let e = z[4];
+
+            let bpe = simd.add(b, e);
+            let cpd = simd.add(c, d);
+            let bme = simd.sub(b, e);
+            let cmd = simd.sub(c, d);
+
+            let x1 = simd.splat_f64(W5X1);
+            let x2 = simd.splat_f64(W5X2);
+            let y1 = simd.splat_f64(W5Y1);
+            let y2 = simd.splat_f64(W5Y2);
+
+            let t1 = simd.add(a, simd.add(simd.real_mul(x1, bpe), simd.real_mul(x2, cpd)));
+            let t2 = simd.add(a, simd.add(simd.real_mul(x2, bpe), simd.real_mul(x1, cpd)));
+            let u1 = simd.mul_j(
+                fwd,
+                simd.add(simd.real_mul(y1, bme), simd.real_mul(y2, cmd)),
+            );
+            let u2 = simd.mul_j(
+                fwd,
+                simd.sub(simd.real_mul(y2, bme), simd.real_mul(y1, cmd)),
+            );
+
+            z[0] = simd.add(a, simd.add(bpe, cpd));
+            z[1] = simd.sub(t1, u1);
+            z[2] = simd.sub(t2, u2);
+            z[3] = simd.add(t2, u2);
+            z[4] = simd.add(t1, u1);
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Performs one radix-`R` stage of the Stockham algorithm with stride `s`, which must be a
+/// multiple of the lane count.
+#[inline(always)]
+fn stockham_core_generic<const R: usize, c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    fwd: bool,
+    s: usize,
+    x: &[c64xN],
+    y: &mut [c64xN],
+    w: &[c64xN::Lane],
+) {
+    assert_eq!(s % simd.lane_count(), 0);
+    let simd_s = s / simd.lane_count();
+    let m = x.len() / (R * simd_s);
+
+    let x: [&[c64xN]; R] = core::array::from_fn(|k| &x[k * m * simd_s..][..m * simd_s]);
+
+    for (p, y) in y.chunks_exact_mut(R * simd_s).enumerate() {
+        let wp: [c64xN; R] = core::array::from_fn(|k| simd.splat(w[k * p * s]));
+        let x: [&[c64xN]; R] = core::array::from_fn(|k| &x[k][p * simd_s..][..simd_s]);
+
+        for q in 0..simd_s {
+            let mut z: [c64xN; R] = core::array::from_fn(|k| x[k][q]);
+            butterfly::<R, c64xN>(simd, fwd, &mut z);
+
+            y[q] = z[0];
+            for k in 1..R {
+                y[k * simd_s + q] = simd.mul(wp[k], z[k]);
+            }
+        }
+    }
+}
+
+/// Performs a radix-4 stage with stride 4 on 8 lanes, where the low and high halves of each
+/// register belong to consecutive butterflies.
+#[inline(always)]
+fn stockham_core_4x8<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    fwd: bool,
+    s: usize,
+    x: &[c64xN],
+    y: &mut [c64xN],
+    w: &[c64xN::Lane],
+) {
+    assert_eq!(s, 4);
+
+    let y = pulp::as_arrays_mut::<4, _>(y).0;
+    let (x0, x1, x2, x3) = split_4(x);
+
+    for (p, (x0, x1, x2, x3, y)) in izip!(x0, x1, x2, x3, y).enumerate() {
+        let [w1, w2, w3]: [c64xN; 3] = core::array::from_fn(|k| {
+            let k = k + 1;
+            let w_lo = w[k * (2 * p) * s];
+            let w_hi = w[k * (2 * p + 1) * s];
+            simd.catlo(simd.splat(w_lo), simd.splat(w_hi))
+        });
+
+        let mut z = [*x0, *x1, *x2, *x3];
+        butterfly::<4, c64xN>(simd, fwd, &mut z);
+        let [aa, bb, cc, dd] = z;
+        let bb = simd.mul(w1, bb);
+        let cc = simd.mul(w2, cc);
+        let dd = simd.mul(w3, dd);
+
+        y[0] = simd.catlo(aa, bb);
+        y[1] = simd.catlo(cc, dd);
+        y[2] = simd.cathi(aa, bb);
+        y[3] = simd.cathi(cc, dd);
+    }
+}
+
+/// Performs the stage of radix `r` whose input stride is `s`, from `x` to `y`.
+///
+/// The stage is vectorized over the stride when it is a multiple of the SIMD lane count. The
+/// radix-4 stages with a smaller stride are vectorized over the butterflies instead, with the
+/// contiguous twiddles from `w_init` for the first stage. The other stages only have a smaller
+/// stride when the lane count does not divide `n / r`, and are computed with scalar code.
+#[inline(always)]
+fn stage<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    fwd: bool,
+    r: usize,
+    s: usize,
+    x: &[c64xN::Lane],
+    y: &mut [c64xN::Lane],
+    w_init: &[c64xN::Lane],
+    w: &[c64xN::Lane],
+) where
+    Scalar: FftSimd<c64xN::Lane>,
+{
+    let lanes = simd.lane_count();
+    if s.is_multiple_of(lanes) {
+        let x: &[c64xN] = bytemuck::cast_slice(x);
+        let y: &mut [c64xN] = bytemuck::cast_slice_mut(y);
+        match r {
+            2 => stockham_core_generic::<2, c64xN>(simd, fwd, s, x, y, w),
+            3 => stockham_core_generic::<3, c64xN>(simd, fwd, s, x, y, w),
+            4 => stockham_core_generic::<4, c64xN>(simd, fwd, s, x, y, w),
+            5 => stockham_core_generic::<5, c64xN>(simd, fwd, s, x, y, w),
+            _ => unreachable!(),
+        }
+    } else if r == 4 && (x.len() / 4).is_multiple_of(lanes) {
+        // the radix-4 stages come first, so this is the first stage, or the second one on 8 lanes
+        let x: &[c64xN] = bytemuck::cast_slice(x);
+        let y: &mut [c64xN] = bytemuck::cast_slice_mut(y);
+        if s == 1 {
+            let w_init: &[c64xN] = bytemuck::cast_slice(w_init);
+            if lanes == 2 {
+                crate::dif4::stockham_core_1x2(simd, fwd, s, x, y, w_init, w);
+            } else {
+                crate::dif4::stockham_core_1x4(simd, fwd, s, x, y, w_init, w);
+            }
+        } else {
+            stockham_core_4x8(simd, fwd, s, x, y, w);
+        }
+    } else {
+        match r {
+            2 => stockham_core_generic::<2, c64xN::Lane>(Scalar, fwd, s, x, y, w),
+            3 => stockham_core_generic::<3, c64xN::Lane>(Scalar, fwd, s, x, y, w),
+            4 => stockham_core_generic::<4, c64xN::Lane>(Scalar, fwd, s, x, y, w),
+            5 => stockham_core_generic::<5, c64xN::Lane>(Scalar, fwd, s, x, y, w),
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Returns the number of stages of an FFT of size `n`.
+fn stage_count(n: usize) -> usize {
+    let mut count = 0;
+    let mut s = 1;
+    while s < n {
+        s *= next_radix(n / s);
+        count += 1;
+    }
+    count
+}
+
+/// Computes the FFT of `src` if it is provided, or of `buf` otherwise, whose size has no prime
+/// factors other than 2, 3 and 5, and stores the result in `buf`, using `scratch` as temporary
+/// storage. `w_init` and `w` are the two halves of the twiddles initialized by [`init_wt`].
+#[inline(always)]
+fn fft_impl<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    fwd: bool,
+    src: Option<&[c64xN::Lane]>,
+    buf: &mut [c64xN::Lane],
+    scratch: &mut [c64xN::Lane],
+    w_init: &[c64xN::Lane],
+    w: &[c64xN::Lane],
+) where
+    Scalar: FftSimd<c64xN::Lane>,
+{
+    let n = buf.len();
+    assert_eq!(scratch.len(), n);
+    assert_eq!(w_init.len(), n);
+    assert_eq!(w.len(), n);
+
+    let mut s = 1;
+    let mut in_buf = true;
+    if let Some(src) = src {
+        assert_eq!(src.len(), n);
+        // the first stage reads from `src`, and writes to the buffer from which the remaining
+        // stages end up in `buf`, so that no copy is needed
+        let r = next_radix(n);
+        in_buf = stage_count(n / r).is_multiple_of(2);
+        if in_buf {
+            stage(simd, fwd, r, s, src, buf, w_init, w);
+        } else {
+            stage(simd, fwd, r, s, src, scratch, w_init, w);
+        }
+        s *= r;
+    }
+    while s < n {
+        let r = next_radix(n / s);
+        if in_buf {
+            stage(simd, fwd, r, s, buf, scratch, w_init, w);
+        } else {
+            stage(simd, fwd, r, s, scratch, buf, w_init, w);
+        }
+        s *= r;
+        in_buf = !in_buf;
+    }
+
+    if !in_buf {
+        buf.copy_from_slice(scratch);
+    }
+}
+
+/// Initializes the twiddles of the mixed-radix kernel of size `n`.
+///
+/// The second half of `w` holds `exp(-2iπ j / n)` at index `j`, and the first half holds the
+/// twiddles of the first stage, `exp(-2iπ k p / n)` at index `k * n / r + p`, where `r` is the
+/// radix of the first stage. `w_inv` holds their conjugates.
+pub fn init_wt<T: Complex>(n: usize, w: &mut [T], w_inv: &mut [T]) {
+    assert_eq!(w.len(), 2 * n);
+    assert_eq!(w_inv.len(), 2 * n);
+
+    let (w_init, w) = w.split_at_mut(n);
+    let (w_init_inv, w_inv) = w_inv.split_at_mut(n);
+
+    let theta = -2.0 / n as f64;
+    for (j, (w, w_inv)) in w.iter_mut().zip(w_inv.iter_mut()).enumerate() {
+        let (s, c) = crate::fft_simd::sincospi64(theta * j as f64);
+        *w = T::from_c64(c64 { re: c, im: s });
+        *w_inv = T::from_c64(c64 { re: c, im: -s });
+    }
+
+    let m = n / next_radix(n);
+    for (k, (w_init, w_init_inv)) in w_init
+        .chunks_exact_mut(m)
+        .zip(w_init_inv.chunks_exact_mut(m))
+        .enumerate()
+    {
+        for (p, (w_init, w_init_inv)) in w_init.iter_mut().zip(w_init_inv).enumerate() {
+            *w_init = w[k * p];
+            *w_init_inv = w_inv[k * p];
+        }
+    }
+}
+
+fn fn_ptr<const FWD: bool, c64xN: Pod, Simd: FftSimd<c64xN>>(
+    simd: Simd,
+) -> fn(
+    Option<&[c64xN::Lane]>,
+    &mut [c64xN::Lane],
+    &mut [c64xN::Lane],
+    &[c64xN::Lane],
+    &[c64xN::Lane],
+)
+where
+    Scalar: FftSimd<c64xN::Lane>,
+{
+    // we can't pass `simd` to the closure even though it's a zero-sized struct,
+    // because we want the closure to be coercible to a function pointer.
+    // so we ignore the passed parameter and reconstruct it inside the closure
+    let _ = simd;
+
+    #[inline(never)]
+    |src: Option<&[c64xN::Lane]>,
+     buf: &mut [c64xN::Lane],
+     scratch: &mut [c64xN::Lane],
+     w_init: &[c64xN::Lane],
+     w: &[c64xN::Lane]| {
+        struct Impl<'a, const FWD: bool, c64xN: Pod, Simd> {
+            simd: Simd,
+            src: Option<&'a [c64xN::Lane]>,
+            buf: &'a mut [c64xN::Lane],
+            scratch: &'a mut [c64xN::Lane],
+            w_init: &'a [c64xN::Lane],
+            w: &'a [c64xN::Lane],
+        }
+        // `simd` is reconstructed here. we know the unwrap can never fail because it was already
+        // passed to us as a function parameter, which proves that it's possible to construct.
+        let simd = Simd::try_new().unwrap();
+
+        // we use NullaryFnOnce instead of a closure because we need the #[inline(always)]
+        // annotation, which doesn't always work with closures for some reason.
+        impl<const FWD: bool, c64xN: Pod, Simd: FftSimd<c64xN>> pulp::NullaryFnOnce
+            for Impl<'_, FWD, c64xN, Simd>
+        where
+            Scalar: FftSimd<c64xN::Lane>,
+        {
+            type Output = ();
+
+            #[inline(always)]
+            fn call(self) -> Self::Output {
+                let Self {
+                    simd,
+                    src,
+                    buf,
+                    scratch,
+                    w_init,
+                    w,
+                } = self;
+                fft_impl(simd, FWD, src, buf, scratch, w_init, w);
+            }
+        }
+
+        simd.vectorize(Impl::<FWD, c64xN, Simd> {
+            simd,
+            src,
+            buf,
+            scratch,
+            w_init,
+            w,
+        });
+    }
+}
+
+macro_rules! kernels {
+    ($c64xN: ty, $simd: expr) => {
+        [
+            fn_ptr::<true, $c64xN, _>($simd),
+            fn_ptr::<false, $c64xN, _>($simd),
+        ]
+    };
+}
+
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")),
+    allow(unused_variables)
+)]
+pub fn fft_impl_dispatch(
+    level: crate::SimdLevel,
+) -> [fn(Option<&[c64]>, &mut [c64], &mut [c64], &[c64], &[c64]); 2] {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        #[cfg(feature = "avx512")]
+        if level.v4().is_some() {
+            return kernels!(c64x4, pulp::x86::V4::try_new().unwrap());
+        }
+        if level.v3().is_some() {
+            return kernels!(c64x2, pulp::x86::V3::try_new().unwrap());
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if level.neon().is_some() {
+            return kernels!(c64x1, pulp::aarch64::Neon::try_new().unwrap());
+        }
+    }
+    kernels!(c64, Scalar)
+}
+
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")),
+    allow(unused_variables)
+)]
+pub fn fft_impl_dispatch_c32(
+    level: crate::SimdLevel,
+) -> [fn(Option<&[c32]>, &mut [c32], &mut [c32], &[c32], &[c32]); 2] {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        #[cfg(feature = "avx512")]
+        if level.v4().is_some() {
+            return kernels!(c32x8, pulp::x86::V4::try_new().unwrap());
+        }
+        if level.v3().is_some() {
+            return kernels!(c32x4, pulp::x86::V3::try_new().unwrap());
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if level.neon().is_some() {
+            return kernels!(c32x2, pulp::aarch64::Neon::try_new().unwrap());
+        }
+    }
+    kernels!(c32, Scalar)
+}
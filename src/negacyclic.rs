@@ -0,0 +1,369 @@
+//! Negacyclic FFT module.
+//!
+//! This module computes the forward or inverse negacyclic FFT of real polynomials of size $n$,
+//! i.e. real polynomials modulo $X^n + 1$.
+//!
+//! Given the coefficients $[a_0, \dots, a_{n-1}]$, the polynomial is folded into $n/2$ complex
+//! numbers $z_j = a_j + i a_{j + n/2}$, which are then twisted by the roots of $-1$,
+//! $z_j \exp\left(\frac{i \pi j}{n}\right)$, before an unordered FFT of size $n/2$ is applied.
+//! This computes the evaluation of the polynomial at $n/2$ roots of $X^n + 1$, one from each pair
+//! of conjugate roots, which is enough to fully describe a real polynomial.
+//!
+//! Like the unordered module, the Fourier coefficients are stored in a permuted order that
+//! depends on the plan. Since the product of two polynomials modulo $X^n + 1$ maps to the
+//! elementwise product of their Fourier coefficients, this order is irrelevant for negacyclic
+//! convolutions.
+
+use crate::{
+    c64, check_power_of_two,
+    fft_simd::{mul_assign, sincospi64},
+    unordered, PlanError,
+};
+use aligned_vec::{avec, ABox};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+
+pub use crate::unordered::Method;
+
+dispatcher!(get_mul_assign, mul_assign);
+
+/// Negacyclic FFT plan.
+///
+/// This type holds an unordered FFT plan of size `n / 2`, as well as the twisting factors needed
+/// to compute the negacyclic FFT of real polynomials of size `n`.
+/// The size must be a power of two, and at least `4`.
+#[derive(Clone)]
+pub struct Plan {
+    twisties: ABox<[c64]>,
+    untwisties: ABox<[c64]>,
+    mul_assign: fn(&mut [c64], &[c64]),
+    plan: unordered::Plan,
+}
+
+impl core::fmt::Debug for Plan {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Plan")
+            .field("polynomial_size", &self.polynomial_size())
+            .field("plan", &self.plan)
+            .finish()
+    }
+}
+
+impl Plan {
+    /// Returns a new negacyclic FFT plan for polynomials of size `n`. The internal unordered
+    /// FFT plan of size `n / 2` is selected by the provided method.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `n` is not a power of two, or if it is less than `4`.
+    /// - Panics if the unordered FFT plan of size `n / 2` can't be created with the provided
+    /// method. See [`unordered::Plan::new`].
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::negacyclic::{Method, Plan};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(8, Method::Measure(Duration::from_millis(10)));
+    /// ```
+    #[track_caller]
+    pub fn new(n: usize, method: Method) -> Self {
+        match Self::try_new(n, method) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Returns a new negacyclic FFT plan for polynomials of size `n`, or an error if the plan is
+    /// not supported. See [`Plan::new`] for the requirements on the sizes.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::negacyclic::{Method, Plan};
+    /// use concrete_fft::PlanError;
+    /// use core::time::Duration;
+    ///
+    /// let method = Method::Measure(Duration::from_millis(10));
+    /// assert!(Plan::try_new(8, method).is_ok());
+    /// assert_eq!(
+    ///     Plan::try_new(2, method).unwrap_err(),
+    ///     PlanError::TooSmall { size: 2, min: 4 },
+    /// );
+    /// ```
+    pub fn try_new(n: usize, method: Method) -> Result<Self, PlanError> {
+        check_power_of_two(n)?;
+        if n < 4 {
+            return Err(PlanError::TooSmall { size: n, min: 4 });
+        }
+
+        let plan = unordered::Plan::try_new(n / 2, method)?;
+
+        let nan = c64 {
+            re: f64::NAN,
+            im: f64::NAN,
+        };
+        let mut twisties = avec![nan; n / 2].into_boxed_slice();
+        let mut untwisties = avec![nan; n / 2].into_boxed_slice();
+
+        let theta = 1.0 / n as f64;
+        let normalization = 2.0 / n as f64;
+        for (j, (twisty, untwisty)) in twisties.iter_mut().zip(&mut *untwisties).enumerate() {
+            let (s, c) = sincospi64(theta * j as f64);
+            *twisty = c64 { re: c, im: s };
+            *untwisty = c64 {
+                re: c * normalization,
+                im: -s * normalization,
+            };
+        }
+
+        Ok(Self {
+            twisties,
+            untwisties,
+            mul_assign: get_mul_assign(plan.simd_level()),
+            plan,
+        })
+    }
+
+    /// Returns the polynomial size of the negacyclic FFT.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     negacyclic::{Method, Plan},
+    ///     ordered::FftAlgo,
+    /// };
+    ///
+    /// let plan = Plan::new(
+    ///     8,
+    ///     Method::UserProvided {
+    ///         base_algo: FftAlgo::Dif2,
+    ///         base_n: 4,
+    ///     },
+    /// );
+    /// assert_eq!(plan.polynomial_size(), 8);
+    /// ```
+    pub fn polynomial_size(&self) -> usize {
+        2 * self.plan.fft_size()
+    }
+
+    /// Returns the number of Fourier coefficients of the negacyclic FFT, which is half the
+    /// polynomial size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     negacyclic::{Method, Plan},
+    ///     ordered::FftAlgo,
+    /// };
+    ///
+    /// let plan = Plan::new(
+    ///     8,
+    ///     Method::UserProvided {
+    ///         base_algo: FftAlgo::Dif2,
+    ///         base_n: 4,
+    ///     },
+    /// );
+    /// assert_eq!(plan.fft_size(), 4);
+    /// ```
+    pub fn fft_size(&self) -> usize {
+        self.plan.fft_size()
+    }
+
+    /// Returns the internal unordered FFT plan.
+    pub fn unordered_plan(&self) -> &unordered::Plan {
+        &self.plan
+    }
+
+    /// Returns the size and alignment of the scratch memory needed to perform an FFT.
+    pub fn fft_scratch(&self) -> Result<StackReq, SizeOverflow> {
+        self.plan.fft_scratch()
+    }
+
+    /// Performs a forward negacyclic FFT of the polynomial `poly`, storing the result in
+    /// `fourier`, and using the provided stack as scratch space.
+    ///
+    /// # Note
+    ///
+    /// The values in `poly` must be in standard order. When this function returns, the values in
+    /// `fourier` will contain the terms of the forward transform in permuted order.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `poly` is not equal to the polynomial size.
+    /// - Panics if the length of `fourier` is not equal to the FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::negacyclic::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(8, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// let poly = [1.0; 8];
+    /// let mut fourier = [c64::default(); 4];
+    /// plan.fwd(&mut fourier, &poly, stack);
+    /// ```
+    pub fn fwd(&self, fourier: &mut [c64], poly: &[f64], stack: PodStack) {
+        let n = self.polynomial_size();
+        assert_eq!(poly.len(), n);
+        assert_eq!(fourier.len(), n / 2);
+
+        let (poly_re, poly_im) = poly.split_at(n / 2);
+        for (z, &re, &im) in izip!(&mut *fourier, poly_re, poly_im) {
+            *z = c64 { re, im };
+        }
+        (self.mul_assign)(fourier, &self.twisties);
+        self.plan.fwd(fourier, stack);
+    }
+
+    /// Performs an inverse negacyclic FFT of `fourier`, storing the result in `poly`, and using
+    /// the provided stack as scratch space. The values of `fourier` are used as temporary storage
+    /// and are unspecified when this function returns.
+    ///
+    /// # Note
+    ///
+    /// The values in `fourier` must be in permuted order. When this function returns, the values
+    /// in `poly` will contain the terms of the inverse transform in standard order.
+    ///
+    /// Unlike the ordered and unordered plans, the inverse transform is normalized, so that
+    /// applying the forward transform followed by the inverse transform returns the original
+    /// polynomial.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `poly` is not equal to the polynomial size.
+    /// - Panics if the length of `fourier` is not equal to the FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::negacyclic::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer, ReborrowMut};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(8, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let mut stack = PodStack::new(&mut memory);
+    ///
+    /// let mut poly = [1.0; 8];
+    /// let mut fourier = [c64::default(); 4];
+    /// plan.fwd(&mut fourier, &poly, stack.rb_mut());
+    /// plan.inv(&mut poly, &mut fourier, stack);
+    /// ```
+    pub fn inv(&self, poly: &mut [f64], fourier: &mut [c64], stack: PodStack) {
+        let n = self.polynomial_size();
+        assert_eq!(poly.len(), n);
+        assert_eq!(fourier.len(), n / 2);
+
+        self.plan.inv(fourier, stack);
+        (self.mul_assign)(fourier, &self.untwisties);
+
+        let (poly_re, poly_im) = poly.split_at_mut(n / 2);
+        for (z, re, im) in izip!(&*fourier, poly_re, poly_im) {
+            *re = z.re;
+            *im = z.im;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ordered::FftAlgo;
+    use alloc::vec;
+    use dyn_stack::{GlobalPodBuffer, ReborrowMut};
+    use rand::random;
+
+    extern crate alloc;
+
+    fn new_plan(n: usize) -> Plan {
+        Plan::new(
+            n,
+            Method::UserProvided {
+                base_algo: FftAlgo::Dif4,
+                base_n: (n / 2).min(32),
+            },
+        )
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_roundtrip() {
+        for n in [4, 8, 16, 64, 256, 1024, 4096] {
+            let plan = new_plan(n);
+            let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+            let mut stack = PodStack::new(&mut mem);
+
+            let mut poly = vec![0.0; n];
+            for x in &mut poly {
+                *x = random();
+            }
+            let orig = poly.clone();
+
+            let mut fourier = vec![c64::default(); n / 2];
+            plan.fwd(&mut fourier, &poly, stack.rb_mut());
+            plan.inv(&mut poly, &mut fourier, stack);
+
+            for (actual, expected) in poly.iter().zip(&orig) {
+                assert!((actual - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_product() {
+        for n in [4, 8, 16, 64, 256, 1024] {
+            let plan = new_plan(n);
+            let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+            let mut stack = PodStack::new(&mut mem);
+
+            let mut lhs = vec![0.0; n];
+            let mut rhs = vec![0.0; n];
+            for x in &mut lhs {
+                *x = random();
+            }
+            for x in &mut rhs {
+                *x = random();
+            }
+
+            let mut negacyclic_convolution = vec![0.0; n];
+            for i in 0..n {
+                for j in 0..n {
+                    if i + j < n {
+                        negacyclic_convolution[i + j] += lhs[i] * rhs[j];
+                    } else {
+                        negacyclic_convolution[i + j - n] -= lhs[i] * rhs[j];
+                    }
+                }
+            }
+
+            let mut lhs_fourier = vec![c64::default(); n / 2];
+            let mut rhs_fourier = vec![c64::default(); n / 2];
+            plan.fwd(&mut lhs_fourier, &lhs, stack.rb_mut());
+            plan.fwd(&mut rhs_fourier, &rhs, stack.rb_mut());
+
+            for (lhs, rhs) in lhs_fourier.iter_mut().zip(&rhs_fourier) {
+                *lhs *= rhs;
+            }
+
+            let mut result = vec![0.0; n];
+            plan.inv(&mut result, &mut lhs_fourier, stack);
+
+            for (actual, expected) in result.iter().zip(&negacyclic_convolution) {
+                assert!((actual - expected).abs() < 1e-10 * n as f64);
+            }
+        }
+    }
+}
@@ -0,0 +1,853 @@
+//! Number-theoretic transform module.
+//!
+//! This module computes the forward or inverse number-theoretic transform (NTT), which is the
+//! counterpart of the FFT over the integers modulo a prime $p$. Given a primitive $n$-th root of
+//! unity $\omega$ modulo $p$, the forward NTT $[X_0, \dots, X_{n-1}]$ of a buffer of integers
+//! $[x_0, \dots, x_{n-1}]$ is given by
+//! $$X_k = \sum_{j = 0}^{n-1} \omega^{jk} x_j \mod p,$$
+//! and the inverse NTT $[Y_0, \dots, Y_{n-1}]$ is given by
+//! $$Y_j = \sum_{k = 0}^{n-1} \omega^{-jk} X_k \mod p.$$
+//!
+//! The forward transform takes its inputs in standard order and outputs its terms in bit-reversed
+//! order, and the inverse transform takes its inputs in bit-reversed order and outputs the result
+//! in standard order. This is the plain bit-reversal of the indices, which differs from the
+//! permutation used by the [`unordered`](crate::unordered) module, since the latter also depends
+//! on the base size of its plan. The outputs of the two modules can therefore not be used
+//! interchangeably, although elementwise products are valid in both domains, which is all that is
+//! needed to compute cyclic convolutions.
+//!
+//! The modulus can be any odd prime $p < 2^{64}$ such that $n$ divides $p - 1$, for example
+//! $2^{64} - 2^{32} + 1$, or primes of the form $k \cdot 2^{32} + 1$. Modular multiplications are
+//! computed with Montgomery's algorithm.
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx512"))]
+use pulp::x86::V4;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use pulp::{cast, x86::V3};
+
+use crate::{check_power_of_two, check_simd_level, PlanError, SimdLevel};
+use aligned_vec::{avec, ABox};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use pulp::Simd;
+
+/// Modular arithmetic on SIMD registers of `u64`, for an odd modulus `p`.
+///
+/// All the inputs and outputs are reduced modulo `p`.
+trait NttSimd: Simd {
+    /// Returns the high 64 bits of the products `a * b`.
+    fn mul_hi_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u64s;
+
+    /// Returns `a - b mod p`.
+    #[inline(always)]
+    fn sub_mod(self, p: Self::u64s, a: Self::u64s, b: Self::u64s) -> Self::u64s {
+        let diff = self.sub_u64s(a, b);
+        self.select_u64s(self.less_than_u64s(a, b), self.add_u64s(diff, p), diff)
+    }
+
+    /// Returns `a + b mod p`.
+    #[inline(always)]
+    fn add_mod(self, p: Self::u64s, a: Self::u64s, b: Self::u64s) -> Self::u64s {
+        // `a + b` may overflow when `p` is larger than `2^63`
+        self.sub_mod(p, a, self.sub_u64s(p, b))
+    }
+
+    /// Returns the Montgomery product `a * b / 2^64 mod p`, where `p_inv` is the inverse of `p`
+    /// modulo `2^64`.
+    #[inline(always)]
+    fn mul_mod(self, p: Self::u64s, p_inv: Self::u64s, a: Self::u64s, b: Self::u64s) -> Self::u64s {
+        // `a * b - m * p` is a multiple of `2^64`, whose quotient is in `(-p, p)`
+        let lo = self.mul_u64s(a, b);
+        let hi = self.mul_hi_u64s(a, b);
+        let m = self.mul_u64s(lo, p_inv);
+        let mp_hi = self.mul_hi_u64s(m, p);
+        let diff = self.sub_u64s(hi, mp_hi);
+        self.select_u64s(self.less_than_u64s(hi, mp_hi), self.add_u64s(diff, p), diff)
+    }
+}
+
+impl NttSimd for pulp::Scalar {
+    #[inline(always)]
+    fn mul_hi_u64s(self, a: u64, b: u64) -> u64 {
+        ((a as u128 * b as u128) >> 64) as u64
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl NttSimd for V3 {
+    #[inline(always)]
+    fn mul_hi_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u64s {
+        let avx = self.avx2;
+        let a = cast(a);
+        let b = cast(b);
+        let a_hi = avx._mm256_srli_epi64::<32>(a);
+        let b_hi = avx._mm256_srli_epi64::<32>(b);
+
+        // products of the 32-bit halves
+        let ll: Self::u64s = cast(avx._mm256_mul_epu32(a, b));
+        let lh: Self::u64s = cast(avx._mm256_mul_epu32(a, b_hi));
+        let hl: Self::u64s = cast(avx._mm256_mul_epu32(a_hi, b));
+        let hh: Self::u64s = cast(avx._mm256_mul_epu32(a_hi, b_hi));
+
+        let shr = |x: Self::u64s| -> Self::u64s { cast(avx._mm256_srli_epi64::<32>(cast(x))) };
+        let mask = self.splat_u64s(0xFFFF_FFFF);
+        let mid = self.add_u64s(
+            shr(ll),
+            self.add_u64s(self.and_u64s(lh, mask), self.and_u64s(hl, mask)),
+        );
+        self.add_u64s(self.add_u64s(hh, shr(mid)), self.add_u64s(shr(lh), shr(hl)))
+    }
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx512"))]
+impl NttSimd for V4 {
+    #[inline(always)]
+    fn mul_hi_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u64s {
+        let avx = self.avx512f;
+        let a = cast(a);
+        let b = cast(b);
+        let a_hi = avx._mm512_srli_epi64::<32>(a);
+        let b_hi = avx._mm512_srli_epi64::<32>(b);
+
+        // products of the 32-bit halves
+        let ll: Self::u64s = cast(avx._mm512_mul_epu32(a, b));
+        let lh: Self::u64s = cast(avx._mm512_mul_epu32(a, b_hi));
+        let hl: Self::u64s = cast(avx._mm512_mul_epu32(a_hi, b));
+        let hh: Self::u64s = cast(avx._mm512_mul_epu32(a_hi, b_hi));
+
+        let shr = |x: Self::u64s| -> Self::u64s { cast(avx._mm512_srli_epi64::<32>(cast(x))) };
+        let mask = self.splat_u64s(0xFFFF_FFFF);
+        let mid = self.add_u64s(
+            shr(ll),
+            self.add_u64s(self.and_u64s(lh, mask), self.and_u64s(hl, mask)),
+        );
+        self.add_u64s(self.add_u64s(hh, shr(mid)), self.add_u64s(shr(lh), shr(hl)))
+    }
+}
+
+/// Runs `$body` with `$simd` bound to the best SIMD type that is allowed by `$level`, and returns
+/// from the enclosing function.
+macro_rules! dispatch {
+    ($level: expr, $simd: ident => $body: expr) => {{
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            #[cfg(feature = "avx512")]
+            if let Some($simd) = $level.v4() {
+                return $simd.vectorize(
+                    #[inline(always)]
+                    || $body,
+                );
+            }
+            if let Some($simd) = $level.v3() {
+                return $simd.vectorize(
+                    #[inline(always)]
+                    || $body,
+                );
+            }
+        }
+        let $simd = pulp::Scalar;
+        $body
+    }};
+}
+
+/// Applies the butterflies of the forward stage where the distance between paired terms is
+/// `len`, which must be a multiple of the lane count of `simd`.
+#[inline(always)]
+fn fwd_stage<S: NttSimd>(simd: S, p: u64, p_inv: u64, buf: &mut [u64], w: &[u64], len: usize) {
+    let p = simd.splat_u64s(p);
+    let p_inv = simd.splat_u64s(p_inv);
+    let w: &[S::u64s] = bytemuck::cast_slice(w);
+
+    for block in buf.chunks_exact_mut(2 * len) {
+        let (z0, z1) = block.split_at_mut(len);
+        let z0: &mut [S::u64s] = bytemuck::cast_slice_mut(z0);
+        let z1: &mut [S::u64s] = bytemuck::cast_slice_mut(z1);
+
+        for (z0, z1, w) in izip!(z0, z1, w) {
+            let a = *z0;
+            let b = *z1;
+            *z0 = simd.add_mod(p, a, b);
+            *z1 = simd.mul_mod(p, p_inv, simd.sub_mod(p, a, b), *w);
+        }
+    }
+}
+
+/// Applies the butterflies of the inverse stage where the distance between paired terms is
+/// `len`, which must be a multiple of the lane count of `simd`.
+#[inline(always)]
+fn inv_stage<S: NttSimd>(simd: S, p: u64, p_inv: u64, buf: &mut [u64], w: &[u64], len: usize) {
+    let p = simd.splat_u64s(p);
+    let p_inv = simd.splat_u64s(p_inv);
+    let w: &[S::u64s] = bytemuck::cast_slice(w);
+
+    for block in buf.chunks_exact_mut(2 * len) {
+        let (z0, z1) = block.split_at_mut(len);
+        let z0: &mut [S::u64s] = bytemuck::cast_slice_mut(z0);
+        let z1: &mut [S::u64s] = bytemuck::cast_slice_mut(z1);
+
+        for (z0, z1, w) in izip!(z0, z1, w) {
+            let a = *z0;
+            let b = simd.mul_mod(p, p_inv, *z1, *w);
+            *z0 = simd.add_mod(p, a, b);
+            *z1 = simd.sub_mod(p, a, b);
+        }
+    }
+}
+
+/// Decimation-in-frequency NTT, taking its input in standard order, and producing its output in
+/// bit-reversed order.
+#[inline(always)]
+fn fwd_impl<S: NttSimd>(simd: S, p: u64, p_inv: u64, buf: &mut [u64], twiddles: &[u64]) {
+    let n = buf.len();
+    let mut len = n / 2;
+    while len > 0 {
+        let w = &twiddles[len..2 * len];
+        if len.is_multiple_of(S::U64_LANES) {
+            fwd_stage(simd, p, p_inv, buf, w, len);
+        } else {
+            fwd_stage(pulp::Scalar, p, p_inv, buf, w, len);
+        }
+        len /= 2;
+    }
+}
+
+/// Decimation-in-time NTT, taking its input in bit-reversed order, and producing its output in
+/// standard order.
+#[inline(always)]
+fn inv_impl<S: NttSimd>(simd: S, p: u64, p_inv: u64, buf: &mut [u64], twiddles: &[u64]) {
+    let n = buf.len();
+    let mut len = 1;
+    while len < n {
+        let w = &twiddles[len..2 * len];
+        if len.is_multiple_of(S::U64_LANES) {
+            inv_stage(simd, p, p_inv, buf, w, len);
+        } else {
+            inv_stage(pulp::Scalar, p, p_inv, buf, w, len);
+        }
+        len *= 2;
+    }
+}
+
+/// Computes `lhs[i] = lhs[i] * rhs[i] * factor / 2^64 mod p` for all `i`.
+#[inline(always)]
+fn mul_impl<S: NttSimd>(simd: S, p: u64, p_inv: u64, factor: u64, lhs: &mut [u64], rhs: &[u64]) {
+    let (lhs_head, lhs_tail) = S::as_mut_simd_u64s(lhs);
+    let (rhs_head, rhs_tail) = S::as_simd_u64s(rhs);
+
+    {
+        let p = simd.splat_u64s(p);
+        let p_inv = simd.splat_u64s(p_inv);
+        let factor = simd.splat_u64s(factor);
+        for (lhs, rhs) in lhs_head.iter_mut().zip(rhs_head) {
+            *lhs = simd.mul_mod(p, p_inv, simd.mul_mod(p, p_inv, *lhs, *rhs), factor);
+        }
+    }
+
+    let simd = pulp::Scalar;
+    for (lhs, rhs) in lhs_tail.iter_mut().zip(rhs_tail) {
+        *lhs = simd.mul_mod(p, p_inv, simd.mul_mod(p, p_inv, *lhs, *rhs), factor);
+    }
+}
+
+/// Computes `buf[i] = buf[i] * factor / 2^64 mod p` for all `i`.
+#[inline(always)]
+fn scale_impl<S: NttSimd>(simd: S, p: u64, p_inv: u64, factor: u64, buf: &mut [u64]) {
+    let (head, tail) = S::as_mut_simd_u64s(buf);
+
+    {
+        let p = simd.splat_u64s(p);
+        let p_inv = simd.splat_u64s(p_inv);
+        let factor = simd.splat_u64s(factor);
+        for x in head {
+            *x = simd.mul_mod(p, p_inv, *x, factor);
+        }
+    }
+
+    let simd = pulp::Scalar;
+    for x in tail {
+        *x = simd.mul_mod(p, p_inv, *x, factor);
+    }
+}
+
+#[inline]
+fn mul_mod_u64(p: u64, a: u64, b: u64) -> u64 {
+    (a as u128 * b as u128 % p as u128) as u64
+}
+
+fn pow_mod_u64(p: u64, base: u64, exp: u64) -> u64 {
+    let mut base = base % p;
+    let mut exp = exp;
+    let mut result = 1 % p;
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result = mul_mod_u64(p, result, base);
+        }
+        base = mul_mod_u64(p, base, base);
+        exp /= 2;
+    }
+    result
+}
+
+/// Returns `true` if `p` is prime, using the Miller-Rabin test with a set of bases that is
+/// deterministic for all 64-bit integers.
+fn is_prime(p: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if p < 2 {
+        return false;
+    }
+    for base in BASES {
+        if p.is_multiple_of(base) {
+            return p == base;
+        }
+    }
+
+    let s = (p - 1).trailing_zeros();
+    let d = (p - 1) >> s;
+    'bases: for base in BASES {
+        let mut x = pow_mod_u64(p, base, d);
+        if x == 1 || x == p - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod_u64(p, x, x);
+            if x == p - 1 {
+                continue 'bases;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Returns a primitive `n`-th root of unity modulo the prime `p`, where `n` is a power of two
+/// that divides `p - 1`.
+fn primitive_root_of_unity(p: u64, n: usize) -> u64 {
+    if n == 1 {
+        return 1;
+    }
+    // `w` is a primitive root if and only if `w^(n/2) = -1`
+    (2..p)
+        .map(|g| pow_mod_u64(p, g, (p - 1) / n as u64))
+        .find(|&w| pow_mod_u64(p, w, n as u64 / 2) == p - 1)
+        .unwrap()
+}
+
+/// Converts `x` to the Montgomery representation `x * 2^64 mod p`.
+#[inline]
+fn to_montgomery(p: u64, x: u64) -> u64 {
+    (((x as u128) << 64) % p as u128) as u64
+}
+
+/// Number-theoretic transform plan.
+///
+/// This type holds a forward and inverse NTT plan and twiddling factors for a specific size and
+/// prime modulus. The size must be a power of two.
+#[derive(Clone)]
+pub struct Plan {
+    modulus: u64,
+    // `modulus^-1 mod 2^64`
+    modulus_inv: u64,
+    // `2^128 mod p`, which cancels the Montgomery factor of a product
+    r2: u64,
+    // `n^-1 * 2^64 mod p`
+    n_inv: u64,
+    // `w_(2 len)^j * 2^64 mod p` at index `len + j`, for `j` in `0..len`
+    twiddles: ABox<[u64]>,
+    twiddles_inv: ABox<[u64]>,
+    simd: SimdLevel,
+}
+
+impl core::fmt::Debug for Plan {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Plan")
+            .field("modulus", &self.modulus)
+            .field("simd", &self.simd)
+            .field("fft_size", &self.fft_size())
+            .finish()
+    }
+}
+
+impl Plan {
+    /// Returns a new NTT plan for the given vector size and prime modulus.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `n` is not a power of two.
+    /// - Panics if `modulus` is not an odd prime such that `n` divides `modulus - 1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::ntt::Plan;
+    ///
+    /// let plan = Plan::new(4, 0xFFFF_FFFF_0000_0001);
+    /// ```
+    #[track_caller]
+    pub fn new(n: usize, modulus: u64) -> Self {
+        match Self::try_new(n, modulus) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Returns a new NTT plan for the given vector size and prime modulus, or an error if `n` is
+    /// not a power of two, or if `modulus` is not an odd prime such that `n` divides
+    /// `modulus - 1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{ntt::Plan, PlanError};
+    ///
+    /// assert!(Plan::try_new(4, 0xFFFF_FFFF_0000_0001).is_ok());
+    /// assert_eq!(
+    ///     Plan::try_new(4, 7).unwrap_err(),
+    ///     PlanError::InvalidModulus {
+    ///         modulus: 7,
+    ///         size: 4
+    ///     },
+    /// );
+    /// ```
+    pub fn try_new(n: usize, modulus: u64) -> Result<Self, PlanError> {
+        Self::try_new_with_simd(n, modulus, SimdLevel::detect())
+    }
+
+    /// Returns a new NTT plan for the given vector size and prime modulus, whose kernels use the
+    /// provided SIMD instruction set instead of the best one available at runtime.
+    ///
+    /// The NTT kernels are only vectorized on x86 CPUs. Other instruction sets use the scalar
+    /// kernels.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `n` is not a power of two.
+    /// - Panics if `modulus` is not an odd prime such that `n` divides `modulus - 1`.
+    /// - Panics if `simd` is not available on the current machine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{ntt::Plan, SimdLevel};
+    ///
+    /// let plan = Plan::new_with_simd(4, 0xFFFF_FFFF_0000_0001, SimdLevel::Scalar);
+    /// assert_eq!(plan.simd_level(), SimdLevel::Scalar);
+    /// ```
+    #[track_caller]
+    pub fn new_with_simd(n: usize, modulus: u64, simd: SimdLevel) -> Self {
+        match Self::try_new_with_simd(n, modulus, simd) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Returns a new NTT plan for the given vector size and prime modulus, whose kernels use the
+    /// provided SIMD instruction set, or an error if `n` is not a power of two, if `modulus` is
+    /// not an odd prime such that `n` divides `modulus - 1`, or if `simd` is not available on the
+    /// current machine.
+    pub fn try_new_with_simd(n: usize, modulus: u64, simd: SimdLevel) -> Result<Self, PlanError> {
+        check_power_of_two(n)?;
+        if modulus.is_multiple_of(2)
+            || !(modulus - 1).is_multiple_of(n as u64)
+            || !is_prime(modulus)
+        {
+            return Err(PlanError::InvalidModulus { modulus, size: n });
+        }
+        check_simd_level(simd)?;
+
+        let p = modulus;
+        let mut p_inv = p;
+        // each iteration doubles the number of correct low bits, starting from 3
+        for _ in 0..5 {
+            p_inv = p_inv.wrapping_mul(2u64.wrapping_sub(p.wrapping_mul(p_inv)));
+        }
+
+        let w = primitive_root_of_unity(p, n);
+        let w_inv = pow_mod_u64(p, w, p - 2);
+
+        let mut twiddles = avec![0u64; n].into_boxed_slice();
+        let mut twiddles_inv = avec![0u64; n].into_boxed_slice();
+        let mut len = n / 2;
+        // `w^(n / (2 len))` is a primitive `2 len`-th root of unity
+        let mut step = w;
+        let mut step_inv = w_inv;
+        while len > 0 {
+            let mut wj = 1;
+            let mut wj_inv = 1;
+            for j in 0..len {
+                twiddles[len + j] = to_montgomery(p, wj);
+                twiddles_inv[len + j] = to_montgomery(p, wj_inv);
+                wj = mul_mod_u64(p, wj, step);
+                wj_inv = mul_mod_u64(p, wj_inv, step_inv);
+            }
+            step = mul_mod_u64(p, step, step);
+            step_inv = mul_mod_u64(p, step_inv, step_inv);
+            len /= 2;
+        }
+
+        let r = to_montgomery(p, 1);
+        Ok(Self {
+            modulus,
+            modulus_inv: p_inv,
+            r2: mul_mod_u64(p, r, r),
+            n_inv: to_montgomery(p, pow_mod_u64(p, n as u64, p - 2)),
+            twiddles,
+            twiddles_inv,
+            simd,
+        })
+    }
+
+    /// Returns the vector size of the NTT.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::ntt::Plan;
+    ///
+    /// let plan = Plan::new(4, 0xFFFF_FFFF_0000_0001);
+    /// assert_eq!(plan.fft_size(), 4);
+    /// ```
+    pub fn fft_size(&self) -> usize {
+        self.twiddles.len()
+    }
+
+    /// Returns the prime modulus of the NTT.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::ntt::Plan;
+    ///
+    /// let plan = Plan::new(4, 0xFFFF_FFFF_0000_0001);
+    /// assert_eq!(plan.modulus(), 0xFFFF_FFFF_0000_0001);
+    /// ```
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    /// Returns the SIMD instruction set that's internally used by the NTT.
+    pub fn simd_level(&self) -> SimdLevel {
+        self.simd
+    }
+
+    /// Returns the size and alignment of the scratch memory needed to perform an NTT.
+    ///
+    /// The NTT is computed in place, so no scratch memory is needed. This function is provided
+    /// for compatibility with the FFT plans.
+    pub fn fft_scratch(&self) -> Result<StackReq, SizeOverflow> {
+        Ok(StackReq::empty())
+    }
+
+    /// Performs a forward NTT in place, using the provided stack as scratch space.
+    ///
+    /// # Note
+    ///
+    /// The values in `buf` must be reduced modulo the plan's modulus, and in standard order prior
+    /// to calling this function. When this function returns, the values in `buf` will contain the
+    /// terms of the forward transform in bit-reversed order, i.e., the term $X_k$ is stored at the
+    /// index obtained by reversing the $\log_2 n$ bits of $k$.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `buf` is not equal to the NTT size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::ntt::Plan;
+    /// use dyn_stack::{GlobalPodBuffer, PodStack};
+    ///
+    /// let plan = Plan::new(4, 0xFFFF_FFFF_0000_0001);
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [1, 0, 0, 0];
+    /// plan.fwd(&mut buf, stack);
+    /// assert_eq!(buf, [1, 1, 1, 1]);
+    /// ```
+    pub fn fwd(&self, buf: &mut [u64], stack: PodStack) {
+        let _ = stack;
+        assert_eq!(self.fft_size(), buf.len());
+        let (p, p_inv, twiddles) = (self.modulus, self.modulus_inv, &*self.twiddles);
+        dispatch!(self.simd, simd => fwd_impl(simd, p, p_inv, buf, twiddles))
+    }
+
+    /// Performs an inverse NTT in place, using the provided stack as scratch space.
+    ///
+    /// Like the FFT, the result is not normalized, so that applying [`Plan::fwd`] followed by
+    /// [`Plan::inv`] multiplies the vector by `n`. The result can be normalized with
+    /// [`Plan::normalize`].
+    ///
+    /// # Note
+    ///
+    /// The values in `buf` must be reduced modulo the plan's modulus, and in the bit-reversed
+    /// order produced by [`Plan::fwd`] prior to calling this function. When this function
+    /// returns, the values in `buf` will contain the terms of the inverse transform in standard
+    /// order.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `buf` is not equal to the NTT size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::ntt::Plan;
+    /// use dyn_stack::{GlobalPodBuffer, PodStack, ReborrowMut};
+    ///
+    /// let plan = Plan::new(4, 0xFFFF_FFFF_0000_0001);
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let mut stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [1, 2, 3, 4];
+    /// plan.fwd(&mut buf, stack.rb_mut());
+    /// plan.inv(&mut buf, stack);
+    /// assert_eq!(buf, [4, 8, 12, 16]);
+    /// ```
+    pub fn inv(&self, buf: &mut [u64], stack: PodStack) {
+        let _ = stack;
+        assert_eq!(self.fft_size(), buf.len());
+        let (p, p_inv, twiddles) = (self.modulus, self.modulus_inv, &*self.twiddles_inv);
+        dispatch!(self.simd, simd => inv_impl(simd, p, p_inv, buf, twiddles))
+    }
+
+    /// Computes `lhs[i] = lhs[i] * rhs[i] mod p` for all `i`.
+    ///
+    /// Since the product is computed elementwise, the result is valid for vectors in the
+    /// bit-reversed order produced by [`Plan::fwd`].
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `lhs` or `rhs` is not equal to the NTT size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::ntt::Plan;
+    ///
+    /// let plan = Plan::new(4, 0xFFFF_FFFF_0000_0001);
+    ///
+    /// let mut lhs = [1 << 32; 4];
+    /// let rhs = [1 << 32; 4];
+    /// plan.pointwise_mul(&mut lhs, &rhs);
+    /// // 2^64 = 2^32 - 1 mod p
+    /// assert_eq!(lhs, [0xFFFF_FFFF; 4]);
+    /// ```
+    pub fn pointwise_mul(&self, lhs: &mut [u64], rhs: &[u64]) {
+        assert_eq!(self.fft_size(), lhs.len());
+        assert_eq!(self.fft_size(), rhs.len());
+        let (p, p_inv, r2) = (self.modulus, self.modulus_inv, self.r2);
+        dispatch!(self.simd, simd => mul_impl(simd, p, p_inv, r2, lhs, rhs))
+    }
+
+    /// Computes `buf[i] = buf[i] / n mod p` for all `i`, which normalizes the result of
+    /// [`Plan::inv`].
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `buf` is not equal to the NTT size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::ntt::Plan;
+    /// use dyn_stack::{GlobalPodBuffer, PodStack, ReborrowMut};
+    ///
+    /// let plan = Plan::new(4, 0xFFFF_FFFF_0000_0001);
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let mut stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [1, 2, 3, 4];
+    /// plan.fwd(&mut buf, stack.rb_mut());
+    /// plan.inv(&mut buf, stack);
+    /// plan.normalize(&mut buf);
+    /// assert_eq!(buf, [1, 2, 3, 4]);
+    /// ```
+    pub fn normalize(&self, buf: &mut [u64]) {
+        assert_eq!(self.fft_size(), buf.len());
+        let (p, p_inv, n_inv) = (self.modulus, self.modulus_inv, self.n_inv);
+        dispatch!(self.simd, simd => scale_impl(simd, p, p_inv, n_inv, buf))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{vec, vec::Vec};
+    use dyn_stack::{GlobalPodBuffer, ReborrowMut};
+    use rand::random;
+
+    extern crate alloc;
+
+    const PRIMES: [u64; 4] = [
+        0xFFFF_FFFF_0000_0001,
+        0xFFFF_FFFC_0000_0001,
+        0x12_0000_0001,
+        0x3FFF_FFFF_FEB0_0001,
+    ];
+
+    fn random_vec(n: usize, p: u64) -> Vec<u64> {
+        (0..n).map(|_| random::<u64>() % p).collect()
+    }
+
+    fn bit_rev(nbits: u32, i: usize) -> usize {
+        if nbits == 0 {
+            0
+        } else {
+            i.reverse_bits() >> (usize::BITS - nbits)
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_fwd() {
+        for p in PRIMES {
+            for n in [1, 2, 4, 8, 16, 32, 64, 128] {
+                let plan = Plan::new(n, p);
+                let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+                let stack = PodStack::new(&mut mem);
+
+                let x = random_vec(n, p);
+                let mut y = x.clone();
+                plan.fwd(&mut y, stack);
+
+                let w = primitive_root_of_unity(p, n);
+                let nbits = n.trailing_zeros();
+                for (k, y) in y.iter().enumerate() {
+                    let k = bit_rev(nbits, k);
+                    let mut expected = 0u64;
+                    for (j, x) in x.iter().enumerate() {
+                        let wjk = pow_mod_u64(p, w, (j * k) as u64);
+                        expected = ((mul_mod_u64(p, wjk, *x) as u128 + expected as u128)
+                            % p as u128) as u64;
+                    }
+                    assert_eq!(*y, expected);
+                }
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_roundtrip() {
+        for p in PRIMES {
+            for n in [1, 2, 4, 16, 256, 1024, 4096] {
+                let plan = Plan::new(n, p);
+                let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+                let mut stack = PodStack::new(&mut mem);
+
+                let x = random_vec(n, p);
+                let mut y = x.clone();
+                plan.fwd(&mut y, stack.rb_mut());
+                plan.inv(&mut y, stack.rb_mut());
+                for (y, x) in y.iter().zip(&x) {
+                    assert_eq!(*y, mul_mod_u64(p, *x, n as u64));
+                }
+                plan.normalize(&mut y);
+                assert_eq!(y, x);
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_convolution() {
+        for p in PRIMES {
+            for n in [2, 8, 64, 512] {
+                let plan = Plan::new(n, p);
+                let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+                let mut stack = PodStack::new(&mut mem);
+
+                let lhs = random_vec(n, p);
+                let rhs = random_vec(n, p);
+
+                let mut expected = vec![0u64; n];
+                for i in 0..n {
+                    for j in 0..n {
+                        let prod = mul_mod_u64(p, lhs[i], rhs[j]);
+                        let acc = &mut expected[(i + j) % n];
+                        *acc = ((*acc as u128 + prod as u128) % p as u128) as u64;
+                    }
+                }
+
+                let mut lhs_fourier = lhs.clone();
+                let mut rhs_fourier = rhs.clone();
+                plan.fwd(&mut lhs_fourier, stack.rb_mut());
+                plan.fwd(&mut rhs_fourier, stack.rb_mut());
+                plan.pointwise_mul(&mut lhs_fourier, &rhs_fourier);
+                plan.inv(&mut lhs_fourier, stack.rb_mut());
+                plan.normalize(&mut lhs_fourier);
+
+                assert_eq!(lhs_fourier, expected);
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_simd_level() {
+        let levels = [
+            SimdLevel::Scalar,
+            SimdLevel::Avx2,
+            SimdLevel::Avx512,
+            SimdLevel::Neon,
+        ];
+        for p in PRIMES {
+            for n in [1, 2, 4, 8, 16, 64, 1024] {
+                let scalar = Plan::new_with_simd(n, p, SimdLevel::Scalar);
+                let mut mem = GlobalPodBuffer::new(scalar.fft_scratch().unwrap());
+                let mut stack = PodStack::new(&mut mem);
+
+                let x = random_vec(n, p);
+                let rhs = random_vec(n, p);
+                let mut expected = x.clone();
+                scalar.fwd(&mut expected, stack.rb_mut());
+                scalar.pointwise_mul(&mut expected, &rhs);
+
+                for simd in levels {
+                    match Plan::try_new_with_simd(n, p, simd) {
+                        Ok(plan) => {
+                            assert!(simd.is_available());
+                            assert_eq!(plan.simd_level(), simd);
+
+                            let mut y = x.clone();
+                            plan.fwd(&mut y, stack.rb_mut());
+                            plan.pointwise_mul(&mut y, &rhs);
+                            assert_eq!(y, expected);
+                        }
+                        Err(err) => {
+                            assert!(!simd.is_available());
+                            assert_eq!(err, PlanError::SimdUnavailable { simd });
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_invalid_modulus() {
+        assert_eq!(
+            Plan::try_new(3, 0xFFFF_FFFF_0000_0001).unwrap_err(),
+            PlanError::NotPowerOfTwo { size: 3 },
+        );
+        // even, composite, and too small 2-adicity
+        for (n, modulus) in [
+            (4, 0x10_0000_0000),
+            (4, 0xFFFF_FFFF_FFFF_FFFF),
+            (1 << 21, 0x3FFF_FFFF_FEB0_0001),
+            (2, 1),
+        ] {
+            assert_eq!(
+                Plan::try_new(n, modulus).unwrap_err(),
+                PlanError::InvalidModulus { modulus, size: n },
+            );
+        }
+        for p in PRIMES {
+            assert!(is_prime(p));
+        }
+    }
+}
@@ -2,6 +2,9 @@
 //!
 //! This FFT is currently based on hte Stockham algorithm, and was ported from the
 //! [OTFFT](http://wwwa.pikara.ne.jp/okojisan/otfft-en/) C++ library by Takuya OKAHISA.
+//! Sizes whose prime factors are all 2, 3 or 5 are computed with mixed-radix Stockham kernels,
+//! while other sizes are computed with Bluestein's algorithm, as a convolution of power-of-two
+//! size.
 //!
 //! This module computes the forward or inverse FFT in standard ordering.
 //! This means that given a buffer of complex numbers $[x_0, \dots, x_{n-1}]$,
@@ -9,14 +12,29 @@
 //! $$X_p = \sum_{q = 0}^{n-1} \exp\left(-\frac{i 2\pi pq}{n}\right),$$
 //! and the inverse FFT $[Y_0, \dots, Y_{n-1}]$ is given by
 //! $$Y_p = \sum_{q = 0}^{n-1} \exp\left(\frac{i 2\pi pq}{n}\right).$$
+//!
+//! Real vectors can be transformed with [`Plan::fwd_real`] and [`Plan::inv_real`], or with a
+//! [`RealPlan`] created from a [`Plan`]. Both only store the first $n/2 + 1$ terms of the forward
+//! FFT, the others being their complex conjugates.
+//!
+//! Two-dimensional and multi-dimensional arrays stored in row-major order can be transformed with
+//! [`Plan2d`] and [`PlanNd`], which apply an FFT plan along each dimension.
+//!
+//! Split-complex vectors, whose real and imaginary parts are stored in two separate slices, can be
+//! transformed with [`Plan::fwd_split`] and [`Plan::inv_split`], or with a [`SplitPlan`] created
+//! from a power-of-two [`Plan`].
 
-use crate::{dif2::split_2, *};
+use crate::{dif2::split_2, fft_simd::mul_assign, split::SplitFft, wisdom::Wisdom, *};
 use aligned_vec::{avec, ABox, CACHELINE_ALIGN};
+use dyn_stack::ReborrowMut;
+
+extern crate alloc;
+use alloc::boxed::Box;
 
 #[cfg(feature = "std")]
 use core::time::Duration;
 #[cfg(feature = "std")]
-use dyn_stack::{GlobalPodBuffer, ReborrowMut};
+use dyn_stack::GlobalPodBuffer;
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
 
 /// Internal FFT algorithm.
@@ -24,6 +42,7 @@ use dyn_stack::{PodStack, SizeOverflow, StackReq};
 /// The FFT can use a decimation-in-frequency (DIF) or decimation-in-time (DIT) approach.
 /// And the FFT radix can be any of 2, 4, 8, 16.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum FftAlgo {
     /// Decimation in frequency with radix 2
@@ -47,9 +66,12 @@ pub enum FftAlgo {
 /// Method for selecting the ordered FFT plan.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[non_exhaustive]
-pub enum Method {
+pub enum Method<'a> {
     /// Select the FFT plan by manually providing the underlying algorithm.
     UserProvided(FftAlgo),
+    /// Select the FFT plan by looking up the algorithm previously measured for the vector size and
+    /// the SIMD instruction set of the current machine.
+    Wisdom(&'a Wisdom),
     /// Select the FFT plan by measuring the running time of all the possible plans and selecting
     /// the fastest one. The provided duration specifies how long the benchmark of each plan should
     /// last.
@@ -59,24 +81,25 @@ pub enum Method {
 }
 
 #[cfg(feature = "std")]
-fn measure_n_runs(
+fn measure_n_runs<T: FftComplex>(
     n_runs: u128,
     algo: FftAlgo,
-    buf: &mut [c64],
-    twiddles_init: &[c64],
-    twiddles: &[c64],
+    buf: &mut [T],
+    twiddles_init: &[T],
+    twiddles: &[T],
+    simd: SimdLevel,
     stack: PodStack,
 ) -> Duration {
     let n = buf.len();
-    let (scratch, _) = stack.make_aligned_raw::<c64>(n, CACHELINE_ALIGN);
-    let [fwd, _] = get_fn_ptr(algo, n);
+    let (scratch, _) = stack.make_aligned_raw::<T>(n, CACHELINE_ALIGN);
+    let [fwd, _] = T::get_fn_ptr(algo, n, simd);
 
     // For wasm we have a dedicated implementation going through js-sys
     use crate::time::Instant;
     let now = Instant::now();
 
     for _ in 0..n_runs {
-        fwd(buf, scratch, twiddles, twiddles_init);
+        fwd(None, buf, scratch, twiddles, twiddles_init);
     }
 
     now.elapsed()
@@ -88,17 +111,18 @@ fn duration_div_f64(duration: Duration, n: f64) -> Duration {
 }
 
 #[cfg(feature = "std")]
-pub(crate) fn measure_fastest_scratch(n: usize) -> StackReq {
+pub(crate) fn measure_fastest_scratch<T: FftComplex>(n: usize) -> StackReq {
     let align = CACHELINE_ALIGN;
-    StackReq::new_aligned::<c64>(2 * n, align) // twiddles
-        .and(StackReq::new_aligned::<c64>(n, align)) // buffer
-        .and(StackReq::new_aligned::<c64>(n, align))
+    StackReq::new_aligned::<T>(2 * n, align) // twiddles
+        .and(StackReq::new_aligned::<T>(n, align)) // buffer
+        .and(StackReq::new_aligned::<T>(n, align))
 }
 
 #[cfg(feature = "std")]
-pub(crate) fn measure_fastest(
+pub(crate) fn measure_fastest<T: FftComplex>(
     min_bench_duration_per_algo: Duration,
     n: usize,
+    simd: SimdLevel,
     stack: PodStack,
 ) -> (FftAlgo, Duration) {
     const N_ALGOS: usize = 8;
@@ -114,16 +138,16 @@ pub(crate) fn measure_fastest(
 
     let align = CACHELINE_ALIGN;
 
-    let f = |_| c64 { re: 0.0, im: 0.0 };
+    let f = |_| T::default();
 
-    let (twiddles, stack) = stack.make_aligned_with::<c64, _>(2 * n, align, f);
+    let (twiddles, stack) = stack.make_aligned_with::<T, _>(2 * n, align, f);
     let twiddles_init = &twiddles[..n];
     let twiddles = &twiddles[n..];
-    let (buf, mut stack) = stack.make_aligned_with::<c64, _>(n, align, f);
+    let (buf, mut stack) = stack.make_aligned_with::<T, _>(n, align, f);
 
     {
         // initialize scratch to load it in the cpu cache
-        drop(stack.rb_mut().make_aligned_with::<c64, _>(n, align, f));
+        drop(stack.rb_mut().make_aligned_with::<T, _>(n, align, f));
     }
 
     let mut avg_durations = [Duration::ZERO; N_ALGOS];
@@ -149,8 +173,15 @@ pub(crate) fn measure_fastest(
             let mut n_runs: u128 = 1;
 
             loop {
-                let duration =
-                    measure_n_runs(n_runs, algo, buf, twiddles_init, twiddles, stack.rb_mut());
+                let duration = measure_n_runs(
+                    n_runs,
+                    algo,
+                    buf,
+                    twiddles_init,
+                    twiddles,
+                    simd,
+                    stack.rb_mut(),
+                );
 
                 if duration < MIN_DURATION {
                     n_runs *= 2;
@@ -165,8 +196,15 @@ pub(crate) fn measure_fastest(
         *avg = if n_runs <= init_n_runs {
             approx_duration
         } else {
-            let duration =
-                measure_n_runs(n_runs, algo, buf, twiddles_init, twiddles, stack.rb_mut());
+            let duration = measure_n_runs(
+                n_runs,
+                algo,
+                buf,
+                twiddles_init,
+                twiddles,
+                simd,
+                stack.rb_mut(),
+            );
             duration_div_f64(duration, n_runs as f64)
         };
     }
@@ -182,54 +220,538 @@ pub(crate) fn measure_fastest(
 /// Ordered FFT plan.
 ///
 /// This type holds a forward and inverse FFT plan and twiddling factors for a specific size.
-/// The size must be a power of two, and can be as large as `2^16` (inclusive).
+/// The size can be any positive integer, but powers of two are the fastest.
 #[derive(Clone)]
 pub struct Plan {
-    fwd: fn(&mut [c64], &mut [c64], &[c64], &[c64]),
-    inv: fn(&mut [c64], &mut [c64], &[c64], &[c64]),
-    twiddles: ABox<[c64]>,
-    twiddles_inv: ABox<[c64]>,
+    fft: AnyFft<c64>,
     algo: FftAlgo,
+    simd: SimdLevel,
+    // only built the first time that a real FFT is performed
+    #[cfg(feature = "std")]
+    real: std::sync::OnceLock<RealPlan>,
+    // only built the first time that a split-complex FFT is performed
+    #[cfg(feature = "std")]
+    split: std::sync::OnceLock<SplitPlan>,
 }
 
 impl core::fmt::Debug for Plan {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Plan")
             .field("algo", &self.algo)
+            .field("simd", &self.simd)
             .field("fft_size", &self.fft_size())
             .finish()
     }
 }
 
-fn do_nothing(_: &mut [c64], _: &mut [c64], _: &[c64], _: &[c64]) {}
+// FFT of size 1, which only needs to copy the input when computing out of place
+fn identity<T: Copy>(src: Option<&[T]>, buf: &mut [T], _: &mut [T], _: &[T], _: &[T]) {
+    if let Some(src) = src {
+        buf.copy_from_slice(src);
+    }
+}
 
 pub(crate) fn get_fn_ptr(
     algo: FftAlgo,
     n: usize,
-) -> [fn(&mut [c64], &mut [c64], &[c64], &[c64]); 2] {
+    simd: SimdLevel,
+) -> [fn(Option<&[c64]>, &mut [c64], &mut [c64], &[c64], &[c64]); 2] {
+    if n == 1 {
+        return [identity; 2];
+    }
+    use FftAlgo::*;
+    match algo {
+        Dif2 => dif2::fft_impl_dispatch(n, simd),
+        Dit2 => dit2::fft_impl_dispatch(n, simd),
+        Dif4 => dif4::fft_impl_dispatch(n, simd),
+        Dit4 => dit4::fft_impl_dispatch(n, simd),
+        Dif8 => dif8::fft_impl_dispatch(n, simd),
+        Dit8 => dit8::fft_impl_dispatch(n, simd),
+        Dif16 => dif16::fft_impl_dispatch(n, simd),
+        Dit16 => dit16::fft_impl_dispatch(n, simd),
+    }
+}
+
+pub(crate) fn get_fn_ptr_c32(
+    algo: FftAlgo,
+    n: usize,
+    simd: SimdLevel,
+) -> [fn(Option<&[c32]>, &mut [c32], &mut [c32], &[c32], &[c32]); 2] {
     if n == 1 {
-        return [do_nothing; 2];
+        return [identity; 2];
     }
     use FftAlgo::*;
     match algo {
-        Dif2 => dif2::fft_impl_dispatch(n),
-        Dit2 => dit2::fft_impl_dispatch(n),
-        Dif4 => dif4::fft_impl_dispatch(n),
-        Dit4 => dit4::fft_impl_dispatch(n),
-        Dif8 => dif8::fft_impl_dispatch(n),
-        Dit8 => dit8::fft_impl_dispatch(n),
-        Dif16 => dif16::fft_impl_dispatch(n),
-        Dit16 => dit16::fft_impl_dispatch(n),
+        Dif2 => dif2::fft_impl_dispatch_c32(n, simd),
+        Dit2 => dit2::fft_impl_dispatch_c32(n, simd),
+        Dif4 => dif4::fft_impl_dispatch_c32(n, simd),
+        Dit4 => dit4::fft_impl_dispatch_c32(n, simd),
+        Dif8 => dif8::fft_impl_dispatch_c32(n, simd),
+        Dit8 => dit8::fft_impl_dispatch_c32(n, simd),
+        Dif16 => dif16::fft_impl_dispatch_c32(n, simd),
+        Dit16 => dit16::fft_impl_dispatch_c32(n, simd),
+    }
+}
+
+dispatcher!(get_mul_assign, mul_assign);
+dispatcher!(c32, get_mul_assign_c32, mul_assign);
+
+/// Complex type on which the ordered FFT can be computed, either [`c64`] or [`c32`].
+pub(crate) trait FftComplex: fft_simd::Complex {
+    fn get_fn_ptr(
+        algo: FftAlgo,
+        n: usize,
+        simd: SimdLevel,
+    ) -> [fn(Option<&[Self]>, &mut [Self], &mut [Self], &[Self], &[Self]); 2];
+    fn get_mixed_fn_ptr(
+        simd: SimdLevel,
+    ) -> [fn(Option<&[Self]>, &mut [Self], &mut [Self], &[Self], &[Self]); 2];
+    fn get_mul_assign(simd: SimdLevel) -> fn(&mut [Self], &[Self]);
+}
+
+impl FftComplex for c64 {
+    fn get_fn_ptr(
+        algo: FftAlgo,
+        n: usize,
+        simd: SimdLevel,
+    ) -> [fn(Option<&[Self]>, &mut [Self], &mut [Self], &[Self], &[Self]); 2] {
+        get_fn_ptr(algo, n, simd)
+    }
+    fn get_mixed_fn_ptr(
+        simd: SimdLevel,
+    ) -> [fn(Option<&[Self]>, &mut [Self], &mut [Self], &[Self], &[Self]); 2] {
+        mixed_radix::fft_impl_dispatch(simd)
+    }
+    fn get_mul_assign(simd: SimdLevel) -> fn(&mut [Self], &[Self]) {
+        get_mul_assign(simd)
+    }
+}
+
+impl FftComplex for c32 {
+    fn get_fn_ptr(
+        algo: FftAlgo,
+        n: usize,
+        simd: SimdLevel,
+    ) -> [fn(Option<&[Self]>, &mut [Self], &mut [Self], &[Self], &[Self]); 2] {
+        get_fn_ptr_c32(algo, n, simd)
+    }
+    fn get_mixed_fn_ptr(
+        simd: SimdLevel,
+    ) -> [fn(Option<&[Self]>, &mut [Self], &mut [Self], &[Self], &[Self]); 2] {
+        mixed_radix::fft_impl_dispatch_c32(simd)
+    }
+    fn get_mul_assign(simd: SimdLevel) -> fn(&mut [Self], &[Self]) {
+        get_mul_assign_c32(simd)
+    }
+}
+
+/// Returns the fastest algorithm for an ordered FFT of size `n`, measured on its first
+/// power-of-two Stockham kernel.
+///
+/// The mixed-radix kernels do not depend on the algorithm, so nothing is measured when the plan
+/// has no power-of-two kernel.
+#[cfg(feature = "std")]
+pub(crate) fn measure_algo<T: FftComplex>(
+    n: usize,
+    duration: Duration,
+    simd: SimdLevel,
+) -> FftAlgo {
+    let Some(n) = algo_kernel_size(n) else {
+        return FftAlgo::Dif4;
+    };
+    measure_fastest::<T>(
+        duration,
+        n,
+        simd,
+        PodStack::new(&mut GlobalPodBuffer::new(measure_fastest_scratch::<T>(n))),
+    )
+    .0
+}
+
+/// Largest FFT size that can be computed directly by the Stockham kernels.
+const MAX_KERNEL_SIZE: usize = 1 << 10;
+
+/// Returns the size of the Stockham kernel that is used for an FFT of size `n`.
+///
+/// Larger sizes are decomposed as `n = n1 * n2`, where `n1` is the kernel size, and `n2` is
+/// decomposed recursively. `n1` is the smallest divisor of `n` that is at least `sqrt(n)`, or the
+/// largest one that fits in a kernel if there is none.
+fn kernel_size(n: usize) -> usize {
+    if n <= MAX_KERNEL_SIZE {
+        n
+    } else {
+        let mut divisors = (2..=MAX_KERNEL_SIZE).filter(|d| n.is_multiple_of(*d));
+        divisors
+            .clone()
+            .find(|d| d * d >= n)
+            .or_else(|| divisors.next_back())
+            .unwrap()
+    }
+}
+
+/// Returns the size of the convolution used by Bluestein's algorithm for an FFT of size `n`.
+fn bluestein_size(n: usize) -> usize {
+    (2 * n - 1).next_power_of_two()
+}
+
+/// Returns the size of the first power-of-two Stockham kernel that is used by a plan of size
+/// `n`, which is the only kind of kernel that depends on the selected algorithm, or `None` if all
+/// its kernels are mixed-radix.
+#[cfg(feature = "std")]
+fn algo_kernel_size(n: usize) -> Option<usize> {
+    let mut n = if mixed_radix::is_supported(n) {
+        n
+    } else {
+        bluestein_size(n)
+    };
+    loop {
+        let n1 = kernel_size(n);
+        if n1.is_power_of_two() && n1 > 1 {
+            return Some(n1);
+        }
+        if n1 == n {
+            return None;
+        }
+        n /= n1;
+    }
+}
+
+/// FFT of a fixed size whose prime factors are all in `{2, 3, 5}`, computed either directly by a
+/// Stockham kernel, or with the four-step algorithm when the size is larger than
+/// [`MAX_KERNEL_SIZE`].
+///
+/// Power-of-two kernels use the radix of the selected algorithm, while the other kernels use
+/// mixed radix 2, 3, 4 and 5 stages.
+#[derive(Clone)]
+struct Fft<T> {
+    fwd: fn(Option<&[T]>, &mut [T], &mut [T], &[T], &[T]),
+    inv: fn(Option<&[T]>, &mut [T], &mut [T], &[T], &[T]),
+    twiddles: ABox<[T]>,
+    twiddles_inv: ABox<[T]>,
+    four_step: Option<FourStep<T>>,
+}
+
+/// Four-step decomposition of an FFT of size `n = n1 * n2`.
+///
+/// The input is viewed as an `n1 x n2` matrix. The FFTs of size `n1` are applied to its columns,
+/// the result is multiplied by the twiddles `exp(-2iπ j2 k1 / n)`, then the FFTs of size `n2` are
+/// applied to its rows, and the result is read in column-major order. The matrix is transposed
+/// between the steps so that every FFT is applied to contiguous memory.
+#[derive(Clone)]
+struct FourStep<T> {
+    // FFT of size `n2`
+    inner: Box<Fft<T>>,
+    // `exp(-2iπ j2 k1 / n)` at index `j2 * n1 + k1`
+    twiddles: ABox<[T]>,
+    twiddles_inv: ABox<[T]>,
+    mul_assign: fn(&mut [T], &[T]),
+}
+
+impl<T: FftComplex> Fft<T> {
+    fn new(algo: FftAlgo, n: usize, simd: SimdLevel) -> Self {
+        use FftAlgo::*;
+        let r = match algo {
+            Dif2 | Dit2 => 2,
+            Dif4 | Dit4 => 4,
+            Dif8 | Dit8 => 8,
+            Dif16 | Dit16 => 16,
+        };
+
+        let n1 = kernel_size(n);
+        let mut twiddles = avec![T::default(); 2 * n1].into_boxed_slice();
+        let mut twiddles_inv = avec![T::default(); 2 * n1].into_boxed_slice();
+        let [fwd, inv] = if n1.is_power_of_two() {
+            fft_simd::init_wt(r, n1, &mut twiddles, &mut twiddles_inv);
+            T::get_fn_ptr(algo, n1, simd)
+        } else {
+            mixed_radix::init_wt(n1, &mut twiddles, &mut twiddles_inv);
+            T::get_mixed_fn_ptr(simd)
+        };
+
+        let four_step = if n1 == n {
+            None
+        } else {
+            let n2 = n / n1;
+            let mut four_step_twiddles = avec![T::default(); n].into_boxed_slice();
+            let mut four_step_twiddles_inv = avec![T::default(); n].into_boxed_slice();
+
+            let theta = -2.0 / n as f64;
+            for (j2, (w, w_inv)) in four_step_twiddles
+                .chunks_exact_mut(n1)
+                .zip(four_step_twiddles_inv.chunks_exact_mut(n1))
+                .enumerate()
+            {
+                for (k1, (w, w_inv)) in w.iter_mut().zip(w_inv).enumerate() {
+                    let (s, c) = fft_simd::sincospi64(theta * (j2 * k1) as f64);
+                    *w = T::from_c64(c64 { re: c, im: s });
+                    *w_inv = T::from_c64(c64 { re: c, im: -s });
+                }
+            }
+
+            Some(FourStep {
+                inner: Box::new(Fft::new(algo, n2, simd)),
+                twiddles: four_step_twiddles,
+                twiddles_inv: four_step_twiddles_inv,
+                mul_assign: T::get_mul_assign(simd),
+            })
+        };
+
+        Self {
+            fwd,
+            inv,
+            twiddles,
+            twiddles_inv,
+            four_step,
+        }
+    }
+
+    fn fft_size(&self) -> usize {
+        match &self.four_step {
+            None => self.twiddles.len() / 2,
+            Some(four_step) => four_step.twiddles.len(),
+        }
+    }
+
+    fn fft_scratch(&self) -> Result<StackReq, SizeOverflow> {
+        let kernel_scratch =
+            StackReq::try_new_aligned::<T>(self.twiddles.len() / 2, CACHELINE_ALIGN)?;
+        match &self.four_step {
+            None => Ok(kernel_scratch),
+            Some(four_step) => StackReq::try_new_aligned::<T>(self.fft_size(), CACHELINE_ALIGN)?
+                .try_and(kernel_scratch.try_or(four_step.inner.fft_scratch()?)?),
+        }
+    }
+
+    fn process(&self, fwd: bool, buf: &mut [T], stack: PodStack) {
+        self.process_impl(fwd, None, buf, stack)
+    }
+
+    /// Computes the FFT of `src` if it is provided, or of `buf` otherwise, and stores the result
+    /// in `buf`.
+    fn process_impl(&self, fwd: bool, src: Option<&[T]>, buf: &mut [T], stack: PodStack) {
+        let (kernel, kernel_twiddles) = if fwd {
+            (self.fwd, &*self.twiddles)
+        } else {
+            (self.inv, &*self.twiddles_inv)
+        };
+        let (w_init, w) = split_2(kernel_twiddles);
+        let n1 = kernel_twiddles.len() / 2;
+
+        match &self.four_step {
+            None => {
+                let (scratch, _) = stack.make_aligned_raw::<T>(n1, CACHELINE_ALIGN);
+                kernel(src, buf, scratch, w_init, w);
+            }
+            Some(four_step) => {
+                let n = buf.len();
+                let n2 = n / n1;
+                let (tmp, mut stack) = stack.make_aligned_raw::<T>(n, CACHELINE_ALIGN);
+
+                // FFTs of size `n1` on the columns of the `n1 x n2` input. when computing out of
+                // place, the input is read directly, and `buf` is only written to afterwards
+                transpose(tmp, src.unwrap_or(buf), n1, n2);
+                {
+                    let (scratch, _) = stack.rb_mut().make_aligned_raw::<T>(n1, CACHELINE_ALIGN);
+                    for row in tmp.chunks_exact_mut(n1) {
+                        kernel(None, row, scratch, w_init, w);
+                    }
+                }
+                (four_step.mul_assign)(
+                    tmp,
+                    if fwd {
+                        &four_step.twiddles
+                    } else {
+                        &four_step.twiddles_inv
+                    },
+                );
+
+                // FFTs of size `n2` on the rows
+                transpose(buf, tmp, n2, n1);
+                for row in buf.chunks_exact_mut(n2) {
+                    four_step.inner.process(fwd, row, stack.rb_mut());
+                }
+
+                // read the result in column-major order
+                transpose(tmp, buf, n1, n2);
+                buf.copy_from_slice(tmp);
+            }
+        }
+    }
+}
+
+/// FFT of size `n` computed with Bluestein's algorithm.
+///
+/// Using `2pk = p² + k² - (k - p)²`, the FFT is rewritten as
+/// `X_k = c_k sum_p (x_p c_p) conj(c_(k - p))`, where `c_k = exp(-iπ k² / n)`. The sum is a
+/// convolution, which is computed by a cyclic convolution of power-of-two size `m >= 2n - 1`.
+#[derive(Clone)]
+struct Bluestein<T> {
+    // FFT of size `m`
+    inner: Fft<T>,
+    // `exp(-iπ k² / n)` for `k` in `0..n`
+    chirp: ABox<[T]>,
+    chirp_inv: ABox<[T]>,
+    // forward FFT of `conj(c_k) / m`, where `k` is taken in `-(n - 1)..n` modulo `m`
+    kernel: ABox<[T]>,
+    kernel_inv: ABox<[T]>,
+    mul_assign: fn(&mut [T], &[T]),
+}
+
+impl<T: FftComplex> Bluestein<T> {
+    fn new(algo: FftAlgo, n: usize, simd: SimdLevel) -> Self {
+        let m = bluestein_size(n);
+        let inner = Fft::new(algo, m, simd);
+
+        // `k²` is reduced modulo `2n`, which is the period of the chirp
+        let mut chirp_c64 = alloc::vec![c64::default(); n];
+        let mut chirp = avec![T::default(); n].into_boxed_slice();
+        let mut chirp_inv = avec![T::default(); n].into_boxed_slice();
+        let mut k2 = 0usize;
+        let theta = -1.0 / n as f64;
+        for (k, ((w_c64, w), w_inv)) in chirp_c64
+            .iter_mut()
+            .zip(chirp.iter_mut())
+            .zip(chirp_inv.iter_mut())
+            .enumerate()
+        {
+            let (s, c) = fft_simd::sincospi64(theta * k2 as f64);
+            *w_c64 = c64 { re: c, im: s };
+            *w = T::from_c64(c64 { re: c, im: s });
+            *w_inv = T::from_c64(c64 { re: c, im: -s });
+            k2 = (k2 + 2 * k + 1) % (2 * n);
+        }
+
+        let mut mem = alloc::vec![0u8; inner.fft_scratch().unwrap().unaligned_bytes_required()];
+        let mut stack = PodStack::new(&mut mem);
+        let mut make_kernel = |conj: bool| {
+            let scale = 1.0 / m as f64;
+            let mut kernel = avec![T::default(); m].into_boxed_slice();
+            for (k, &w) in chirp_c64.iter().enumerate() {
+                let w = T::from_c64(if conj { w.conj() } else { w } * scale);
+                kernel[k] = w;
+                if k > 0 {
+                    kernel[m - k] = w;
+                }
+            }
+            inner.process(true, &mut kernel, stack.rb_mut());
+            kernel
+        };
+        let kernel = make_kernel(true);
+        let kernel_inv = make_kernel(false);
+
+        Self {
+            chirp,
+            chirp_inv,
+            kernel,
+            kernel_inv,
+            mul_assign: T::get_mul_assign(simd),
+            inner,
+        }
+    }
+
+    fn fft_size(&self) -> usize {
+        self.chirp.len()
+    }
+
+    fn fft_scratch(&self) -> Result<StackReq, SizeOverflow> {
+        StackReq::try_new_aligned::<T>(self.inner.fft_size(), CACHELINE_ALIGN)?
+            .try_and(self.inner.fft_scratch()?)
+    }
+
+    fn process_impl(&self, fwd: bool, src: Option<&[T]>, buf: &mut [T], stack: PodStack) {
+        let (chirp, kernel) = if fwd {
+            (&*self.chirp, &*self.kernel)
+        } else {
+            (&*self.chirp_inv, &*self.kernel_inv)
+        };
+        let n = buf.len();
+        let m = self.inner.fft_size();
+        let (tmp, mut stack) = stack.make_aligned_raw::<T>(m, CACHELINE_ALIGN);
+
+        let (head, tail) = tmp.split_at_mut(n);
+        head.copy_from_slice(src.unwrap_or(buf));
+        (self.mul_assign)(head, chirp);
+        tail.fill(T::default());
+
+        self.inner.process(true, tmp, stack.rb_mut());
+        (self.mul_assign)(tmp, kernel);
+        self.inner.process(false, tmp, stack);
+
+        buf.copy_from_slice(&tmp[..n]);
+        (self.mul_assign)(buf, chirp);
+    }
+}
+
+/// FFT of an arbitrary size, computed with the Stockham kernels when its prime factors are all in
+/// `{2, 3, 5}`, and with Bluestein's algorithm otherwise.
+#[derive(Clone)]
+enum AnyFft<T> {
+    Stockham(Fft<T>),
+    Bluestein(Box<Bluestein<T>>),
+}
+
+impl<T: FftComplex> AnyFft<T> {
+    fn new(algo: FftAlgo, n: usize, simd: SimdLevel) -> Self {
+        if mixed_radix::is_supported(n) {
+            Self::Stockham(Fft::new(algo, n, simd))
+        } else {
+            Self::Bluestein(Box::new(Bluestein::new(algo, n, simd)))
+        }
+    }
+
+    fn fft_size(&self) -> usize {
+        match self {
+            Self::Stockham(fft) => fft.fft_size(),
+            Self::Bluestein(fft) => fft.fft_size(),
+        }
+    }
+
+    fn fft_scratch(&self) -> Result<StackReq, SizeOverflow> {
+        match self {
+            Self::Stockham(fft) => fft.fft_scratch(),
+            Self::Bluestein(fft) => fft.fft_scratch(),
+        }
+    }
+
+    fn process(&self, fwd: bool, buf: &mut [T], stack: PodStack) {
+        self.process_impl(fwd, None, buf, stack)
+    }
+
+    fn process_impl(&self, fwd: bool, src: Option<&[T]>, buf: &mut [T], stack: PodStack) {
+        match self {
+            Self::Stockham(fft) => fft.process_impl(fwd, src, buf, stack),
+            Self::Bluestein(fft) => fft.process_impl(fwd, src, buf, stack),
+        }
+    }
+}
+
+/// Writes the transpose of the `rows x cols` row-major matrix `src` to `dst`.
+fn transpose<T: Copy>(dst: &mut [T], src: &[T], rows: usize, cols: usize) {
+    const BLOCK: usize = 16;
+    assert_eq!(dst.len(), rows * cols);
+    assert_eq!(src.len(), rows * cols);
+
+    for i0 in (0..rows).step_by(BLOCK) {
+        for j0 in (0..cols).step_by(BLOCK) {
+            for i in i0..(i0 + BLOCK).min(rows) {
+                for j in j0..(j0 + BLOCK).min(cols) {
+                    dst[j * rows + i] = src[i * cols + j];
+                }
+            }
+        }
     }
 }
 
 impl Plan {
     /// Returns a new FFT plan for the given vector size, selected by the provided method.
     ///
+    /// Sizes larger than `2^10` are computed with a four-step decomposition, with FFTs of size at
+    /// most `2^10` at each step. Sizes with prime factors other than 2, 3 and 5 are computed with
+    /// Bluestein's algorithm, using power-of-two FFTs of size at least `2n - 1`.
+    ///
     /// # Panics
     ///
-    /// - Panics if `n` is not a power of two.
-    /// - Panics if `n` is greater than `2^10`.
+    /// - Panics if `n` is zero.
     ///
     /// # Example
     #[cfg_attr(feature = "std", doc = " ```")]
@@ -239,42 +761,110 @@ impl Plan {
     ///
     /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
     /// ```
+    #[track_caller]
     pub fn new(n: usize, method: Method) -> Self {
-        assert!(n.is_power_of_two());
-        assert!(n.trailing_zeros() < 11);
+        match Self::try_new(n, method) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Returns a new FFT plan for the given vector size, selected by the provided method, or an
+    /// error if `n` is zero.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::ordered::{Method, Plan};
+    /// use concrete_fft::PlanError;
+    /// use core::time::Duration;
+    ///
+    /// let method = Method::Measure(Duration::from_millis(10));
+    /// assert!(Plan::try_new(4, method).is_ok());
+    /// assert!(Plan::try_new(6, method).is_ok());
+    /// assert_eq!(
+    ///     Plan::try_new(0, method).unwrap_err(),
+    ///     PlanError::TooSmall { size: 0, min: 1 },
+    /// );
+    /// ```
+    pub fn try_new(n: usize, method: Method) -> Result<Self, PlanError> {
+        Self::try_new_with_simd(n, method, SimdLevel::detect())
+    }
+
+    /// Returns a new FFT plan for the given vector size, selected by the provided method, whose
+    /// kernels use the provided SIMD instruction set instead of the best one available at runtime.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `n` is zero.
+    /// - Panics if `simd` is not available on the current machine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::{FftAlgo, Method, Plan},
+    ///     SimdLevel,
+    /// };
+    ///
+    /// let plan = Plan::new_with_simd(4, Method::UserProvided(FftAlgo::Dif2), SimdLevel::Scalar);
+    /// assert_eq!(plan.simd_level(), SimdLevel::Scalar);
+    /// ```
+    #[track_caller]
+    pub fn new_with_simd(n: usize, method: Method, simd: SimdLevel) -> Self {
+        match Self::try_new_with_simd(n, method, simd) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Returns a new FFT plan for the given vector size, selected by the provided method, whose
+    /// kernels use the provided SIMD instruction set, or an error if `n` is zero or if `simd` is
+    /// not available on the current machine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::{FftAlgo, Method, Plan},
+    ///     PlanError, SimdLevel,
+    /// };
+    ///
+    /// let method = Method::UserProvided(FftAlgo::Dif2);
+    /// assert!(Plan::try_new_with_simd(4, method, SimdLevel::Scalar).is_ok());
+    /// if !SimdLevel::Avx512.is_available() {
+    ///     assert_eq!(
+    ///         Plan::try_new_with_simd(4, method, SimdLevel::Avx512).unwrap_err(),
+    ///         PlanError::SimdUnavailable {
+    ///             simd: SimdLevel::Avx512
+    ///         },
+    ///     );
+    /// }
+    /// ```
+    pub fn try_new_with_simd(n: usize, method: Method, simd: SimdLevel) -> Result<Self, PlanError> {
+        if n == 0 {
+            return Err(PlanError::TooSmall { size: n, min: 1 });
+        }
+        check_simd_level(simd)?;
 
         let algo = match method {
             Method::UserProvided(algo) => algo,
+            Method::Wisdom(wisdom) => wisdom
+                .ordered(n, simd)
+                .ok_or(PlanError::MissingWisdom { size: n })?,
             #[cfg(feature = "std")]
-            Method::Measure(duration) => {
-                measure_fastest(
-                    duration,
-                    n,
-                    PodStack::new(&mut GlobalPodBuffer::new(measure_fastest_scratch(n))),
-                )
-                .0
-            }
+            Method::Measure(duration) => measure_algo::<c64>(n, duration, simd),
         };
 
-        let [fwd, inv] = get_fn_ptr(algo, n);
-
-        let mut twiddles = avec![c64::default(); 2 * n].into_boxed_slice();
-        let mut twiddles_inv = avec![c64::default(); 2 * n].into_boxed_slice();
-        use FftAlgo::*;
-        let r = match algo {
-            Dif2 | Dit2 => 2,
-            Dif4 | Dit4 => 4,
-            Dif8 | Dit8 => 8,
-            Dif16 | Dit16 => 16,
-        };
-        fft_simd::init_wt(r, n, &mut twiddles, &mut twiddles_inv);
-        Self {
-            fwd,
-            inv,
-            twiddles,
+        Ok(Self {
+            fft: AnyFft::new(algo, n, simd),
             algo,
-            twiddles_inv,
-        }
+            simd,
+            #[cfg(feature = "std")]
+            real: std::sync::OnceLock::new(),
+            #[cfg(feature = "std")]
+            split: std::sync::OnceLock::new(),
+        })
     }
 
     /// Returns the vector size of the FFT.
@@ -289,7 +879,7 @@ impl Plan {
     /// assert_eq!(plan.fft_size(), 4);
     /// ```
     pub fn fft_size(&self) -> usize {
-        self.twiddles.len() / 2
+        self.fft.fft_size()
     }
 
     /// Returns the algorithm that's internally used by the FFT.
@@ -306,6 +896,23 @@ impl Plan {
         self.algo
     }
 
+    /// Returns the SIMD instruction set that's internally used by the FFT.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::{FftAlgo, Method, Plan},
+    ///     SimdLevel,
+    /// };
+    ///
+    /// let plan = Plan::new(4, Method::UserProvided(FftAlgo::Dif2));
+    /// assert_eq!(plan.simd_level(), SimdLevel::detect());
+    /// ```
+    pub fn simd_level(&self) -> SimdLevel {
+        self.simd
+    }
+
     /// Returns the size and alignment of the scratch memory needed to perform an FFT.
     ///
     /// # Example
@@ -318,7 +925,7 @@ impl Plan {
     /// let scratch = plan.fft_scratch().unwrap();
     /// ```
     pub fn fft_scratch(&self) -> Result<StackReq, SizeOverflow> {
-        StackReq::try_new_aligned::<c64>(self.fft_size(), CACHELINE_ALIGN)
+        self.fft.fft_scratch()
     }
 
     /// Performs a forward FFT in place, using the provided stack as scratch space.
@@ -340,10 +947,8 @@ impl Plan {
     /// plan.fwd(&mut buf, stack);
     /// ```
     pub fn fwd(&self, buf: &mut [c64], stack: PodStack) {
-        let n = self.fft_size();
-        let (scratch, _) = stack.make_aligned_raw::<c64>(n, CACHELINE_ALIGN);
-        let (w_init, w) = split_2(&self.twiddles);
-        (self.fwd)(buf, scratch, w_init, w)
+        assert_eq!(buf.len(), self.fft_size());
+        self.fft.process(true, buf, stack)
     }
 
     /// Performs an inverse FFT in place, using the provided stack as scratch space.
@@ -366,27 +971,1701 @@ impl Plan {
     /// plan.inv(&mut buf, stack);
     /// ```
     pub fn inv(&self, buf: &mut [c64], stack: PodStack) {
-        let n = self.fft_size();
-        let (scratch, _) = stack.make_aligned_raw::<c64>(n, CACHELINE_ALIGN);
-        let (w_init, w) = split_2(&self.twiddles_inv);
-        (self.inv)(buf, scratch, w_init, w)
+        assert_eq!(buf.len(), self.fft_size());
+        self.fft.process(false, buf, stack)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::{
-        c64, dif16, dif2, dif4, dif8, dit16, dit2, dit4, dit8,
-        fft_simd::{init_wt, FftSimd, Pod},
-    };
-    use num_complex::ComplexFloat;
-    use rand::random;
-    use rustfft::FftPlanner;
+    /// Performs a forward FFT of `input`, storing the result in `output`, and using the provided
+    /// stack as scratch space.
+    ///
+    /// The scratch space requirement is the same as that of [`Plan::fwd`].
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `input` or `output` is not equal to the FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::ordered::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// let input = [c64::default(); 4];
+    /// let mut output = [c64::default(); 4];
+    /// plan.fwd_out_of_place(&input, &mut output, stack);
+    /// ```
+    pub fn fwd_out_of_place(&self, input: &[c64], output: &mut [c64], stack: PodStack) {
+        assert_eq!(input.len(), self.fft_size());
+        assert_eq!(output.len(), self.fft_size());
+        self.fft.process_impl(true, Some(input), output, stack)
+    }
+
+    /// Performs an inverse FFT of `input`, storing the result in `output`, and using the provided
+    /// stack as scratch space.
+    ///
+    /// The scratch space requirement is the same as that of [`Plan::inv`].
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `input` or `output` is not equal to the FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::ordered::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer, ReborrowMut};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let mut stack = PodStack::new(&mut memory);
+    ///
+    /// let input = [c64::default(); 4];
+    /// let mut transformed = [c64::default(); 4];
+    /// let mut output = [c64::default(); 4];
+    /// plan.fwd_out_of_place(&input, &mut transformed, stack.rb_mut());
+    /// plan.inv_out_of_place(&transformed, &mut output, stack);
+    /// ```
+    pub fn inv_out_of_place(&self, input: &[c64], output: &mut [c64], stack: PodStack) {
+        assert_eq!(input.len(), self.fft_size());
+        assert_eq!(output.len(), self.fft_size());
+        self.fft.process_impl(false, Some(input), output, stack)
+    }
+
+    /// Returns the size and alignment of the scratch memory needed to perform a real FFT with
+    /// [`Plan::fwd_real`] or [`Plan::inv_real`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::ordered::{FftAlgo, Method, Plan};
+    ///
+    /// let plan = Plan::new(4, Method::UserProvided(FftAlgo::Dif4));
+    /// let scratch = plan.fft_real_scratch().unwrap();
+    /// ```
+    pub fn fft_real_scratch(&self) -> Result<StackReq, SizeOverflow> {
+        #[cfg(feature = "std")]
+        {
+            self.real_plan().fft_scratch()
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            full_scratch(&self.fft)
+        }
+    }
+
+    /// Performs a forward FFT of the real vector `buf`, storing the first `n / 2 + 1` terms of
+    /// the result in `out`, and using the provided stack as scratch space.
+    ///
+    /// This computes the same transform as [`RealPlan::fwd`]. The [`RealPlan`] is built the first
+    /// time that a real FFT is performed with this plan, and is reused afterwards. Without the
+    /// `std` feature, the real FFT is instead computed with a complex FFT of size `n`.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `buf` is not equal to the FFT size.
+    /// - Panics if the length of `out` is not equal to `n / 2 + 1`.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::ordered::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_real_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// let buf = [1.0, 2.0, 3.0, 4.0];
+    /// let mut out = [c64::default(); 3];
+    /// plan.fwd_real(&buf, &mut out, stack);
+    /// assert_eq!(out, [c64::new(10.0, 0.0), c64::new(-2.0, 2.0), c64::new(-2.0, 0.0)]);
+    /// ```
+    pub fn fwd_real(&self, buf: &[f64], out: &mut [c64], stack: PodStack) {
+        #[cfg(feature = "std")]
+        {
+            self.real_plan().fwd(buf, out, stack)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            let n = self.fft_size();
+            assert_eq!(buf.len(), n);
+            assert_eq!(out.len(), n / 2 + 1);
+            full_real_fwd(&self.fft, buf, out, stack)
+        }
+    }
+
+    /// Performs an inverse FFT of the Hermitian vector whose first `n / 2 + 1` terms are given by
+    /// `buf`, storing the real result in `out`, and using the provided stack as scratch space.
+    ///
+    /// This computes the same transform as [`RealPlan::inv`], and is not normalized.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `buf` is not equal to `n / 2 + 1`.
+    /// - Panics if the length of `out` is not equal to the FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::ordered::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer, ReborrowMut};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_real_scratch().unwrap());
+    /// let mut stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [1.0, 2.0, 3.0, 4.0];
+    /// let mut out = [c64::default(); 3];
+    /// plan.fwd_real(&buf, &mut out, stack.rb_mut());
+    /// plan.inv_real(&out, &mut buf, stack);
+    /// assert_eq!(buf, [4.0, 8.0, 12.0, 16.0]);
+    /// ```
+    pub fn inv_real(&self, buf: &[c64], out: &mut [f64], stack: PodStack) {
+        #[cfg(feature = "std")]
+        {
+            self.real_plan().inv(buf, out, stack)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            let n = self.fft_size();
+            assert_eq!(buf.len(), n / 2 + 1);
+            assert_eq!(out.len(), n);
+            full_real_inv(&self.fft, buf, out, stack)
+        }
+    }
+
+    /// Returns the size and alignment of the scratch memory needed to perform a split-complex FFT
+    /// with [`Plan::fwd_split`] or [`Plan::inv_split`].
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the FFT size is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::ordered::{FftAlgo, Method, Plan};
+    ///
+    /// let plan = Plan::new(4, Method::UserProvided(FftAlgo::Dif4));
+    /// let scratch = plan.fft_split_scratch().unwrap();
+    /// ```
+    #[track_caller]
+    pub fn fft_split_scratch(&self) -> Result<StackReq, SizeOverflow> {
+        #[cfg(feature = "std")]
+        {
+            self.split_plan().fft_scratch()
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            self.assert_split();
+            full_scratch(&self.fft)
+        }
+    }
+
+    /// Performs a forward FFT in place on the split-complex vector whose real and imaginary parts
+    /// are stored in `re` and `im`, using the provided stack as scratch space.
+    ///
+    /// This computes the same transform as [`SplitPlan::fwd`]. The [`SplitPlan`] is built the
+    /// first time that a split-complex FFT is performed with this plan, and is reused afterwards.
+    /// Without the `std` feature, the vector is instead interleaved into a complex buffer and
+    /// transformed with [`Plan::fwd`].
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the FFT size is not a power of two.
+    /// - Panics if the length of `re` or `im` is not equal to the FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::ordered::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_split_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// let mut re = [1.0, 2.0, 3.0, 4.0];
+    /// let mut im = [0.0; 4];
+    /// plan.fwd_split(&mut re, &mut im, stack);
+    /// assert_eq!(re, [10.0, -2.0, -2.0, -2.0]);
+    /// assert_eq!(im, [0.0, 2.0, 0.0, -2.0]);
+    /// ```
+    #[track_caller]
+    pub fn fwd_split(&self, re: &mut [f64], im: &mut [f64], stack: PodStack) {
+        #[cfg(feature = "std")]
+        {
+            self.split_plan().fwd(re, im, stack)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            self.assert_split();
+            full_split(true, &self.fft, re, im, stack)
+        }
+    }
+
+    /// Performs an inverse FFT in place on the split-complex vector whose real and imaginary parts
+    /// are stored in `re` and `im`, using the provided stack as scratch space.
+    ///
+    /// This computes the same transform as [`SplitPlan::inv`], and is not normalized.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the FFT size is not a power of two.
+    /// - Panics if the length of `re` or `im` is not equal to the FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::ordered::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer, ReborrowMut};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_split_scratch().unwrap());
+    /// let mut stack = PodStack::new(&mut memory);
+    ///
+    /// let mut re = [1.0, 2.0, 3.0, 4.0];
+    /// let mut im = [0.0; 4];
+    /// plan.fwd_split(&mut re, &mut im, stack.rb_mut());
+    /// plan.inv_split(&mut re, &mut im, stack);
+    /// assert_eq!(re, [4.0, 8.0, 12.0, 16.0]);
+    /// ```
+    #[track_caller]
+    pub fn inv_split(&self, re: &mut [f64], im: &mut [f64], stack: PodStack) {
+        #[cfg(feature = "std")]
+        {
+            self.split_plan().inv(re, im, stack)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            self.assert_split();
+            full_split(false, &self.fft, re, im, stack)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn real_plan(&self) -> &RealPlan {
+        self.real.get_or_init(|| RealPlan::new(self))
+    }
+
+    #[cfg(feature = "std")]
+    #[track_caller]
+    fn split_plan(&self) -> &SplitPlan {
+        self.split.get_or_init(|| SplitPlan::new(self))
+    }
+
+    // split-complex FFTs have the same size restrictions with or without the `std` feature
+    #[cfg(not(feature = "std"))]
+    #[track_caller]
+    fn assert_split(&self) {
+        if let Err(err) = check_power_of_two(self.fft_size()) {
+            panic!("{err}");
+        }
+    }
+}
+
+// configuration of a plan that is sent over the wire. the tables are always rebuilt from it when
+// deserializing
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Plan")]
+struct PlanConfig {
+    n: usize,
+    algo: FftAlgo,
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for Plan {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        PlanConfig {
+            n: self.fft_size(),
+            algo: self.algo,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// The SIMD instruction set of the plan is not serialized, since the plan may be deserialized on a
+/// different machine. The deserialized plan uses the best instruction set available at runtime.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for Plan {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let PlanConfig { n, algo } = PlanConfig::deserialize(deserializer)?;
+        Self::try_new(n, Method::UserProvided(algo)).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Ordered FFT plan for split-complex vectors, whose real and imaginary parts are stored in two
+/// separate slices.
+///
+/// This type is created from a [`Plan`] whose size is a power of two, and uses the same algorithm
+/// and SIMD instruction set. Its twiddling factors are only computed when it is created, so plans
+/// that never perform split-complex FFTs don't pay for them.
+#[derive(Clone)]
+pub struct SplitPlan {
+    fft: SplitFft,
+    algo: FftAlgo,
+}
+
+impl core::fmt::Debug for SplitPlan {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SplitPlan")
+            .field("algo", &self.algo)
+            .field("fft_size", &self.fft_size())
+            .finish()
+    }
+}
+
+impl SplitPlan {
+    /// Returns a new split-complex FFT plan with the same size and algorithm as `plan`.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the size of `plan` is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::ordered::{FftAlgo, Method, Plan, SplitPlan};
+    ///
+    /// let plan = Plan::new(4, Method::UserProvided(FftAlgo::Dif4));
+    /// let split = SplitPlan::new(&plan);
+    /// ```
+    #[track_caller]
+    pub fn new(plan: &Plan) -> Self {
+        match Self::try_new(plan) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Returns a new split-complex FFT plan with the same size and algorithm as `plan`, or an
+    /// error if the size of `plan` is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::{FftAlgo, Method, Plan, SplitPlan},
+    ///     PlanError,
+    /// };
+    ///
+    /// let method = Method::UserProvided(FftAlgo::Dif4);
+    /// assert!(SplitPlan::try_new(&Plan::new(4, method)).is_ok());
+    /// assert_eq!(
+    ///     SplitPlan::try_new(&Plan::new(6, method)).unwrap_err(),
+    ///     PlanError::NotPowerOfTwo { size: 6 },
+    /// );
+    /// ```
+    pub fn try_new(plan: &Plan) -> Result<Self, PlanError> {
+        let n = plan.fft_size();
+        check_power_of_two(n)?;
+
+        Ok(Self {
+            fft: SplitFft::new(plan.algo, n, plan.simd),
+            algo: plan.algo,
+        })
+    }
+
+    /// Returns the vector size of the FFT.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::ordered::{FftAlgo, Method, Plan, SplitPlan};
+    ///
+    /// let plan = SplitPlan::new(&Plan::new(4, Method::UserProvided(FftAlgo::Dif4)));
+    /// assert_eq!(plan.fft_size(), 4);
+    /// ```
+    pub fn fft_size(&self) -> usize {
+        self.fft.fft_size()
+    }
+
+    /// Returns the algorithm that's internally used by the FFT.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::ordered::{FftAlgo, Method, Plan, SplitPlan};
+    ///
+    /// let plan = SplitPlan::new(&Plan::new(4, Method::UserProvided(FftAlgo::Dif4)));
+    /// assert_eq!(plan.algo(), FftAlgo::Dif4);
+    /// ```
+    pub fn algo(&self) -> FftAlgo {
+        self.algo
+    }
+
+    /// Returns the size and alignment of the scratch memory needed to perform an FFT.
+    pub fn fft_scratch(&self) -> Result<StackReq, SizeOverflow> {
+        let scratch = StackReq::try_new_aligned::<f64>(self.fft_size(), CACHELINE_ALIGN)?;
+        scratch.try_and(scratch)
+    }
+
+    /// Performs a forward FFT in place on the split-complex vector whose real and imaginary parts
+    /// are stored in `re` and `im`, using the provided stack as scratch space.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `re` or `im` is not equal to the FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::ordered::{Method, Plan, SplitPlan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer};
+    /// use core::time::Duration;
+    ///
+    /// let plan = SplitPlan::new(&Plan::new(4, Method::Measure(Duration::from_millis(10))));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// let mut re = [1.0, 2.0, 3.0, 4.0];
+    /// let mut im = [0.0; 4];
+    /// plan.fwd(&mut re, &mut im, stack);
+    /// assert_eq!(re, [10.0, -2.0, -2.0, -2.0]);
+    /// assert_eq!(im, [0.0, 2.0, 0.0, -2.0]);
+    /// ```
+    pub fn fwd(&self, re: &mut [f64], im: &mut [f64], stack: PodStack) {
+        let n = self.fft_size();
+        let (scratch_re, stack) = stack.make_aligned_raw::<f64>(n, CACHELINE_ALIGN);
+        let (scratch_im, _) = stack.make_aligned_raw::<f64>(n, CACHELINE_ALIGN);
+        self.fft.fwd(re, im, scratch_re, scratch_im);
+    }
+
+    /// Performs an inverse FFT in place on the split-complex vector whose real and imaginary parts
+    /// are stored in `re` and `im`, using the provided stack as scratch space.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `re` or `im` is not equal to the FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::ordered::{Method, Plan, SplitPlan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer, ReborrowMut};
+    /// use core::time::Duration;
+    ///
+    /// let plan = SplitPlan::new(&Plan::new(4, Method::Measure(Duration::from_millis(10))));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let mut stack = PodStack::new(&mut memory);
+    ///
+    /// let mut re = [1.0, 2.0, 3.0, 4.0];
+    /// let mut im = [0.0; 4];
+    /// plan.fwd(&mut re, &mut im, stack.rb_mut());
+    /// plan.inv(&mut re, &mut im, stack);
+    /// assert_eq!(re, [4.0, 8.0, 12.0, 16.0]);
+    /// ```
+    pub fn inv(&self, re: &mut [f64], im: &mut [f64], stack: PodStack) {
+        let n = self.fft_size();
+        let (scratch_re, stack) = stack.make_aligned_raw::<f64>(n, CACHELINE_ALIGN);
+        let (scratch_im, _) = stack.make_aligned_raw::<f64>(n, CACHELINE_ALIGN);
+        self.fft.inv(re, im, scratch_re, scratch_im);
+    }
+}
+
+/// Ordered FFT plan for real vectors, whose forward transform only stores the first `n / 2 + 1`
+/// terms, the others being their complex conjugates.
+///
+/// This type is created from a [`Plan`] and uses the same algorithm and SIMD instruction set.
+/// Even sizes are computed with a complex FFT of size `n / 2`, while odd sizes are computed with a
+/// complex FFT of size `n`. Its FFT and twiddling factors are only computed when it is created, so
+/// plans that never perform real FFTs don't pay for them.
+#[derive(Clone)]
+pub struct RealPlan {
+    // FFT of size `n / 2` if `n` is even, and of size `n` otherwise
+    fft: AnyFft<c64>,
+    n: usize,
+    algo: FftAlgo,
+    // `exp(-2iπk/n)` for `k` in `0..n / 2`, only used when `n` is even
+    twiddles: ABox<[c64]>,
+}
+
+impl core::fmt::Debug for RealPlan {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RealPlan")
+            .field("algo", &self.algo)
+            .field("fft_size", &self.fft_size())
+            .finish()
+    }
+}
+
+impl RealPlan {
+    /// Returns a new real FFT plan with the same size and algorithm as `plan`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::ordered::{FftAlgo, Method, Plan, RealPlan};
+    ///
+    /// let plan = Plan::new(4, Method::UserProvided(FftAlgo::Dif4));
+    /// let real = RealPlan::new(&plan);
+    /// ```
+    pub fn new(plan: &Plan) -> Self {
+        let n = plan.fft_size();
+
+        if !n.is_multiple_of(2) {
+            return Self {
+                fft: plan.fft.clone(),
+                n,
+                algo: plan.algo,
+                twiddles: avec![c64::default(); 0].into_boxed_slice(),
+            };
+        }
+
+        let mut twiddles = avec![c64::default(); n / 2].into_boxed_slice();
+        let theta = -2.0 / n as f64;
+        for (k, w) in twiddles.iter_mut().enumerate() {
+            let (s, c) = fft_simd::sincospi64(theta * k as f64);
+            *w = c64 { re: c, im: s };
+        }
+
+        Self {
+            fft: AnyFft::new(plan.algo, n / 2, plan.simd),
+            n,
+            algo: plan.algo,
+            twiddles,
+        }
+    }
+
+    /// Returns the vector size of the FFT.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::ordered::{FftAlgo, Method, Plan, RealPlan};
+    ///
+    /// let plan = RealPlan::new(&Plan::new(4, Method::UserProvided(FftAlgo::Dif4)));
+    /// assert_eq!(plan.fft_size(), 4);
+    /// ```
+    pub fn fft_size(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the algorithm that's internally used by the FFT.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::ordered::{FftAlgo, Method, Plan, RealPlan};
+    ///
+    /// let plan = RealPlan::new(&Plan::new(4, Method::UserProvided(FftAlgo::Dif4)));
+    /// assert_eq!(plan.algo(), FftAlgo::Dif4);
+    /// ```
+    pub fn algo(&self) -> FftAlgo {
+        self.algo
+    }
+
+    /// Returns the size and alignment of the scratch memory needed to perform an FFT.
+    pub fn fft_scratch(&self) -> Result<StackReq, SizeOverflow> {
+        if self.n.is_multiple_of(2) {
+            self.fft.fft_scratch()
+        } else {
+            full_scratch(&self.fft)
+        }
+    }
+
+    /// Performs a forward FFT of the real vector `buf`, storing the first `n / 2 + 1` terms of
+    /// the result in `out`, and using the provided stack as scratch space.
+    ///
+    /// The remaining terms are given by the Hermitian symmetry of the transform of a real
+    /// vector, $X_{n-p} = \overline{X_p}$.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `buf` is not equal to the FFT size.
+    /// - Panics if the length of `out` is not equal to `n / 2 + 1`.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::ordered::{Method, Plan, RealPlan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer};
+    /// use core::time::Duration;
+    ///
+    /// let plan = RealPlan::new(&Plan::new(4, Method::Measure(Duration::from_millis(10))));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// let buf = [1.0; 4];
+    /// let mut out = [c64::default(); 3];
+    /// plan.fwd(&buf, &mut out, stack);
+    /// ```
+    pub fn fwd(&self, buf: &[f64], out: &mut [c64], stack: PodStack) {
+        let n = self.n;
+        assert_eq!(buf.len(), n);
+        assert_eq!(out.len(), n / 2 + 1);
+
+        if n == 1 {
+            out[0] = c64 {
+                re: buf[0],
+                im: 0.0,
+            };
+            return;
+        }
+
+        if !n.is_multiple_of(2) {
+            full_real_fwd(&self.fft, buf, out, stack);
+            return;
+        }
+
+        // pack the even terms in the real part and the odd terms in the imaginary part, then
+        // perform a complex FFT of size `n / 2`
+        let m = n / 2;
+        let (z, last) = out.split_at_mut(m);
+        for (z, x) in z.iter_mut().zip(buf.chunks_exact(2)) {
+            *z = c64 { re: x[0], im: x[1] };
+        }
+
+        self.fft.process(true, z, stack);
+
+        // unpack the transforms of the even and odd terms, `E_k` and `O_k`, and combine them
+        // with `X_k = E_k + exp(-2iπk/n) O_k`
+        let z0 = z[0];
+        z[0] = c64 {
+            re: z0.re + z0.im,
+            im: 0.0,
+        };
+        last[0] = c64 {
+            re: z0.re - z0.im,
+            im: 0.0,
+        };
+        for k in 1..m / 2 + 1 {
+            let j = m - k;
+            let zk = z[k];
+            let zj = z[j].conj();
+            let w = self.twiddles[k];
+
+            let even = (zk + zj) * 0.5;
+            let odd = (zk - zj) * c64 { re: 0.0, im: -0.5 };
+            let w_odd = w * odd;
+
+            z[k] = even + w_odd;
+            // `E_j = conj(E_k)`, `O_j = conj(O_k)` and `exp(-2iπj/n) = -conj(exp(-2iπk/n))`
+            z[j] = (even - w_odd).conj();
+        }
+    }
+
+    /// Performs an inverse FFT of the Hermitian vector whose first `n / 2 + 1` terms are given by
+    /// `buf`, storing the real result in `out`, and using the provided stack as scratch space.
+    ///
+    /// Like [`Plan::inv`], the result is not normalized, so that applying [`RealPlan::fwd`]
+    /// followed by [`RealPlan::inv`] multiplies the vector by `n`.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `buf` is not equal to `n / 2 + 1`.
+    /// - Panics if the length of `out` is not equal to the FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::ordered::{Method, Plan, RealPlan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer, ReborrowMut};
+    /// use core::time::Duration;
+    ///
+    /// let plan = RealPlan::new(&Plan::new(4, Method::Measure(Duration::from_millis(10))));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let mut stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [1.0; 4];
+    /// let mut out = [c64::default(); 3];
+    /// plan.fwd(&buf, &mut out, stack.rb_mut());
+    /// plan.inv(&out, &mut buf, stack);
+    /// ```
+    pub fn inv(&self, buf: &[c64], out: &mut [f64], stack: PodStack) {
+        let n = self.n;
+        assert_eq!(buf.len(), n / 2 + 1);
+        assert_eq!(out.len(), n);
+
+        if n == 1 {
+            out[0] = buf[0].re;
+            return;
+        }
+
+        if !n.is_multiple_of(2) {
+            full_real_inv(&self.fft, buf, out, stack);
+            return;
+        }
+
+        // build the packed spectrum `Z_k = E_k + i O_k` in the output buffer, then perform a
+        // complex inverse FFT of size `n / 2`
+        let m = n / 2;
+        let z: &mut [c64] = bytemuck::cast_slice_mut(out);
+        let i = c64 { re: 0.0, im: 1.0 };
+
+        let x0 = buf[0];
+        let xm = buf[m].conj();
+        z[0] = (x0 + xm) + i * (x0 - xm);
+        for k in 1..m / 2 + 1 {
+            let xk = buf[k];
+            let xj = buf[m - k].conj();
+            let w = self.twiddles[k].conj();
+
+            let even = xk + xj;
+            let odd = (xk - xj) * w;
+
+            z[k] = even + i * odd;
+            z[m - k] = even.conj() + i * odd.conj();
+        }
+
+        self.fft.process(false, z, stack);
+    }
+}
+
+// scratch memory needed to copy a vector to a complex buffer of the same size and transform it
+fn full_scratch(fft: &AnyFft<c64>) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new_aligned::<c64>(fft.fft_size(), CACHELINE_ALIGN)?.try_and(fft.fft_scratch()?)
+}
+
+// forward real FFT, computed with a complex FFT of the same size
+fn full_real_fwd(fft: &AnyFft<c64>, buf: &[f64], out: &mut [c64], stack: PodStack) {
+    let n = fft.fft_size();
+    let (z, stack) = stack.make_aligned_raw::<c64>(n, CACHELINE_ALIGN);
+    for (z, &x) in z.iter_mut().zip(buf) {
+        *z = c64 { re: x, im: 0.0 };
+    }
+    fft.process(true, z, stack);
+    out.copy_from_slice(&z[..n / 2 + 1]);
+}
+
+// split-complex FFT, computed by interleaving the vector into a complex buffer
+#[cfg(not(feature = "std"))]
+fn full_split(fwd: bool, fft: &AnyFft<c64>, re: &mut [f64], im: &mut [f64], stack: PodStack) {
+    let n = fft.fft_size();
+    assert_eq!(re.len(), n);
+    assert_eq!(im.len(), n);
+
+    let (z, stack) = stack.make_aligned_raw::<c64>(n, CACHELINE_ALIGN);
+    for (z, (&re, &im)) in z.iter_mut().zip(re.iter().zip(&*im)) {
+        *z = c64 { re, im };
+    }
+    fft.process(fwd, z, stack);
+    for (z, (re, im)) in z.iter().zip(re.iter_mut().zip(im)) {
+        *re = z.re;
+        *im = z.im;
+    }
+}
+
+// inverse real FFT, computed with a complex FFT of the same size
+fn full_real_inv(fft: &AnyFft<c64>, buf: &[c64], out: &mut [f64], stack: PodStack) {
+    let n = fft.fft_size();
+    // rebuild the full spectrum from its Hermitian symmetry
+    let (z, stack) = stack.make_aligned_raw::<c64>(n, CACHELINE_ALIGN);
+    z[..n / 2 + 1].copy_from_slice(buf);
+    for k in 1..n.div_ceil(2) {
+        z[n - k] = buf[k].conj();
+    }
+    fft.process(false, z, stack);
+    for (x, z) in out.iter_mut().zip(&*z) {
+        *x = z.re;
+    }
+}
+
+/// Ordered FFT plan for 32-bit complex numbers.
+///
+/// This type is the single precision counterpart of [`Plan`], and holds a forward and inverse FFT
+/// plan and twiddling factors for a specific size. The size must be a power of two.
+#[derive(Clone)]
+pub struct Plan32 {
+    fft: Fft<c32>,
+    algo: FftAlgo,
+    simd: SimdLevel,
+}
+
+impl core::fmt::Debug for Plan32 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Plan32")
+            .field("algo", &self.algo)
+            .field("simd", &self.simd)
+            .field("fft_size", &self.fft_size())
+            .finish()
+    }
+}
+
+impl Plan32 {
+    /// Returns a new FFT plan for the given vector size, selected by the provided method.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `n` is not a power of two.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::ordered::{Method, Plan32};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan32::new(4, Method::Measure(Duration::from_millis(10)));
+    /// ```
+    #[track_caller]
+    pub fn new(n: usize, method: Method) -> Self {
+        match Self::try_new(n, method) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Returns a new FFT plan for the given vector size, selected by the provided method, or an
+    /// error if `n` is not a power of two.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::ordered::{Method, Plan32};
+    /// use concrete_fft::PlanError;
+    /// use core::time::Duration;
+    ///
+    /// let method = Method::Measure(Duration::from_millis(10));
+    /// assert!(Plan32::try_new(4, method).is_ok());
+    /// assert_eq!(
+    ///     Plan32::try_new(6, method).unwrap_err(),
+    ///     PlanError::NotPowerOfTwo { size: 6 },
+    /// );
+    /// ```
+    pub fn try_new(n: usize, method: Method) -> Result<Self, PlanError> {
+        Self::try_new_with_simd(n, method, SimdLevel::detect())
+    }
+
+    /// Returns a new FFT plan for the given vector size, selected by the provided method, whose
+    /// kernels use the provided SIMD instruction set instead of the best one available at runtime.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `n` is not a power of two.
+    /// - Panics if `simd` is not available on the current machine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::{FftAlgo, Method, Plan32},
+    ///     SimdLevel,
+    /// };
+    ///
+    /// let plan = Plan32::new_with_simd(4, Method::UserProvided(FftAlgo::Dif2), SimdLevel::Scalar);
+    /// assert_eq!(plan.simd_level(), SimdLevel::Scalar);
+    /// ```
+    #[track_caller]
+    pub fn new_with_simd(n: usize, method: Method, simd: SimdLevel) -> Self {
+        match Self::try_new_with_simd(n, method, simd) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Returns a new FFT plan for the given vector size, selected by the provided method, whose
+    /// kernels use the provided SIMD instruction set, or an error if `n` is not a power of two or
+    /// if `simd` is not available on the current machine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::{FftAlgo, Method, Plan32},
+    ///     PlanError, SimdLevel,
+    /// };
+    ///
+    /// let method = Method::UserProvided(FftAlgo::Dif2);
+    /// assert!(Plan32::try_new_with_simd(4, method, SimdLevel::Scalar).is_ok());
+    /// if !SimdLevel::Avx512.is_available() {
+    ///     assert_eq!(
+    ///         Plan32::try_new_with_simd(4, method, SimdLevel::Avx512).unwrap_err(),
+    ///         PlanError::SimdUnavailable {
+    ///             simd: SimdLevel::Avx512
+    ///         },
+    ///     );
+    /// }
+    /// ```
+    pub fn try_new_with_simd(n: usize, method: Method, simd: SimdLevel) -> Result<Self, PlanError> {
+        check_power_of_two(n)?;
+        check_simd_level(simd)?;
+
+        let algo = match method {
+            Method::UserProvided(algo) => algo,
+            Method::Wisdom(wisdom) => wisdom
+                .ordered(n, simd)
+                .ok_or(PlanError::MissingWisdom { size: n })?,
+            #[cfg(feature = "std")]
+            Method::Measure(duration) => measure_algo::<c32>(n, duration, simd),
+        };
+
+        Ok(Self {
+            fft: Fft::new(algo, n, simd),
+            algo,
+            simd,
+        })
+    }
+
+    /// Returns the vector size of the FFT.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::ordered::{FftAlgo, Method, Plan32};
+    ///
+    /// let plan = Plan32::new(4, Method::UserProvided(FftAlgo::Dif2));
+    /// assert_eq!(plan.fft_size(), 4);
+    /// ```
+    pub fn fft_size(&self) -> usize {
+        self.fft.fft_size()
+    }
+
+    /// Returns the algorithm that's internally used by the FFT.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::ordered::{FftAlgo, Method, Plan32};
+    ///
+    /// let plan = Plan32::new(4, Method::UserProvided(FftAlgo::Dif2));
+    /// assert_eq!(plan.algo(), FftAlgo::Dif2);
+    /// ```
+    pub fn algo(&self) -> FftAlgo {
+        self.algo
+    }
+
+    /// Returns the SIMD instruction set that's internally used by the FFT.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::{FftAlgo, Method, Plan32},
+    ///     SimdLevel,
+    /// };
+    ///
+    /// let plan = Plan32::new(4, Method::UserProvided(FftAlgo::Dif2));
+    /// assert_eq!(plan.simd_level(), SimdLevel::detect());
+    /// ```
+    pub fn simd_level(&self) -> SimdLevel {
+        self.simd
+    }
+
+    /// Returns the size and alignment of the scratch memory needed to perform an FFT.
+    pub fn fft_scratch(&self) -> Result<StackReq, SizeOverflow> {
+        self.fft.fft_scratch()
+    }
+
+    /// Performs a forward FFT in place, using the provided stack as scratch space.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c32;
+    /// use concrete_fft::ordered::{Method, Plan32};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan32::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [c32::default(); 4];
+    /// plan.fwd(&mut buf, stack);
+    /// ```
+    pub fn fwd(&self, buf: &mut [c32], stack: PodStack) {
+        assert_eq!(buf.len(), self.fft_size());
+        self.fft.process(true, buf, stack)
+    }
+
+    /// Performs an inverse FFT in place, using the provided stack as scratch space.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c32;
+    /// use concrete_fft::ordered::{Method, Plan32};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer, ReborrowMut};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan32::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let mut stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [c32::default(); 4];
+    /// plan.fwd(&mut buf, stack.rb_mut());
+    /// plan.inv(&mut buf, stack);
+    /// ```
+    pub fn inv(&self, buf: &mut [c32], stack: PodStack) {
+        assert_eq!(buf.len(), self.fft_size());
+        self.fft.process(false, buf, stack)
+    }
+}
+
+/// Writes the transpose of the `rows x cols` row-major matrix `src` to `dst`, transposing `4 x 4`
+/// tiles in registers. Matrices whose dimensions are not multiples of `4` are transposed by
+/// [`transpose`] instead.
+#[cfg(all(feature = "avx512", any(target_arch = "x86", target_arch = "x86_64")))]
+#[inline(always)]
+fn transpose_x4<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    dst: &mut [c64xN::Lane],
+    src: &[c64xN::Lane],
+    rows: usize,
+    cols: usize,
+) {
+    const BLOCK: usize = 16;
+    assert_eq!(simd.lane_count(), 4);
+    assert_eq!(dst.len(), rows * cols);
+    assert_eq!(src.len(), rows * cols);
+
+    if !rows.is_multiple_of(4) || !cols.is_multiple_of(4) {
+        return transpose(dst, src, rows, cols);
+    }
+
+    for i0 in (0..rows).step_by(BLOCK) {
+        for j0 in (0..cols).step_by(BLOCK) {
+            for i in (i0..(i0 + BLOCK).min(rows)).step_by(4) {
+                for j in (j0..(j0 + BLOCK).min(cols)).step_by(4) {
+                    let load = |k: usize| -> c64xN {
+                        bytemuck::cast_slice(&src[(i + k) * cols + j..][..4])[0]
+                    };
+                    let (r0, r1, r2, r3) = simd.transpose(load(0), load(1), load(2), load(3));
+                    for (k, r) in [r0, r1, r2, r3].into_iter().enumerate() {
+                        bytemuck::cast_slice_mut(&mut dst[(j + k) * rows + i..][..4])[0] = r;
+                    }
+                }
+            }
+        }
+    }
+}
+
+// returns a function that transposes a row-major matrix, using the best simd instruction set
+// that is available at runtime
+#[cfg_attr(
+    not(all(feature = "avx512", any(target_arch = "x86", target_arch = "x86_64"))),
+    allow(unused_variables)
+)]
+fn get_transpose(simd: SimdLevel) -> fn(&mut [c64], &[c64], usize, usize) {
+    #[cfg(all(feature = "avx512", any(target_arch = "x86", target_arch = "x86_64")))]
+    if simd.v4().is_some() {
+        return |dst, src, rows, cols| {
+            let simd = pulp::x86::V4::try_new().unwrap();
+            simd.vectorize(
+                #[inline(always)]
+                || transpose_x4::<crate::fft_simd::c64x4>(simd, dst, src, rows, cols),
+            );
+        };
+    }
+
+    transpose::<c64>
+}
+
+// applies `plan` to the vectors of each block of `buf`, where each block is a `plan.fft_size() x
+// inner` row-major matrix, and the vectors are its columns
+fn process_axis(
+    plan: &Plan,
+    fwd: bool,
+    buf: &mut [c64],
+    inner: usize,
+    transpose: fn(&mut [c64], &[c64], usize, usize),
+    stack: PodStack,
+) {
+    let n = plan.fft_size();
+    let process = |buf: &mut [c64], stack: PodStack| {
+        if fwd {
+            plan.fwd(buf, stack)
+        } else {
+            plan.inv(buf, stack)
+        }
+    };
+
+    if inner == 1 {
+        let mut stack = stack;
+        for buf in buf.chunks_exact_mut(n) {
+            process(buf, stack.rb_mut());
+        }
+        return;
+    }
+
+    let (tmp, mut stack) = stack.make_aligned_raw::<c64>(n * inner, CACHELINE_ALIGN);
+    for block in buf.chunks_exact_mut(n * inner) {
+        transpose(tmp, block, n, inner);
+        for buf in tmp.chunks_exact_mut(n) {
+            process(buf, stack.rb_mut());
+        }
+        transpose(block, tmp, inner, n);
+    }
+}
+
+fn process_axis_scratch(plan: &Plan, inner: usize) -> Result<StackReq, SizeOverflow> {
+    if inner == 1 {
+        plan.fft_scratch()
+    } else {
+        let n = plan.fft_size().checked_mul(inner).ok_or(SizeOverflow)?;
+        StackReq::try_new_aligned::<c64>(n, CACHELINE_ALIGN)?.try_and(plan.fft_scratch()?)
+    }
+}
+
+/// Two-dimensional ordered FFT plan.
+///
+/// This type holds the FFT plans of the rows and of the columns of a `rows x cols` matrix, stored
+/// in row-major order. Both dimensions must be positive.
+///
+/// The rows are transformed directly, while the columns are transposed into rows before being
+/// transformed, then transposed back.
+#[derive(Clone)]
+pub struct Plan2d {
+    // plan of size `cols`, applied to each row
+    row_plan: Plan,
+    // plan of size `rows`, applied to each column
+    col_plan: Plan,
+    simd: SimdLevel,
+    transpose: fn(&mut [c64], &[c64], usize, usize),
+}
+
+impl core::fmt::Debug for Plan2d {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Plan2d")
+            .field("shape", &self.shape())
+            .field("row_plan", &self.row_plan)
+            .field("col_plan", &self.col_plan)
+            .finish()
+    }
+}
+
+impl Plan2d {
+    /// Returns a new two-dimensional FFT plan for `rows x cols` matrices. The FFT plans of each
+    /// dimension are selected by the provided method.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `rows` or `cols` is zero.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::ordered::{Method, Plan2d};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan2d::new(4, 8, Method::Measure(Duration::from_millis(10)));
+    /// ```
+    #[track_caller]
+    pub fn new(rows: usize, cols: usize, method: Method) -> Self {
+        match Self::try_new(rows, cols, method) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Returns a new two-dimensional FFT plan for `rows x cols` matrices, or an error if `rows`
+    /// or `cols` is zero.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::ordered::{Method, Plan2d};
+    /// use concrete_fft::PlanError;
+    /// use core::time::Duration;
+    ///
+    /// let method = Method::Measure(Duration::from_millis(10));
+    /// assert!(Plan2d::try_new(4, 8, method).is_ok());
+    /// assert_eq!(
+    ///     Plan2d::try_new(4, 0, method).unwrap_err(),
+    ///     PlanError::TooSmall { size: 0, min: 1 },
+    /// );
+    /// ```
+    pub fn try_new(rows: usize, cols: usize, method: Method) -> Result<Self, PlanError> {
+        Self::try_new_with_simd(rows, cols, method, SimdLevel::detect())
+    }
+
+    /// Returns a new two-dimensional FFT plan for `rows x cols` matrices, whose kernels use the
+    /// provided SIMD instruction set instead of the best one available at runtime.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `rows` or `cols` is zero.
+    /// - Panics if `simd` is not available on the current machine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::{FftAlgo, Method, Plan2d},
+    ///     SimdLevel,
+    /// };
+    ///
+    /// let method = Method::UserProvided(FftAlgo::Dif4);
+    /// let plan = Plan2d::new_with_simd(4, 8, method, SimdLevel::Scalar);
+    /// assert_eq!(plan.simd_level(), SimdLevel::Scalar);
+    /// ```
+    #[track_caller]
+    pub fn new_with_simd(rows: usize, cols: usize, method: Method, simd: SimdLevel) -> Self {
+        match Self::try_new_with_simd(rows, cols, method, simd) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Returns a new two-dimensional FFT plan for `rows x cols` matrices, whose kernels use the
+    /// provided SIMD instruction set, or an error if `rows` or `cols` is zero or if `simd` is not
+    /// available on the current machine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::{FftAlgo, Method, Plan2d},
+    ///     PlanError, SimdLevel,
+    /// };
+    ///
+    /// let method = Method::UserProvided(FftAlgo::Dif4);
+    /// assert!(Plan2d::try_new_with_simd(4, 8, method, SimdLevel::Scalar).is_ok());
+    /// if !SimdLevel::Avx512.is_available() {
+    ///     assert_eq!(
+    ///         Plan2d::try_new_with_simd(4, 8, method, SimdLevel::Avx512).unwrap_err(),
+    ///         PlanError::SimdUnavailable {
+    ///             simd: SimdLevel::Avx512
+    ///         },
+    ///     );
+    /// }
+    /// ```
+    pub fn try_new_with_simd(
+        rows: usize,
+        cols: usize,
+        method: Method,
+        simd: SimdLevel,
+    ) -> Result<Self, PlanError> {
+        let row_plan = Plan::try_new_with_simd(cols, method, simd)?;
+        let col_plan = if rows == cols {
+            row_plan.clone()
+        } else {
+            Plan::try_new_with_simd(rows, method, simd)?
+        };
+
+        Ok(Self {
+            row_plan,
+            col_plan,
+            simd,
+            transpose: get_transpose(simd),
+        })
+    }
+
+    /// Returns the number of rows and columns of the matrices transformed by the plan.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::ordered::{FftAlgo, Method, Plan2d};
+    ///
+    /// let plan = Plan2d::new(4, 8, Method::UserProvided(FftAlgo::Dif4));
+    /// assert_eq!(plan.shape(), (4, 8));
+    /// ```
+    pub fn shape(&self) -> (usize, usize) {
+        (self.col_plan.fft_size(), self.row_plan.fft_size())
+    }
+
+    /// Returns the SIMD instruction set that's internally used by the FFT.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::{FftAlgo, Method, Plan2d},
+    ///     SimdLevel,
+    /// };
+    ///
+    /// let plan = Plan2d::new(4, 8, Method::UserProvided(FftAlgo::Dif4));
+    /// assert_eq!(plan.simd_level(), SimdLevel::detect());
+    /// ```
+    pub fn simd_level(&self) -> SimdLevel {
+        self.simd
+    }
+
+    /// Returns the size and alignment of the scratch memory needed to perform an FFT.
+    pub fn fft_scratch(&self) -> Result<StackReq, SizeOverflow> {
+        let (_, cols) = self.shape();
+        process_axis_scratch(&self.row_plan, 1)?.try_or(process_axis_scratch(&self.col_plan, cols)?)
+    }
+
+    /// Performs a forward two-dimensional FFT in place, using the provided stack as scratch space.
+    ///
+    /// # Note
+    ///
+    /// The values in `buf` are stored in row-major order. The forward transform is computed along
+    /// both dimensions, and its terms are in standard order.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `buf` is not equal to `rows * cols`.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::ordered::{Method, Plan2d};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan2d::new(4, 8, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [c64::new(1.0, 0.0); 4 * 8];
+    /// plan.fwd(&mut buf, stack);
+    /// assert_eq!(buf[0], c64::new(32.0, 0.0));
+    /// ```
+    pub fn fwd(&self, buf: &mut [c64], stack: PodStack) {
+        self.process(true, buf, stack);
+    }
+
+    /// Performs an inverse two-dimensional FFT in place, using the provided stack as scratch
+    /// space.
+    ///
+    /// # Note
+    ///
+    /// The values in `buf` are stored in row-major order. Like [`Plan::inv`], the inverse
+    /// transform is not normalized, so applying the forward transform followed by the inverse
+    /// transform multiplies the values by `rows * cols`.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `buf` is not equal to `rows * cols`.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::ordered::{Method, Plan2d};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer, ReborrowMut};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan2d::new(4, 8, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let mut stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [c64::default(); 4 * 8];
+    /// plan.fwd(&mut buf, stack.rb_mut());
+    /// plan.inv(&mut buf, stack);
+    /// ```
+    pub fn inv(&self, buf: &mut [c64], stack: PodStack) {
+        self.process(false, buf, stack);
+    }
+
+    fn process(&self, fwd: bool, buf: &mut [c64], mut stack: PodStack) {
+        let (rows, cols) = self.shape();
+        assert_eq!(buf.len(), rows * cols);
+
+        process_axis(&self.row_plan, fwd, buf, 1, self.transpose, stack.rb_mut());
+        process_axis(&self.col_plan, fwd, buf, cols, self.transpose, stack);
+    }
+}
+
+/// Multi-dimensional ordered FFT plan.
+///
+/// This type holds an FFT plan for each dimension of an array with an arbitrary number of
+/// dimensions, stored in row-major order, i.e. the last dimension is contiguous in memory. All
+/// the dimensions must be positive.
+#[derive(Clone)]
+pub struct PlanNd {
+    plans: Box<[Plan]>,
+    simd: SimdLevel,
+    transpose: fn(&mut [c64], &[c64], usize, usize),
+}
+
+impl core::fmt::Debug for PlanNd {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PlanNd")
+            .field("plans", &self.plans)
+            .finish()
+    }
+}
+
+impl PlanNd {
+    /// Returns a new multi-dimensional FFT plan for arrays of the given shape. The FFT plans of
+    /// each dimension are selected by the provided method.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if any of the dimensions is zero.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::ordered::{Method, PlanNd};
+    /// use core::time::Duration;
+    ///
+    /// let plan = PlanNd::new(&[2, 4, 8], Method::Measure(Duration::from_millis(10)));
+    /// ```
+    #[track_caller]
+    pub fn new(shape: &[usize], method: Method) -> Self {
+        match Self::try_new(shape, method) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Returns a new multi-dimensional FFT plan for arrays of the given shape, or an error if any
+    /// of the dimensions is zero.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::ordered::{Method, PlanNd};
+    /// use concrete_fft::PlanError;
+    /// use core::time::Duration;
+    ///
+    /// let method = Method::Measure(Duration::from_millis(10));
+    /// assert!(PlanNd::try_new(&[2, 4, 8], method).is_ok());
+    /// assert_eq!(
+    ///     PlanNd::try_new(&[2, 0, 8], method).unwrap_err(),
+    ///     PlanError::TooSmall { size: 0, min: 1 },
+    /// );
+    /// ```
+    pub fn try_new(shape: &[usize], method: Method) -> Result<Self, PlanError> {
+        Self::try_new_with_simd(shape, method, SimdLevel::detect())
+    }
+
+    /// Returns a new multi-dimensional FFT plan for arrays of the given shape, whose kernels use
+    /// the provided SIMD instruction set instead of the best one available at runtime.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if any of the dimensions is zero.
+    /// - Panics if `simd` is not available on the current machine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::{FftAlgo, Method, PlanNd},
+    ///     SimdLevel,
+    /// };
+    ///
+    /// let method = Method::UserProvided(FftAlgo::Dif4);
+    /// let plan = PlanNd::new_with_simd(&[2, 4, 8], method, SimdLevel::Scalar);
+    /// assert_eq!(plan.simd_level(), SimdLevel::Scalar);
+    /// ```
+    #[track_caller]
+    pub fn new_with_simd(shape: &[usize], method: Method, simd: SimdLevel) -> Self {
+        match Self::try_new_with_simd(shape, method, simd) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Returns a new multi-dimensional FFT plan for arrays of the given shape, whose kernels use
+    /// the provided SIMD instruction set, or an error if any of the dimensions is zero or if
+    /// `simd` is not available on the current machine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::{FftAlgo, Method, PlanNd},
+    ///     PlanError, SimdLevel,
+    /// };
+    ///
+    /// let method = Method::UserProvided(FftAlgo::Dif4);
+    /// assert!(PlanNd::try_new_with_simd(&[2, 4, 8], method, SimdLevel::Scalar).is_ok());
+    /// if !SimdLevel::Avx512.is_available() {
+    ///     assert_eq!(
+    ///         PlanNd::try_new_with_simd(&[2, 4, 8], method, SimdLevel::Avx512).unwrap_err(),
+    ///         PlanError::SimdUnavailable {
+    ///             simd: SimdLevel::Avx512
+    ///         },
+    ///     );
+    /// }
+    /// ```
+    pub fn try_new_with_simd(
+        shape: &[usize],
+        method: Method,
+        simd: SimdLevel,
+    ) -> Result<Self, PlanError> {
+        check_simd_level(simd)?;
+
+        let mut plans = alloc::vec::Vec::<Plan>::with_capacity(shape.len());
+        for &n in shape {
+            // dimensions of the same size share the same plan
+            let plan = match plans.iter().find(|plan| plan.fft_size() == n) {
+                Some(plan) => plan.clone(),
+                None => Plan::try_new_with_simd(n, method, simd)?,
+            };
+            plans.push(plan);
+        }
+
+        Ok(Self {
+            plans: plans.into_boxed_slice(),
+            simd,
+            transpose: get_transpose(simd),
+        })
+    }
+
+    /// Returns the shape of the arrays transformed by the plan.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::ordered::{FftAlgo, Method, PlanNd};
+    ///
+    /// let plan = PlanNd::new(&[2, 4, 8], Method::UserProvided(FftAlgo::Dif4));
+    /// assert_eq!(plan.shape().collect::<Vec<_>>(), [2, 4, 8]);
+    /// ```
+    pub fn shape(&self) -> impl Iterator<Item = usize> + '_ {
+        self.plans.iter().map(Plan::fft_size)
+    }
+
+    /// Returns the SIMD instruction set that's internally used by the FFT.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::{FftAlgo, Method, PlanNd},
+    ///     SimdLevel,
+    /// };
+    ///
+    /// let plan = PlanNd::new(&[2, 4, 8], Method::UserProvided(FftAlgo::Dif4));
+    /// assert_eq!(plan.simd_level(), SimdLevel::detect());
+    /// ```
+    pub fn simd_level(&self) -> SimdLevel {
+        self.simd
+    }
+
+    /// Returns the total number of values of the arrays transformed by the plan.
+    pub fn fft_size(&self) -> usize {
+        self.shape().product()
+    }
+
+    /// Returns the size and alignment of the scratch memory needed to perform an FFT.
+    pub fn fft_scratch(&self) -> Result<StackReq, SizeOverflow> {
+        let mut req = StackReq::empty();
+        let mut inner = 1usize;
+        for plan in self.plans.iter().rev() {
+            req = req.try_or(process_axis_scratch(plan, inner)?)?;
+            inner = inner.checked_mul(plan.fft_size()).ok_or(SizeOverflow)?;
+        }
+        Ok(req)
+    }
+
+    /// Performs a forward multi-dimensional FFT in place, using the provided stack as scratch
+    /// space.
+    ///
+    /// # Note
+    ///
+    /// The values in `buf` are stored in row-major order. The forward transform is computed along
+    /// every dimension, and its terms are in standard order.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `buf` is not equal to the product of the dimensions.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::ordered::{Method, PlanNd};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer};
+    /// use core::time::Duration;
+    ///
+    /// let plan = PlanNd::new(&[2, 4, 8], Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [c64::new(1.0, 0.0); 2 * 4 * 8];
+    /// plan.fwd(&mut buf, stack);
+    /// assert_eq!(buf[0], c64::new(64.0, 0.0));
+    /// ```
+    pub fn fwd(&self, buf: &mut [c64], stack: PodStack) {
+        self.process(true, buf, stack);
+    }
+
+    /// Performs an inverse multi-dimensional FFT in place, using the provided stack as scratch
+    /// space.
+    ///
+    /// # Note
+    ///
+    /// The values in `buf` are stored in row-major order. Like [`Plan::inv`], the inverse
+    /// transform is not normalized, so applying the forward transform followed by the inverse
+    /// transform multiplies the values by the product of the dimensions.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `buf` is not equal to the product of the dimensions.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::ordered::{Method, PlanNd};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer, ReborrowMut};
+    /// use core::time::Duration;
+    ///
+    /// let plan = PlanNd::new(&[2, 4, 8], Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let mut stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [c64::default(); 2 * 4 * 8];
+    /// plan.fwd(&mut buf, stack.rb_mut());
+    /// plan.inv(&mut buf, stack);
+    /// ```
+    pub fn inv(&self, buf: &mut [c64], stack: PodStack) {
+        self.process(false, buf, stack);
+    }
+
+    fn process(&self, fwd: bool, buf: &mut [c64], mut stack: PodStack) {
+        assert_eq!(buf.len(), self.fft_size());
+
+        let mut inner = 1;
+        for plan in self.plans.iter().rev() {
+            process_axis(plan, fwd, buf, inner, self.transpose, stack.rb_mut());
+            inner *= plan.fft_size();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FftAlgo, Method, Plan, Plan2d, Plan32, PlanNd, RealPlan, SplitPlan};
+    use crate::{
+        c32, c64, dif16, dif2, dif4, dif8, dit16, dit2, dit4, dit8,
+        fft_simd::{init_wt, FftSimd, Pod},
+        mixed_radix, PlanError, SimdLevel,
+    };
+    use dyn_stack::{GlobalPodBuffer, PodStack, ReborrowMut};
+    use num_complex::ComplexFloat;
+    use rand::random;
+    use rustfft::FftPlanner;
 
     extern crate alloc;
     use alloc::vec;
 
-    fn test_fft_simd<c64xN: Pod>(simd: impl FftSimd<c64xN>) {
+    fn test_fft_simd<c64xN: Pod<Lane = c64>>(simd: impl FftSimd<c64xN>) {
         for (r, fft) in [
             (2, dif2::fft_impl(simd)),
             (2, dit2::fft_impl(simd)),
@@ -412,8 +2691,8 @@ mod tests {
                 fn test_inner(
                     n: usize,
                     r: usize,
-                    fwd: fn(&mut [c64], &mut [c64], &[c64], &[c64]),
-                    inv: fn(&mut [c64], &mut [c64], &[c64], &[c64]),
+                    fwd: fn(Option<&[c64]>, &mut [c64], &mut [c64], &[c64], &[c64]),
+                    inv: fn(Option<&[c64]>, &mut [c64], &mut [c64], &[c64], &[c64]),
                 ) {
                     let mut scratch = vec![c64::default(); n];
                     let mut twiddles = vec![c64::default(); 2 * n];
@@ -424,7 +2703,19 @@ mod tests {
                         *z = c64::new(random(), random());
                     }
                     let orig = x.clone();
-                    fwd(&mut x, &mut scratch, &twiddles[..n], &twiddles[n..]);
+                    fwd(None, &mut x, &mut scratch, &twiddles[..n], &twiddles[n..]);
+                    // the out-of-place kernel gives the same result, and only reads its input
+                    {
+                        let mut y = vec![c64::default(); n];
+                        fwd(
+                            Some(&orig),
+                            &mut y,
+                            &mut scratch,
+                            &twiddles[..n],
+                            &twiddles[n..],
+                        );
+                        assert_eq!(x, y);
+                    }
                     // compare with rustfft
                     {
                         let mut planner = FftPlanner::new();
@@ -436,7 +2727,13 @@ mod tests {
                             assert!((*z_expected - *z_actual).abs() < 1e-12);
                         }
                     }
-                    inv(&mut x, &mut scratch, &twiddles_inv[..n], &twiddles_inv[n..]);
+                    inv(
+                        None,
+                        &mut x,
+                        &mut scratch,
+                        &twiddles_inv[..n],
+                        &twiddles_inv[n..],
+                    );
                     for z in &mut x {
                         *z /= n as f64;
                     }
@@ -450,18 +2747,733 @@ mod tests {
         }
     }
 
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_fft_real() {
+        for exp in 0..=12 {
+            let n: usize = 1 << exp;
+            for algo in [FftAlgo::Dif2, FftAlgo::Dit4, FftAlgo::Dif8, FftAlgo::Dit16] {
+                let complex = Plan::new(n, Method::UserProvided(algo));
+                let plan = RealPlan::new(&complex);
+                let mut mem = GlobalPodBuffer::new(
+                    plan.fft_scratch()
+                        .unwrap()
+                        .or(complex.fft_real_scratch().unwrap()),
+                );
+                let mut stack = PodStack::new(&mut mem);
+
+                let mut x = vec![0.0; n];
+                for x in &mut x {
+                    *x = random();
+                }
+                let orig = x.clone();
+
+                let mut out = vec![c64::default(); n / 2 + 1];
+                plan.fwd(&x, &mut out, stack.rb_mut());
+                // compare with rustfft
+                {
+                    let mut planner = FftPlanner::new();
+                    let plan = planner.plan_fft_forward(n);
+                    let mut y: vec::Vec<c64> = orig.iter().map(|&re| c64::new(re, 0.0)).collect();
+                    plan.process(&mut y);
+
+                    for (z_expected, z_actual) in y.iter().zip(&out) {
+                        assert!((*z_expected - *z_actual).abs() < 1e-12);
+                    }
+                }
+
+                // real FFTs performed directly with the complex plan
+                let mut out_complex = vec![c64::default(); n / 2 + 1];
+                complex.fwd_real(&x, &mut out_complex, stack.rb_mut());
+                for (z_expected, z_actual) in out.iter().zip(&out_complex) {
+                    assert!((*z_expected - *z_actual).abs() < 1e-12);
+                }
+
+                let mut y = vec![0.0; n];
+                complex.inv_real(&out, &mut y, stack.rb_mut());
+
+                plan.inv(&out, &mut x, stack);
+                for (expected, actual, actual_complex) in izip!(&orig, &x, &y) {
+                    assert!((expected - actual / n as f64).abs() < 1e-14);
+                    assert!((expected - actual_complex / n as f64).abs() < 1e-14);
+                }
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_fft_large() {
+        for exp in 11..=20 {
+            let n: usize = 1 << exp;
+            let plan = Plan::new(n, Method::UserProvided(FftAlgo::Dif4));
+            let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+            let mut stack = PodStack::new(&mut mem);
+
+            let mut x = vec![c64::default(); n];
+            for z in &mut x {
+                *z = c64::new(random(), random());
+            }
+            let orig = x.clone();
+
+            plan.fwd(&mut x, stack.rb_mut());
+            // compare with rustfft
+            {
+                let mut planner = FftPlanner::new();
+                let plan = planner.plan_fft_forward(n);
+                let mut y = orig.clone();
+                plan.process(&mut y);
+
+                for (z_expected, z_actual) in y.iter().zip(&x) {
+                    assert!((*z_expected - *z_actual).abs() < 1e-9);
+                }
+            }
+
+            plan.inv(&mut x, stack);
+            for (z_expected, z_actual) in orig.iter().zip(&x) {
+                assert!((*z_expected - *z_actual / n as f64).abs() < 1e-14);
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_algo_kernel_size() {
+        use super::algo_kernel_size;
+
+        assert_eq!(algo_kernel_size(1), None);
+        assert_eq!(algo_kernel_size(9), None);
+        assert_eq!(algo_kernel_size(3 << 8), None);
+        assert_eq!(algo_kernel_size(1 << 8), Some(1 << 8));
+        assert_eq!(algo_kernel_size(1 << 14), Some(1 << 7));
+        assert_eq!(algo_kernel_size(3 << 12), Some(1 << 7));
+        assert_eq!(algo_kernel_size(7), Some(16));
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_fft_mixed_radix() {
+        for level in [SimdLevel::detect(), SimdLevel::Scalar] {
+            let [fwd, inv] = mixed_radix::fft_impl_dispatch(level);
+            for n in [
+                3, 5, 6, 9, 10, 12, 15, 20, 24, 25, 45, 60, 96, 120, 375, 720, 960,
+            ] {
+                let mut scratch = vec![c64::default(); n];
+                let mut twiddles = vec![c64::default(); 2 * n];
+                let mut twiddles_inv = vec![c64::default(); 2 * n];
+                mixed_radix::init_wt(n, &mut twiddles, &mut twiddles_inv);
+                let mut x = vec![c64::default(); n];
+                for z in &mut x {
+                    *z = c64::new(random(), random());
+                }
+                let orig = x.clone();
+                fwd(None, &mut x, &mut scratch, &twiddles[..n], &twiddles[n..]);
+                // the out-of-place kernel gives the same result, and only reads its input
+                {
+                    let mut y = vec![c64::default(); n];
+                    fwd(
+                        Some(&orig),
+                        &mut y,
+                        &mut scratch,
+                        &twiddles[..n],
+                        &twiddles[n..],
+                    );
+                    assert_eq!(x, y);
+                }
+                // compare with rustfft
+                {
+                    let mut planner = FftPlanner::new();
+                    let plan = planner.plan_fft_forward(n);
+                    let mut y = orig.clone();
+                    plan.process(&mut y);
+
+                    for (z_expected, z_actual) in y.iter().zip(&x) {
+                        assert!((*z_expected - *z_actual).abs() < 1e-12 * n as f64);
+                    }
+                }
+                inv(
+                    None,
+                    &mut x,
+                    &mut scratch,
+                    &twiddles_inv[..n],
+                    &twiddles_inv[n..],
+                );
+                for (z_expected, z_actual) in orig.iter().zip(&x) {
+                    assert!((*z_expected - *z_actual / n as f64).abs() < 1e-14 * n as f64);
+                }
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_fft_mixed_radix_c32() {
+        for level in [SimdLevel::detect(), SimdLevel::Scalar] {
+            let [fwd, inv] = mixed_radix::fft_impl_dispatch_c32(level);
+            for n in [3, 5, 6, 12, 24, 45, 96, 120, 960] {
+                let mut scratch = vec![c32::default(); n];
+                let mut twiddles = vec![c32::default(); 2 * n];
+                let mut twiddles_inv = vec![c32::default(); 2 * n];
+                mixed_radix::init_wt(n, &mut twiddles, &mut twiddles_inv);
+                let mut x = vec![c32::default(); n];
+                for z in &mut x {
+                    *z = c32::new(random(), random());
+                }
+                let orig = x.clone();
+                fwd(None, &mut x, &mut scratch, &twiddles[..n], &twiddles[n..]);
+                {
+                    let mut planner = FftPlanner::new();
+                    let plan = planner.plan_fft_forward(n);
+                    let mut y = orig.clone();
+                    plan.process(&mut y);
+
+                    for (z_expected, z_actual) in y.iter().zip(&x) {
+                        assert!((*z_expected - *z_actual).abs() < 1e-5 * n as f32);
+                    }
+                }
+                inv(
+                    None,
+                    &mut x,
+                    &mut scratch,
+                    &twiddles_inv[..n],
+                    &twiddles_inv[n..],
+                );
+                for (z_expected, z_actual) in orig.iter().zip(&x) {
+                    assert!((*z_expected - *z_actual / n as f32).abs() < 1e-5);
+                }
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_fft_non_power_of_two() {
+        for n in [
+            3,
+            5,
+            6,
+            7,
+            11,
+            12,
+            15,
+            45,
+            97,
+            1009,
+            1536,
+            3 * 5 * 7 * 11,
+            5usize.pow(5),
+            3 << 12,
+            5 << 14,
+        ] {
+            for algo in [FftAlgo::Dif2, FftAlgo::Dit4, FftAlgo::Dif8, FftAlgo::Dit16] {
+                let plan = Plan::new(n, Method::UserProvided(algo));
+                assert_eq!(plan.fft_size(), n);
+                let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+                let mut stack = PodStack::new(&mut mem);
+
+                let mut x = vec![c64::default(); n];
+                for z in &mut x {
+                    *z = c64::new(random(), random());
+                }
+                let orig = x.clone();
+
+                plan.fwd(&mut x, stack.rb_mut());
+                // compare with rustfft
+                {
+                    let mut planner = FftPlanner::new();
+                    let plan = planner.plan_fft_forward(n);
+                    let mut y = orig.clone();
+                    plan.process(&mut y);
+
+                    for (z_expected, z_actual) in y.iter().zip(&x) {
+                        assert!((*z_expected - *z_actual).abs() < 1e-9);
+                    }
+                }
+
+                let mut y = vec![c64::default(); n];
+                plan.fwd_out_of_place(&orig, &mut y, stack.rb_mut());
+                assert_eq!(x, y);
+
+                plan.inv(&mut x, stack.rb_mut());
+                for (z_expected, z_actual) in orig.iter().zip(&x) {
+                    assert!((*z_expected - *z_actual / n as f64).abs() < 1e-12);
+                }
+
+                // real transforms, through the half size FFT for even sizes, and the full size
+                // FFT for odd sizes
+                let real = RealPlan::new(&plan);
+                let mut mem = GlobalPodBuffer::new(real.fft_scratch().unwrap());
+                let mut stack = PodStack::new(&mut mem);
+
+                let x: vec::Vec<f64> = orig.iter().map(|z| z.re).collect();
+                let mut out = vec![c64::default(); n / 2 + 1];
+                real.fwd(&x, &mut out, stack.rb_mut());
+                {
+                    let mut planner = FftPlanner::new();
+                    let plan = planner.plan_fft_forward(n);
+                    let mut y: vec::Vec<c64> = x.iter().map(|&re| c64::new(re, 0.0)).collect();
+                    plan.process(&mut y);
+
+                    for (z_expected, z_actual) in y.iter().zip(&out) {
+                        assert!((*z_expected - *z_actual).abs() < 1e-9);
+                    }
+                }
+
+                let mut y = vec![0.0; n];
+                real.inv(&out, &mut y, stack);
+                for (expected, actual) in x.iter().zip(&y) {
+                    assert!((expected - actual / n as f64).abs() < 1e-12);
+                }
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_fft_out_of_place() {
+        for algo in [FftAlgo::Dit8, FftAlgo::Dif4, FftAlgo::Dif16] {
+            // power-of-two, mixed-radix, four-step and Bluestein sizes
+            for n in [1, 4, 6, 256, 375, 1 << 12, 3 << 11, 1 << 15, 7] {
+                let plan = Plan::new(n, Method::UserProvided(algo));
+                // the out-of-place transforms need no more scratch space than the in-place ones,
+                // so the stack is sized exactly for the latter
+                let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+                let mut stack = PodStack::new(&mut mem);
+
+                let mut x = vec![c64::default(); n];
+                for z in &mut x {
+                    *z = c64::new(random(), random());
+                }
+                let orig = x.clone();
+                let input = x.clone();
+                let mut y = vec![c64::default(); n];
+
+                plan.fwd(&mut x, stack.rb_mut());
+                plan.fwd_out_of_place(&input, &mut y, stack.rb_mut());
+                assert_eq!(x, y);
+                assert_eq!(input, orig);
+
+                let transformed = x.clone();
+                plan.inv(&mut x, stack.rb_mut());
+                plan.inv_out_of_place(&transformed, &mut y, stack.rb_mut());
+                assert_eq!(x, y);
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_fft_split() {
+        use FftAlgo::*;
+        for simd in [
+            SimdLevel::Scalar,
+            SimdLevel::Avx2,
+            SimdLevel::Avx512,
+            SimdLevel::Neon,
+        ] {
+            for algo in [Dif2, Dit2, Dif4, Dit4, Dif8, Dit8, Dif16, Dit16] {
+                for n in [1, 2, 4, 8, 16, 32, 64, 128, 256, 512, 1 << 12, 1 << 13] {
+                    let Ok(plan) = Plan::try_new_with_simd(n, Method::UserProvided(algo), simd)
+                    else {
+                        continue;
+                    };
+                    let split = SplitPlan::new(&plan);
+                    let mut mem = GlobalPodBuffer::new(
+                        plan.fft_scratch()
+                            .unwrap()
+                            .try_or(split.fft_scratch().unwrap())
+                            .unwrap()
+                            .try_or(plan.fft_split_scratch().unwrap())
+                            .unwrap(),
+                    );
+                    let mut stack = PodStack::new(&mut mem);
+
+                    // the inputs are bounded by 1, so the outputs of both transforms are bounded
+                    // by `n`
+                    for fwd in [true, false] {
+                        let mut x = vec![c64::default(); n];
+                        for z in &mut x {
+                            *z = c64::new(random(), random());
+                        }
+                        let mut re: vec::Vec<f64> = x.iter().map(|z| z.re).collect();
+                        let mut im: vec::Vec<f64> = x.iter().map(|z| z.im).collect();
+                        let mut plan_re = re.clone();
+                        let mut plan_im = im.clone();
+
+                        if fwd {
+                            plan.fwd(&mut x, stack.rb_mut());
+                            split.fwd(&mut re, &mut im, stack.rb_mut());
+                            plan.fwd_split(&mut plan_re, &mut plan_im, stack.rb_mut());
+                        } else {
+                            plan.inv(&mut x, stack.rb_mut());
+                            split.inv(&mut re, &mut im, stack.rb_mut());
+                            plan.inv_split(&mut plan_re, &mut plan_im, stack.rb_mut());
+                        }
+                        for (z, re, im, plan_re, plan_im) in izip!(&x, &re, &im, &plan_re, &plan_im)
+                        {
+                            assert!((*z - c64::new(*re, *im)).abs() < 1e-12 * n as f64);
+                            assert!((*z - c64::new(*plan_re, *plan_im)).abs() < 1e-12 * n as f64);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fft_split_non_power_of_two() {
+        let plan = Plan::new(6, Method::UserProvided(FftAlgo::Dif4));
+        assert_eq!(
+            SplitPlan::try_new(&plan).unwrap_err(),
+            PlanError::NotPowerOfTwo { size: 6 },
+        );
+    }
+
+    fn test_fft_simd_c32<c32xN: Pod<Lane = c32>>(simd: impl FftSimd<c32xN>) {
+        for (r, fft) in [
+            (2, dif2::fft_impl(simd)),
+            (2, dit2::fft_impl(simd)),
+            (4, dif4::fft_impl(simd)),
+            (4, dit4::fft_impl(simd)),
+            (8, dif8::fft_impl(simd)),
+            (8, dit8::fft_impl(simd)),
+            (16, dif16::fft_impl(simd)),
+            (16, dit16::fft_impl(simd)),
+        ] {
+            // the radix-2 kernels support at most 2 lanes, and the radix-4 ones up to 8 lanes
+            if simd.lane_count() > r && (r == 2 || simd.lane_count() > 8) {
+                continue;
+            }
+
+            for exp in 1..=10 {
+                let n: usize = 1 << exp;
+                if simd.lane_count() > 1 && simd.lane_count() * r > n {
+                    continue;
+                }
+
+                let [fwd, inv] = fft.make_fn_ptr(n);
+
+                let mut scratch = vec![c32::default(); n];
+                let mut twiddles = vec![c32::default(); 2 * n];
+                let mut twiddles_inv = vec![c32::default(); 2 * n];
+                init_wt(r, n, &mut twiddles, &mut twiddles_inv);
+                let mut x = vec![c32::default(); n];
+                for z in &mut x {
+                    *z = c32::new(random(), random());
+                }
+                let orig = x.clone();
+                fwd(None, &mut x, &mut scratch, &twiddles[..n], &twiddles[n..]);
+                // the out-of-place kernel gives the same result, and only reads its input
+                {
+                    let mut y = vec![c32::default(); n];
+                    fwd(
+                        Some(&orig),
+                        &mut y,
+                        &mut scratch,
+                        &twiddles[..n],
+                        &twiddles[n..],
+                    );
+                    assert_eq!(x, y);
+                }
+                // compare with rustfft
+                {
+                    let mut planner = FftPlanner::new();
+                    let plan = planner.plan_fft_forward(n);
+                    let mut y = orig.clone();
+                    plan.process(&mut y);
+
+                    for (z_expected, z_actual) in y.iter().zip(&x) {
+                        assert!((*z_expected - *z_actual).abs() < 1e-5 * n as f32);
+                    }
+                }
+                inv(
+                    None,
+                    &mut x,
+                    &mut scratch,
+                    &twiddles_inv[..n],
+                    &twiddles_inv[n..],
+                );
+                for z in &mut x {
+                    *z /= n as f32;
+                }
+                for (z_expected, z_actual) in orig.iter().zip(&x) {
+                    assert!((*z_expected - *z_actual).abs() < 1e-5);
+                }
+            }
+        }
+    }
+
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
     #[test]
     fn test_fft() {
-        test_fft_simd(crate::fft_simd::Scalar);
+        test_fft_simd::<c64>(crate::fft_simd::Scalar);
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        {
+            if let Some(simd) = pulp::x86::V3::try_new() {
+                test_fft_simd::<crate::fft_simd::c64x2>(simd);
+            }
+            #[cfg(feature = "avx512")]
+            if let Some(simd) = pulp::x86::V4::try_new() {
+                test_fft_simd::<crate::fft_simd::c64x4>(simd);
+            }
+        }
+        #[cfg(target_arch = "aarch64")]
+        {
+            if let Some(simd) = pulp::aarch64::Neon::try_new() {
+                test_fft_simd::<crate::fft_simd::c64x1>(simd);
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_fft_c32() {
+        test_fft_simd_c32::<c32>(crate::fft_simd::Scalar);
         #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
         {
             if let Some(simd) = pulp::x86::V3::try_new() {
-                test_fft_simd(simd);
+                test_fft_simd_c32::<crate::fft_simd::c32x4>(simd);
             }
-            #[cfg(feature = "nightly")]
+            #[cfg(feature = "avx512")]
             if let Some(simd) = pulp::x86::V4::try_new() {
-                test_fft_simd(simd);
+                test_fft_simd_c32::<crate::fft_simd::c32x8>(simd);
+            }
+        }
+        #[cfg(target_arch = "aarch64")]
+        {
+            if let Some(simd) = pulp::aarch64::Neon::try_new() {
+                test_fft_simd_c32::<crate::fft_simd::c32x2>(simd);
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_plan_c32() {
+        for exp in 0..=14 {
+            let n: usize = 1 << exp;
+            for algo in [FftAlgo::Dif2, FftAlgo::Dit4, FftAlgo::Dif8, FftAlgo::Dit16] {
+                let plan = Plan32::new(n, Method::UserProvided(algo));
+                let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+                let mut stack = PodStack::new(&mut mem);
+
+                let mut x = vec![c32::default(); n];
+                for z in &mut x {
+                    *z = c32::new(random(), random());
+                }
+                let orig = x.clone();
+
+                plan.fwd(&mut x, stack.rb_mut());
+                // compare with rustfft
+                {
+                    let mut planner = FftPlanner::new();
+                    let plan = planner.plan_fft_forward(n);
+                    let mut y = orig.clone();
+                    plan.process(&mut y);
+
+                    for (z_expected, z_actual) in y.iter().zip(&x) {
+                        assert!((*z_expected - *z_actual).abs() < 1e-5 * n as f32);
+                    }
+                }
+
+                plan.inv(&mut x, stack);
+                for (z_expected, z_actual) in orig.iter().zip(&x) {
+                    assert!((*z_expected - *z_actual / n as f32).abs() < 1e-5);
+                }
+            }
+        }
+    }
+
+    // applies a one-dimensional FFT along each axis of the row-major array `x`, gathering the
+    // strided values of each vector
+    fn fft_nd_naive(x: &mut [c64], shape: &[usize], fwd: bool) {
+        let mut inner = 1;
+        for &n in shape.iter().rev() {
+            let plan = Plan::new(n, Method::UserProvided(FftAlgo::Dif2));
+            let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+            let mut stack = PodStack::new(&mut mem);
+
+            let mut tmp = vec![c64::default(); n];
+            for block in x.chunks_exact_mut(n * inner) {
+                for j in 0..inner {
+                    for (i, z) in tmp.iter_mut().enumerate() {
+                        *z = block[i * inner + j];
+                    }
+                    if fwd {
+                        plan.fwd(&mut tmp, stack.rb_mut());
+                    } else {
+                        plan.inv(&mut tmp, stack.rb_mut());
+                    }
+                    for (i, z) in tmp.iter().enumerate() {
+                        block[i * inner + j] = *z;
+                    }
+                }
+            }
+            inner *= n;
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_fft_2d() {
+        for (rows, cols) in [
+            (1, 1),
+            (1, 8),
+            (8, 1),
+            (4, 8),
+            (32, 16),
+            (64, 64),
+            (2, 256),
+            (6, 10),
+            (7, 12),
+        ] {
+            let plan = Plan2d::new(rows, cols, Method::UserProvided(FftAlgo::Dif4));
+            assert_eq!(plan.shape(), (rows, cols));
+            let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+            let mut stack = PodStack::new(&mut mem);
+
+            let mut x = vec![c64::default(); rows * cols];
+            for z in &mut x {
+                *z = c64::new(random(), random());
+            }
+            let orig = x.clone();
+
+            let mut expected = x.clone();
+            fft_nd_naive(&mut expected, &[rows, cols], true);
+            plan.fwd(&mut x, stack.rb_mut());
+            for (actual, expected) in x.iter().zip(&expected) {
+                assert!((actual - expected).abs() < 1e-9);
+            }
+
+            plan.inv(&mut x, stack.rb_mut());
+            for (actual, expected) in x.iter().zip(&orig) {
+                assert!((actual / (rows * cols) as f64 - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_fft_nd() {
+        for shape in [
+            &[][..],
+            &[16],
+            &[4, 8],
+            &[2, 4, 8],
+            &[8, 8, 8],
+            &[4, 1, 16, 2],
+        ] {
+            let plan = PlanNd::new(shape, Method::UserProvided(FftAlgo::Dit4));
+            assert!(plan.shape().eq(shape.iter().copied()));
+            let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+            let mut stack = PodStack::new(&mut mem);
+
+            let size = shape.iter().product::<usize>();
+            let mut x = vec![c64::default(); size];
+            for z in &mut x {
+                *z = c64::new(random(), random());
+            }
+            let orig = x.clone();
+
+            let mut expected = x.clone();
+            fft_nd_naive(&mut expected, shape, true);
+            plan.fwd(&mut x, stack.rb_mut());
+            for (actual, expected) in x.iter().zip(&expected) {
+                assert!((actual - expected).abs() < 1e-9);
+            }
+
+            plan.inv(&mut x, stack.rb_mut());
+            for (actual, expected) in x.iter().zip(&orig) {
+                assert!((actual / size as f64 - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_plan_serde() {
+        for n in [1, 4, 64, 4096] {
+            for algo in [FftAlgo::Dif2, FftAlgo::Dit4, FftAlgo::Dif8, FftAlgo::Dit16] {
+                let plan = Plan::new(n, Method::UserProvided(algo));
+                let json = serde_json::to_string(&plan).unwrap();
+                let plan2: Plan = serde_json::from_str(&json).unwrap();
+                assert_eq!(plan2.fft_size(), n);
+                assert_eq!(plan2.algo(), algo);
+
+                let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+                let mut stack = PodStack::new(&mut mem);
+
+                let mut x = vec![c64::default(); n];
+                for z in &mut x {
+                    *z = c64::new(random(), random());
+                }
+                let mut y = x.clone();
+                plan.fwd(&mut x, stack.rb_mut());
+                plan2.fwd(&mut y, stack.rb_mut());
+                assert_eq!(x, y);
+            }
+        }
+
+        assert!(serde_json::from_str::<Plan>(r#"{"n":0,"algo":"Dif2"}"#).is_err());
+        assert!(serde_json::from_str::<Plan>(r#"{"n":8,"algo":"Dif3"}"#).is_err());
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_simd_level() {
+        let levels = [
+            SimdLevel::Scalar,
+            SimdLevel::Avx2,
+            SimdLevel::Avx512,
+            SimdLevel::Neon,
+        ];
+        for n in [1, 4, 64, 4096] {
+            let method = Method::UserProvided(FftAlgo::Dif4);
+            let scalar = Plan::new_with_simd(n, method, SimdLevel::Scalar);
+            let mut mem = GlobalPodBuffer::new(scalar.fft_scratch().unwrap());
+            let mut stack = PodStack::new(&mut mem);
+
+            let mut x = vec![c64::default(); n];
+            for z in &mut x {
+                *z = c64::new(random(), random());
+            }
+            let mut expected = x.clone();
+            scalar.fwd(&mut expected, stack.rb_mut());
+
+            for simd in levels {
+                match Plan::try_new_with_simd(n, method, simd) {
+                    Ok(plan) => {
+                        assert!(simd.is_available());
+                        assert_eq!(plan.simd_level(), simd);
+                        let mut y = x.clone();
+                        plan.fwd(&mut y, stack.rb_mut());
+                        for (y, expected) in y.iter().zip(&expected) {
+                            assert!((y - expected).norm() < 1e-10 * n as f64);
+                        }
+                    }
+                    Err(err) => {
+                        assert!(!simd.is_available());
+                        assert_eq!(err, PlanError::SimdUnavailable { simd });
+                    }
+                }
+            }
+        }
+
+        // the multi-dimensional and single precision plans use the requested level as well
+        let method = Method::UserProvided(FftAlgo::Dif4);
+        for simd in levels {
+            match Plan2d::try_new_with_simd(8, 16, method, simd) {
+                Ok(plan) => assert_eq!(plan.simd_level(), simd),
+                Err(err) => assert_eq!(err, PlanError::SimdUnavailable { simd }),
+            }
+            match PlanNd::try_new_with_simd(&[4, 8, 16], method, simd) {
+                Ok(plan) => assert_eq!(plan.simd_level(), simd),
+                Err(err) => assert_eq!(err, PlanError::SimdUnavailable { simd }),
+            }
+            match Plan32::try_new_with_simd(64, method, simd) {
+                Ok(plan) => assert_eq!(plan.simd_level(), simd),
+                Err(err) => assert_eq!(err, PlanError::SimdUnavailable { simd }),
             }
         }
     }
@@ -0,0 +1,828 @@
+//! Polynomial multiplication module.
+//!
+//! This module computes the cyclic product of two polynomials of size $n$, i.e. their product
+//! modulo $X^n - 1$, or their negacyclic product, i.e. their product modulo $X^n + 1$.
+//!
+//! Polynomials with `f64` coefficients are multiplied in the Fourier domain, and so the result is
+//! only approximate. The negacyclic product is computed with a [`negacyclic::Plan`] of size $n$,
+//! and the cyclic product with an [`unordered::Plan`] of size $n$, whose inputs are the real
+//! coefficients of the polynomials.
+//!
+//! With the `fft128` feature, the products of polynomials with `u64` coefficients are computed
+//! from their full product, which has degree at most $2n - 2$. The inputs are zero-padded to size
+//! $2n$, so that the negacyclic FFT of size $2n$ computes the full product without any wraparound.
+//! The coefficients $c_j$ of the full product are then folded into $c_j + c_{j + n}$ for the
+//! cyclic product, and $c_j - c_{j + n}$ for the negacyclic product.
+//!
+//! The coefficients are split into signed 32-bit digits, so that the coefficients of the products
+//! of the digits are less than $n \cdot 2^{63}$ in magnitude. The 128-bit FFT computes them with a
+//! relative error of the order of $\log_2(n) \cdot 2^{-104}$, so the absolute error stays far
+//! below $1/2$ for all practical sizes, e.g. it is less than $2^{-6}$ for $n \le 2^{30}$. Rounding
+//! the result of the inverse transform to the nearest integers then recovers the exact products,
+//! and so the result is exact modulo $2^{64}$.
+//!
+//! The 128-bit FFT requires a polynomial size of at least $32$, so smaller polynomials with `u64`
+//! coefficients are multiplied directly with the schoolbook algorithm instead.
+//!
+//! The free functions [`cyclic`](fn@cyclic) and [`negacyclic`](fn@negacyclic) compute a single
+//! product without requiring a plan or scratch memory from the caller. They allocate their scratch
+//! memory for each call, and reuse the plan of the previous call on the same thread when the
+//! standard library is available, so [`Plan`] should be preferred when computing many products.
+
+use crate::{c64, check_power_of_two, negacyclic, ordered::FftAlgo, unordered, PlanError};
+#[cfg(feature = "fft128")]
+use crate::{dif4::split_mut_4, fft128, fft128::f128};
+use aligned_vec::CACHELINE_ALIGN;
+use dyn_stack::{GlobalPodBuffer, PodStack, ReborrowMut, SizeOverflow, StackReq};
+
+pub use crate::unordered::Method;
+
+/// Polynomial multiplication plan.
+///
+/// This type holds a negacyclic FFT plan of size `n` and an unordered FFT plan of size `n`, which
+/// are used to compute the negacyclic and cyclic products of polynomials of size `n`. The size
+/// must be a power of two, and at least `4`.
+#[derive(Clone)]
+pub struct Plan {
+    plan: negacyclic::Plan,
+    cyclic_plan: unordered::Plan,
+    #[cfg(feature = "fft128")]
+    plan128: Option<fft128::Plan>,
+}
+
+impl core::fmt::Debug for Plan {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Plan")
+            .field("polynomial_size", &self.polynomial_size())
+            .field("plan", &self.plan)
+            .field("cyclic_plan", &self.cyclic_plan)
+            .finish()
+    }
+}
+
+impl Plan {
+    /// Returns a new polynomial multiplication plan for polynomials of size `n`. The internal
+    /// unordered FFT plan of size `n` is selected by the provided method, and so is the one of
+    /// size `n / 2` of the negacyclic plan, with a base size of at most `n / 2`.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `n` is not a power of two, or if it is less than `4`.
+    /// - Panics if the unordered FFT plans of size `n` and `n / 2` can't be created with the
+    /// provided method. See [`unordered::Plan::new`].
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::polymul::{Method, Plan};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(8, Method::Measure(Duration::from_millis(10)));
+    /// ```
+    #[track_caller]
+    pub fn new(n: usize, method: Method) -> Self {
+        match Self::try_new(n, method) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Returns a new polynomial multiplication plan for polynomials of size `n`, or an error if
+    /// the plan is not supported. See [`Plan::new`] for the requirements on the sizes.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::polymul::{Method, Plan};
+    /// use concrete_fft::PlanError;
+    /// use core::time::Duration;
+    ///
+    /// let method = Method::Measure(Duration::from_millis(10));
+    /// assert!(Plan::try_new(8, method).is_ok());
+    /// assert_eq!(
+    ///     Plan::try_new(2, method).unwrap_err(),
+    ///     PlanError::TooSmall { size: 2, min: 4 },
+    /// );
+    /// ```
+    pub fn try_new(n: usize, method: Method) -> Result<Self, PlanError> {
+        check_power_of_two(n)?;
+        if n < 4 {
+            return Err(PlanError::TooSmall { size: n, min: 4 });
+        }
+
+        // the negacyclic plan holds an unordered plan of size `n / 2`
+        let half_method = match method {
+            Method::UserProvided { base_algo, base_n } => Method::UserProvided {
+                base_algo,
+                base_n: base_n.min(n / 2),
+            },
+            method => method,
+        };
+
+        Ok(Self {
+            plan: negacyclic::Plan::try_new(n, half_method)?,
+            cyclic_plan: unordered::Plan::try_new(n, method)?,
+            // small products are computed directly instead
+            #[cfg(feature = "fft128")]
+            plan128: fft128::Plan::try_new(n).ok(),
+        })
+    }
+
+    /// Returns the polynomial size of the plan.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::FftAlgo,
+    ///     polymul::{Method, Plan},
+    /// };
+    ///
+    /// let plan = Plan::new(
+    ///     8,
+    ///     Method::UserProvided {
+    ///         base_algo: FftAlgo::Dif2,
+    ///         base_n: 8,
+    ///     },
+    /// );
+    /// assert_eq!(plan.polynomial_size(), 8);
+    /// ```
+    pub fn polynomial_size(&self) -> usize {
+        self.plan.polynomial_size()
+    }
+
+    /// Returns the internal negacyclic FFT plan, which is used for the negacyclic products.
+    pub fn negacyclic_plan(&self) -> &negacyclic::Plan {
+        &self.plan
+    }
+
+    /// Returns the internal unordered FFT plan, which is used for the cyclic products.
+    pub fn cyclic_plan(&self) -> &unordered::Plan {
+        &self.cyclic_plan
+    }
+
+    /// Returns the size and alignment of the scratch memory needed to compute a product.
+    pub fn polymul_scratch(&self) -> Result<StackReq, SizeOverflow> {
+        let n = self.polynomial_size();
+        let negacyclic_req = StackReq::try_all_of([
+            StackReq::try_new_aligned::<f64>(n, CACHELINE_ALIGN)?, // padded polynomial
+            StackReq::try_new_aligned::<c64>(n / 2, CACHELINE_ALIGN)?, // lhs
+            StackReq::try_new_aligned::<c64>(n / 2, CACHELINE_ALIGN)?, // rhs
+            self.plan.fft_scratch()?,
+        ])?;
+        let cyclic_req = StackReq::try_all_of([
+            StackReq::try_new_aligned::<c64>(n, CACHELINE_ALIGN)?, // lhs
+            StackReq::try_new_aligned::<c64>(n, CACHELINE_ALIGN)?, // rhs
+            self.cyclic_plan.fft_scratch()?,
+        ])?;
+        let req = negacyclic_req.try_or(cyclic_req)?;
+
+        #[cfg(feature = "fft128")]
+        let req = req.try_or(StackReq::try_all_of([
+            StackReq::try_new_aligned::<u128>(2 * n, CACHELINE_ALIGN)?, // padded polynomial
+            StackReq::try_new_aligned::<f64>(4 * n, CACHELINE_ALIGN)?,  // lhs low digits
+            StackReq::try_new_aligned::<f64>(4 * n, CACHELINE_ALIGN)?,  // lhs high digits
+            StackReq::try_new_aligned::<f64>(4 * n, CACHELINE_ALIGN)?,  // rhs low digits
+            StackReq::try_new_aligned::<f64>(4 * n, CACHELINE_ALIGN)?,  // rhs high digits
+        ])?)?;
+
+        Ok(req)
+    }
+
+    /// Computes the product of `a` and `b` modulo $X^n - 1$, storing the result in `out`, and
+    /// using the provided stack as scratch space.
+    ///
+    /// `a` and `b` may be shorter than the polynomial size, in which case the missing coefficients
+    /// are taken to be zero.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `a` or `b` is greater than the polynomial size.
+    /// - Panics if the length of `out` is not equal to the polynomial size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::polymul::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.polymul_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// // (1 + X^3) * (1 + X) = 1 + X + X^3 + X^4 = 2 + X + X^3 modulo X^4 - 1
+    /// let mut out = [0.0; 4];
+    /// plan.cyclic(&[1.0, 0.0, 0.0, 1.0], &[1.0, 1.0], &mut out, stack);
+    ///
+    /// for (actual, expected) in out.iter().zip([2.0, 1.0, 0.0, 1.0]) {
+    ///     assert!((actual - expected).abs() < 1e-9);
+    /// }
+    /// ```
+    pub fn cyclic(&self, a: &[f64], b: &[f64], out: &mut [f64], stack: PodStack) {
+        let n = self.polynomial_size();
+        assert!(a.len() <= n);
+        assert!(b.len() <= n);
+        assert_eq!(out.len(), n);
+
+        let (fourier_a, stack) = stack.make_aligned_raw::<c64>(n, CACHELINE_ALIGN);
+        let (fourier_b, mut stack) = stack.make_aligned_raw::<c64>(n, CACHELINE_ALIGN);
+
+        pad_complex(fourier_a, a);
+        self.cyclic_plan.fwd(fourier_a, stack.rb_mut());
+        pad_complex(fourier_b, b);
+        self.cyclic_plan.fwd(fourier_b, stack.rb_mut());
+
+        self.cyclic_plan.pointwise_mul(fourier_a, fourier_b);
+        self.cyclic_plan.inv(fourier_a, stack);
+
+        // the product of real polynomials is real
+        let normalization = 1.0 / n as f64;
+        for (out, z) in izip!(out, &*fourier_a) {
+            *out = z.re * normalization;
+        }
+    }
+
+    /// Computes the product of `a` and `b` modulo $X^n + 1$, storing the result in `out`, and
+    /// using the provided stack as scratch space.
+    ///
+    /// `a` and `b` may be shorter than the polynomial size, in which case the missing coefficients
+    /// are taken to be zero.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `a` or `b` is greater than the polynomial size.
+    /// - Panics if the length of `out` is not equal to the polynomial size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::polymul::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.polymul_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// // (1 + X^3) * (1 + X) = 1 + X + X^3 + X^4 = X + X^3 modulo X^4 + 1
+    /// let mut out = [0.0; 4];
+    /// plan.negacyclic(&[1.0, 0.0, 0.0, 1.0], &[1.0, 1.0], &mut out, stack);
+    ///
+    /// for (actual, expected) in out.iter().zip([0.0, 1.0, 0.0, 1.0]) {
+    ///     assert!((actual - expected).abs() < 1e-9);
+    /// }
+    /// ```
+    pub fn negacyclic(&self, a: &[f64], b: &[f64], out: &mut [f64], stack: PodStack) {
+        let n = self.polynomial_size();
+        assert!(a.len() <= n);
+        assert!(b.len() <= n);
+        assert_eq!(out.len(), n);
+
+        let (poly, stack) = stack.make_aligned_raw::<f64>(n, CACHELINE_ALIGN);
+        let (fourier_a, stack) = stack.make_aligned_raw::<c64>(n / 2, CACHELINE_ALIGN);
+        let (fourier_b, mut stack) = stack.make_aligned_raw::<c64>(n / 2, CACHELINE_ALIGN);
+
+        pad(poly, a);
+        self.plan.fwd(fourier_a, poly, stack.rb_mut());
+        pad(poly, b);
+        self.plan.fwd(fourier_b, poly, stack.rb_mut());
+
+        self.plan
+            .unordered_plan()
+            .pointwise_mul(fourier_a, fourier_b);
+
+        // the inverse negacyclic transform is already normalized
+        self.plan.inv(out, fourier_a, stack);
+    }
+
+    /// Computes the product of `a` and `b` modulo $X^n - 1$ and modulo $2^{64}$, storing the
+    /// result in `out`, and using the provided stack as scratch space.
+    ///
+    /// `a` and `b` may be shorter than the polynomial size, in which case the missing coefficients
+    /// are taken to be zero.
+    ///
+    /// Unlike [`Plan::cyclic`], the result is exact. Signed coefficients can be multiplied by
+    /// casting them to `u64`, since the product modulo $2^{64}$ is the same.
+    ///
+    /// Polynomials of size less than `32` are multiplied with the schoolbook algorithm, since the
+    /// 128-bit FFT doesn't support them.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `a` or `b` is greater than the polynomial size.
+    /// - Panics if the length of `out` is not equal to the polynomial size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::polymul::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.polymul_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// let mut out = [0; 4];
+    /// plan.cyclic_u64(&[1, 0, 0, u64::MAX], &[1, 2], &mut out, stack);
+    /// assert_eq!(out, [u64::MAX, 2, 0, u64::MAX]);
+    /// ```
+    #[cfg(feature = "fft128")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fft128")))]
+    pub fn cyclic_u64(&self, a: &[u64], b: &[u64], out: &mut [u64], stack: PodStack) {
+        self.product_u64(a, b, out, false, stack);
+    }
+
+    /// Computes the product of `a` and `b` modulo $X^n + 1$ and modulo $2^{64}$, storing the
+    /// result in `out`, and using the provided stack as scratch space.
+    ///
+    /// `a` and `b` may be shorter than the polynomial size, in which case the missing coefficients
+    /// are taken to be zero.
+    ///
+    /// Unlike [`Plan::negacyclic`], the result is exact. Signed coefficients can be multiplied by
+    /// casting them to `u64`, since the product modulo $2^{64}$ is the same.
+    ///
+    /// Polynomials of size less than `32` are multiplied with the schoolbook algorithm, since the
+    /// 128-bit FFT doesn't support them.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `a` or `b` is greater than the polynomial size.
+    /// - Panics if the length of `out` is not equal to the polynomial size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::polymul::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.polymul_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// let mut out = [0; 4];
+    /// plan.negacyclic_u64(&[1, 0, 0, u64::MAX], &[1, 2], &mut out, stack);
+    /// assert_eq!(out, [3, 2, 0, u64::MAX]);
+    /// ```
+    #[cfg(feature = "fft128")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fft128")))]
+    pub fn negacyclic_u64(&self, a: &[u64], b: &[u64], out: &mut [u64], stack: PodStack) {
+        self.product_u64(a, b, out, true, stack);
+    }
+
+    #[cfg(feature = "fft128")]
+    fn product_u64(
+        &self,
+        a: &[u64],
+        b: &[u64],
+        out: &mut [u64],
+        negacyclic: bool,
+        stack: PodStack,
+    ) {
+        let n = self.polynomial_size();
+        assert!(a.len() <= n);
+        assert!(b.len() <= n);
+        assert_eq!(out.len(), n);
+
+        let Some(plan) = &self.plan128 else {
+            schoolbook_u64(a, b, out, negacyclic);
+            return;
+        };
+
+        // the coefficients are split into two signed 32-bit digits, so that
+        // `a * b = a_lo * b_lo + (a_lo * b_hi + a_hi * b_lo) * 2^32` modulo `2^64`.
+        // the products of the digits are small enough to be computed exactly by the 128-bit FFT.
+        let (poly, stack) = stack.make_aligned_raw::<u128>(2 * n, CACHELINE_ALIGN);
+        let (a_lo, stack) = stack.make_aligned_raw::<f64>(4 * n, CACHELINE_ALIGN);
+        let (a_hi, stack) = stack.make_aligned_raw::<f64>(4 * n, CACHELINE_ALIGN);
+        let (b_lo, stack) = stack.make_aligned_raw::<f64>(4 * n, CACHELINE_ALIGN);
+        let (b_hi, _) = stack.make_aligned_raw::<f64>(4 * n, CACHELINE_ALIGN);
+
+        fwd_digits(plan, a_lo, poly, a, |x| split_digits(x).0);
+        fwd_digits(plan, a_hi, poly, a, |x| split_digits(x).1);
+        fwd_digits(plan, b_lo, poly, b, |x| split_digits(x).0);
+        fwd_digits(plan, b_hi, poly, b, |x| split_digits(x).1);
+
+        {
+            let (a_lo_re0, a_lo_re1, a_lo_im0, a_lo_im1) = split_mut_4(a_lo);
+            let (a_hi_re0, a_hi_re1, a_hi_im0, a_hi_im1) = split_mut_4(a_hi);
+            let (b_lo_re0, b_lo_re1, b_lo_im0, b_lo_im1) = split_mut_4(b_lo);
+            let (b_hi_re0, b_hi_re1, b_hi_im0, b_hi_im1) = split_mut_4(b_hi);
+
+            for (
+                (a_lo_re0, a_lo_re1, a_lo_im0, a_lo_im1),
+                (a_hi_re0, a_hi_re1, a_hi_im0, a_hi_im1),
+                (&b_lo_re0, &b_lo_re1, &b_lo_im0, &b_lo_im1),
+                (&b_hi_re0, &b_hi_re1, &b_hi_im0, &b_hi_im1),
+            ) in izip!(
+                izip!(a_lo_re0, a_lo_re1, a_lo_im0, a_lo_im1),
+                izip!(a_hi_re0, a_hi_re1, a_hi_im0, a_hi_im1),
+                izip!(&*b_lo_re0, &*b_lo_re1, &*b_lo_im0, &*b_lo_im1),
+                izip!(&*b_hi_re0, &*b_hi_re1, &*b_hi_im0, &*b_hi_im1),
+            ) {
+                let a_lo = (f128(*a_lo_re0, *a_lo_re1), f128(*a_lo_im0, *a_lo_im1));
+                let a_hi = (f128(*a_hi_re0, *a_hi_re1), f128(*a_hi_im0, *a_hi_im1));
+                let b_lo = (f128(b_lo_re0, b_lo_re1), f128(b_lo_im0, b_lo_im1));
+                let b_hi = (f128(b_hi_re0, b_hi_re1), f128(b_hi_im0, b_hi_im1));
+
+                let lo = mul_f128(a_lo, b_lo);
+                let (cross0, cross1) = (mul_f128(a_lo, b_hi), mul_f128(a_hi, b_lo));
+                let cross = (cross0.0 + cross1.0, cross0.1 + cross1.1);
+
+                (*a_lo_re0, *a_lo_re1, *a_lo_im0, *a_lo_im1) = (lo.0 .0, lo.0 .1, lo.1 .0, lo.1 .1);
+                (*a_hi_re0, *a_hi_re1, *a_hi_im0, *a_hi_im1) =
+                    (cross.0 .0, cross.0 .1, cross.1 .0, cross.1 .1);
+            }
+        }
+
+        inv_fold(plan, poly, a_lo, n, negacyclic);
+        for (out, &lo) in izip!(&mut *out, &*poly) {
+            *out = lo as u64;
+        }
+        inv_fold(plan, poly, a_hi, n, negacyclic);
+        for (out, &cross) in izip!(&mut *out, &*poly) {
+            *out = out.wrapping_add((cross as u64) << 32);
+        }
+    }
+}
+
+// method of the plans that are created by the free functions, which doesn't require any
+// measurement
+fn default_method(n: usize) -> Method<'static> {
+    Method::UserProvided {
+        base_algo: FftAlgo::Dif4,
+        base_n: n.min(256),
+    }
+}
+
+// calls `f` with a polynomial multiplication plan of size `n`, and scratch memory for it. the plan
+// is cached for the current thread, and only rebuilt when the size changes
+#[track_caller]
+fn with_plan<R>(n: usize, f: impl FnOnce(&Plan, PodStack) -> R) -> R {
+    #[cfg(feature = "std")]
+    {
+        use core::cell::RefCell;
+
+        std::thread_local! {
+            static PLAN: RefCell<Option<Plan>> = const { RefCell::new(None) };
+        }
+
+        PLAN.with(|plan| {
+            let mut plan = plan.borrow_mut();
+            if plan.as_ref().map(Plan::polynomial_size) != Some(n) {
+                *plan = Some(Plan::new(n, default_method(n)));
+            }
+            let plan = plan.as_ref().unwrap();
+            let mut mem = GlobalPodBuffer::new(plan.polymul_scratch().unwrap());
+            f(plan, PodStack::new(&mut mem))
+        })
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        let plan = Plan::new(n, default_method(n));
+        let mut mem = GlobalPodBuffer::new(plan.polymul_scratch().unwrap());
+        f(&plan, PodStack::new(&mut mem))
+    }
+}
+
+/// Computes the product of `a` and `b` modulo $X^n - 1$, storing the result in `out`, where `n`
+/// is the length of `out`.
+///
+/// This is equivalent to [`Plan::cyclic`], with a plan of size `n` that is managed internally.
+///
+/// # Panics
+///
+/// - Panics if `n` is not a power of two, or if it is less than `4`.
+/// - Panics if the length of `a` or `b` is greater than `n`.
+///
+/// # Example
+///
+/// ```
+/// use concrete_fft::polymul;
+///
+/// // (1 + X^3) * (1 + X) = 1 + X + X^3 + X^4 = 2 + X + X^3 modulo X^4 - 1
+/// let mut out = [0.0; 4];
+/// polymul::cyclic(&[1.0, 0.0, 0.0, 1.0], &[1.0, 1.0], &mut out);
+///
+/// for (actual, expected) in out.iter().zip([2.0, 1.0, 0.0, 1.0]) {
+///     assert!((actual - expected).abs() < 1e-9);
+/// }
+/// ```
+#[track_caller]
+pub fn cyclic(a: &[f64], b: &[f64], out: &mut [f64]) {
+    with_plan(out.len(), |plan, stack| plan.cyclic(a, b, out, stack))
+}
+
+/// Computes the product of `a` and `b` modulo $X^n + 1$, storing the result in `out`, where `n`
+/// is the length of `out`.
+///
+/// This is equivalent to [`Plan::negacyclic`], with a plan of size `n` that is managed internally.
+///
+/// # Panics
+///
+/// - Panics if `n` is not a power of two, or if it is less than `4`.
+/// - Panics if the length of `a` or `b` is greater than `n`.
+///
+/// # Example
+///
+/// ```
+/// use concrete_fft::polymul;
+///
+/// // (1 + X^3) * (1 + X) = 1 + X + X^3 + X^4 = X + X^3 modulo X^4 + 1
+/// let mut out = [0.0; 4];
+/// polymul::negacyclic(&[1.0, 0.0, 0.0, 1.0], &[1.0, 1.0], &mut out);
+///
+/// for (actual, expected) in out.iter().zip([0.0, 1.0, 0.0, 1.0]) {
+///     assert!((actual - expected).abs() < 1e-9);
+/// }
+/// ```
+#[track_caller]
+pub fn negacyclic(a: &[f64], b: &[f64], out: &mut [f64]) {
+    with_plan(out.len(), |plan, stack| plan.negacyclic(a, b, out, stack))
+}
+
+/// Computes the product of `a` and `b` modulo $X^n - 1$ and modulo $2^{64}$, storing the result
+/// in `out`, where `n` is the length of `out`.
+///
+/// This is equivalent to [`Plan::cyclic_u64`], with a plan of size `n` that is managed internally.
+///
+/// # Panics
+///
+/// - Panics if `n` is not a power of two, or if it is less than `4`.
+/// - Panics if the length of `a` or `b` is greater than `n`.
+///
+/// # Example
+///
+/// ```
+/// use concrete_fft::polymul;
+///
+/// let mut out = [0; 4];
+/// polymul::cyclic_u64(&[1, 0, 0, u64::MAX], &[1, 2], &mut out);
+/// assert_eq!(out, [u64::MAX, 2, 0, u64::MAX]);
+/// ```
+#[cfg(feature = "fft128")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fft128")))]
+#[track_caller]
+pub fn cyclic_u64(a: &[u64], b: &[u64], out: &mut [u64]) {
+    with_plan(out.len(), |plan, stack| plan.cyclic_u64(a, b, out, stack))
+}
+
+/// Computes the product of `a` and `b` modulo $X^n + 1$ and modulo $2^{64}$, storing the result
+/// in `out`, where `n` is the length of `out`.
+///
+/// This is equivalent to [`Plan::negacyclic_u64`], with a plan of size `n` that is managed
+/// internally.
+///
+/// # Panics
+///
+/// - Panics if `n` is not a power of two, or if it is less than `4`.
+/// - Panics if the length of `a` or `b` is greater than `n`.
+///
+/// # Example
+///
+/// ```
+/// use concrete_fft::polymul;
+///
+/// let mut out = [0; 4];
+/// polymul::negacyclic_u64(&[1, 0, 0, u64::MAX], &[1, 2], &mut out);
+/// assert_eq!(out, [3, 2, 0, u64::MAX]);
+/// ```
+#[cfg(feature = "fft128")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fft128")))]
+#[track_caller]
+pub fn negacyclic_u64(a: &[u64], b: &[u64], out: &mut [u64]) {
+    with_plan(out.len(), |plan, stack| {
+        plan.negacyclic_u64(a, b, out, stack)
+    })
+}
+
+// copies `src` into `dst`, and fills the rest of `dst` with zeros
+fn pad(dst: &mut [f64], src: &[f64]) {
+    let (head, tail) = dst.split_at_mut(src.len());
+    head.copy_from_slice(src);
+    tail.fill(0.0);
+}
+
+// copies `src` into the real parts of `dst`, and fills the rest of `dst` with zeros
+fn pad_complex(dst: &mut [c64], src: &[f64]) {
+    let (head, tail) = dst.split_at_mut(src.len());
+    for (dst, &re) in izip!(head, src) {
+        *dst = c64 { re, im: 0.0 };
+    }
+    tail.fill(c64::default());
+}
+
+// splits `x` into two signed digits `lo` and `hi`, both in `[-2^31, 2^31)`, such that
+// `x = lo + hi * 2^32` modulo `2^64`
+#[cfg(feature = "fft128")]
+fn split_digits(x: u64) -> (i64, i64) {
+    let lo = x as u32 as i32 as i64;
+    let hi = (x as i64).wrapping_sub(lo) >> 32;
+    (lo, hi)
+}
+
+#[cfg(feature = "fft128")]
+fn mul_f128(a: (f128, f128), b: (f128, f128)) -> (f128, f128) {
+    (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0)
+}
+
+// computes the forward transform of the digits of the zero-padded polynomial `src`
+#[cfg(feature = "fft128")]
+fn fwd_digits(
+    plan: &fft128::Plan,
+    fourier: &mut [f64],
+    poly: &mut [u128],
+    src: &[u64],
+    digit: fn(u64) -> i64,
+) {
+    let (head, tail) = poly.split_at_mut(src.len());
+    for (dst, &x) in izip!(head, src) {
+        *dst = digit(x) as i128 as u128;
+    }
+    tail.fill(0);
+
+    let (re0, re1, im0, im1) = split_mut_4(fourier);
+    plan.fwd_u128(re0, re1, im0, im1, poly);
+}
+
+// computes the inverse transform of `fourier`, and folds the full product that is stored in `poly`
+// into its first `n` coefficients
+#[cfg(feature = "fft128")]
+fn inv_fold(
+    plan: &fft128::Plan,
+    poly: &mut [u128],
+    fourier: &mut [f64],
+    n: usize,
+    negacyclic: bool,
+) {
+    poly.fill(0);
+    let (re0, re1, im0, im1) = split_mut_4(fourier);
+    plan.inv_u128_add_assign(poly, re0, re1, im0, im1);
+
+    let (lo, hi) = poly.split_at_mut(n);
+    for (lo, &hi) in izip!(lo, &*hi) {
+        *lo = if negacyclic {
+            lo.wrapping_sub(hi)
+        } else {
+            lo.wrapping_add(hi)
+        };
+    }
+}
+
+#[cfg(feature = "fft128")]
+fn schoolbook_u64(a: &[u64], b: &[u64], out: &mut [u64], negacyclic: bool) {
+    let n = out.len();
+    out.fill(0);
+    for (i, &a) in a.iter().enumerate() {
+        for (j, &b) in b.iter().enumerate() {
+            let prod = a.wrapping_mul(b);
+            let k = i + j;
+            if k < n {
+                out[k] = out[k].wrapping_add(prod);
+            } else if negacyclic {
+                out[k - n] = out[k - n].wrapping_sub(prod);
+            } else {
+                out[k - n] = out[k - n].wrapping_add(prod);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{vec, vec::Vec};
+    use rand::random;
+
+    extern crate alloc;
+
+    fn new_plan(n: usize) -> Plan {
+        Plan::new(
+            n,
+            Method::UserProvided {
+                base_algo: FftAlgo::Dif4,
+                base_n: n.min(32),
+            },
+        )
+    }
+
+    fn schoolbook(a: &[f64], b: &[f64], n: usize, negacyclic: bool) -> Vec<f64> {
+        let mut out = vec![0.0; n];
+        for (i, &a) in a.iter().enumerate() {
+            for (j, &b) in b.iter().enumerate() {
+                let k = i + j;
+                if k < n {
+                    out[k] += a * b;
+                } else if negacyclic {
+                    out[k - n] -= a * b;
+                } else {
+                    out[k - n] += a * b;
+                }
+            }
+        }
+        out
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_polymul() {
+        for n in [4, 8, 16, 64, 256, 1024] {
+            let plan = new_plan(n);
+            let mut mem = GlobalPodBuffer::new(plan.polymul_scratch().unwrap());
+            let mut stack = PodStack::new(&mut mem);
+
+            for (len_a, len_b) in [(n, n), (n / 2, n), (n / 2, n / 2), (1, n)] {
+                let a: Vec<f64> = (0..len_a).map(|_| random::<f64>() - 0.5).collect();
+                let b: Vec<f64> = (0..len_b).map(|_| random::<f64>() - 0.5).collect();
+
+                let mut cyclic = vec![0.0; n];
+                plan.cyclic(&a, &b, &mut cyclic, stack.rb_mut());
+                for (actual, expected) in izip!(&cyclic, schoolbook(&a, &b, n, false)) {
+                    assert!((actual - expected).abs() < 1e-10);
+                }
+
+                let mut negacyclic = vec![0.0; n];
+                plan.negacyclic(&a, &b, &mut negacyclic, stack.rb_mut());
+                for (actual, expected) in izip!(&negacyclic, schoolbook(&a, &b, n, true)) {
+                    assert!((actual - expected).abs() < 1e-10);
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "fft128")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_polymul_u64() {
+        for n in [4, 16, 32, 64, 256, 1024] {
+            let plan = new_plan(n);
+            let mut mem = GlobalPodBuffer::new(plan.polymul_scratch().unwrap());
+            let mut stack = PodStack::new(&mut mem);
+
+            for (len_a, len_b) in [(n, n), (n / 2, n), (1, n)] {
+                let a: Vec<u64> = (0..len_a).map(|_| random()).collect();
+                let b: Vec<u64> = (0..len_b).map(|_| random()).collect();
+
+                for negacyclic in [false, true] {
+                    let mut expected = vec![0; n];
+                    schoolbook_u64(&a, &b, &mut expected, negacyclic);
+
+                    let mut actual = vec![0; n];
+                    if negacyclic {
+                        plan.negacyclic_u64(&a, &b, &mut actual, stack.rb_mut());
+                    } else {
+                        plan.cyclic_u64(&a, &b, &mut actual, stack.rb_mut());
+                    }
+                    assert_eq!(actual, expected);
+                }
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_polymul_free_functions() {
+        // the sizes alternate, so that the cached plan is rebuilt
+        for n in [4, 64, 64, 8, 1024, 4] {
+            let a: Vec<f64> = (0..n).map(|_| random::<f64>() - 0.5).collect();
+            let b: Vec<f64> = (0..n / 2).map(|_| random::<f64>() - 0.5).collect();
+
+            let mut cyclic = vec![0.0; n];
+            super::cyclic(&a, &b, &mut cyclic);
+            for (actual, expected) in izip!(&cyclic, schoolbook(&a, &b, n, false)) {
+                assert!((actual - expected).abs() < 1e-10);
+            }
+
+            let mut negacyclic = vec![0.0; n];
+            super::negacyclic(&a, &b, &mut negacyclic);
+            for (actual, expected) in izip!(&negacyclic, schoolbook(&a, &b, n, true)) {
+                assert!((actual - expected).abs() < 1e-10);
+            }
+
+            #[cfg(feature = "fft128")]
+            {
+                let a: Vec<u64> = (0..n).map(|_| random()).collect();
+                let b: Vec<u64> = (0..n / 2).map(|_| random()).collect();
+
+                for negacyclic in [false, true] {
+                    let mut expected = vec![0; n];
+                    schoolbook_u64(&a, &b, &mut expected, negacyclic);
+
+                    let mut actual = vec![0; n];
+                    if negacyclic {
+                        super::negacyclic_u64(&a, &b, &mut actual);
+                    } else {
+                        super::cyclic_u64(&a, &b, &mut actual);
+                    }
+                    assert_eq!(actual, expected);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,1095 @@
+// FFT kernels operating on split-complex vectors, where the real and imaginary parts are stored in
+// two separate slices.
+//
+// the ordered kernels follow the same stockham structure as the interleaved ones in the `dif*` and
+// `dit*` modules: the radix-`R` passes alternate between the buffer and the scratch space, and are
+// followed (decimation in frequency) or preceded (decimation in time) by a single pass of radix at
+// most `R` that has no twiddles. each pass is vectorized over the index `j = q + s * p` of its
+// butterflies, where `s` is the stride of the pass, so the passes whose stride is smaller than the
+// number of lanes shuffle their outputs (or inputs) in registers instead of falling back to scalar
+// code.
+//
+// the unordered kernels follow the structure of the unordered module: radix-2, 4 or 8 passes
+// computed in place, down to an ordered kernel of the base size.
+//
+// the twiddles of a radix-`R` pass of size `n` are stored at the indices `(k - 1) * n / R + j`,
+// and are equal to `exp(-2iπ k j / n)` for `k` in `1..R` and `j` in `0..n / R`. the inverse
+// kernels use their conjugates.
+
+use crate::{
+    fft_simd::{sincospi64, H1X, H1Y},
+    ordered::FftAlgo,
+    SimdLevel,
+};
+use aligned_vec::{avec, ABox};
+use core::f64::consts::FRAC_1_SQRT_2;
+use pulp::Simd;
+
+// ordered kernel, taking the real and imaginary parts of the buffer, of the scratch space and of
+// the twiddles
+type OrderedFn = fn(&mut [f64], &mut [f64], &mut [f64], &mut [f64], &[f64], &[f64]);
+
+// unordered pass, taking the real and imaginary parts of the buffer and of the twiddles
+type PassFn = fn(&mut [f64], &mut [f64], &[f64], &[f64]);
+
+// instruction sets that the kernels can be instantiated with. they are zero-sized, so they can be
+// reconstructed inside the kernels, which lets the kernels be coerced to function pointers.
+trait SplitSimd: Simd {
+    fn get() -> Self;
+}
+
+impl SplitSimd for pulp::Scalar {
+    #[inline(always)]
+    fn get() -> Self {
+        pulp::Scalar::new()
+    }
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+impl SplitSimd for pulp::x86::V3 {
+    #[inline(always)]
+    fn get() -> Self {
+        Self::try_new().unwrap()
+    }
+}
+
+#[cfg(all(feature = "avx512", any(target_arch = "x86_64", target_arch = "x86")))]
+impl SplitSimd for pulp::x86::V4 {
+    #[inline(always)]
+    fn get() -> Self {
+        Self::try_new().unwrap()
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+impl SplitSimd for pulp::aarch64::Neon {
+    #[inline(always)]
+    fn get() -> Self {
+        Self::try_new().unwrap()
+    }
+}
+
+#[inline(always)]
+fn lane_count<S: Simd>() -> usize {
+    core::mem::size_of::<S::f64s>() / core::mem::size_of::<f64>()
+}
+
+pub(crate) fn radix(algo: FftAlgo) -> usize {
+    use FftAlgo::*;
+    match algo {
+        Dif2 | Dit2 => 2,
+        Dif4 | Dit4 => 4,
+        Dif8 | Dit8 => 8,
+        Dif16 | Dit16 => 16,
+    }
+}
+
+#[inline(always)]
+fn bit_rev<const R: usize>(i: usize) -> usize {
+    if R == 1 {
+        i
+    } else {
+        i.reverse_bits() >> (usize::BITS - R.trailing_zeros())
+    }
+}
+
+// returns `(a_re + i a_im) * (w_re + i w_im)`
+#[inline(always)]
+fn mul<S: Simd>(
+    simd: S,
+    a_re: S::f64s,
+    a_im: S::f64s,
+    w_re: S::f64s,
+    w_im: S::f64s,
+) -> (S::f64s, S::f64s) {
+    (
+        simd.mul_add_e_f64s(a_re, w_re, simd.neg_f64s(simd.mul_f64s(a_im, w_im))),
+        simd.mul_add_e_f64s(a_re, w_im, simd.mul_f64s(a_im, w_re)),
+    )
+}
+
+// returns `(re + i im) * exp(∓iπ t / 8)`, where the sign is negative for the forward transform
+#[inline(always)]
+fn rotate<S: Simd>(simd: S, fwd: bool, t: usize, re: S::f64s, im: S::f64s) -> (S::f64s, S::f64s) {
+    // multiples of a quarter turn are computed exactly
+    let (re, im) = match (t / 4) % 4 {
+        0 => (re, im),
+        1 if fwd => (im, simd.neg_f64s(re)),
+        1 => (simd.neg_f64s(im), re),
+        2 => (simd.neg_f64s(re), simd.neg_f64s(im)),
+        _ if fwd => (simd.neg_f64s(im), re),
+        _ => (im, simd.neg_f64s(re)),
+    };
+
+    // `exp(-iπ t / 8)` for the remaining `t`
+    let (c, s) = match t % 4 {
+        0 => return (re, im),
+        1 => (H1X, H1Y),
+        2 => (FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
+        _ => (-H1Y, -H1X),
+    };
+    let s = if fwd { s } else { -s };
+    mul(simd, re, im, simd.splat_f64s(c), simd.splat_f64s(s))
+}
+
+// computes the DFT of size `R` of `(re, im)` in place, with a radix-2 network that is fully
+// unrolled since `R` is known at compile time, so that all of its twiddles are constants
+#[inline(always)]
+fn butterfly<S: Simd, const R: usize>(
+    simd: S,
+    fwd: bool,
+    re: &mut [S::f64s; R],
+    im: &mut [S::f64s; R],
+) {
+    let mut h = R / 2;
+    while h > 0 {
+        for start in (0..R).step_by(2 * h) {
+            for i in 0..h {
+                let (a_re, a_im) = (re[start + i], im[start + i]);
+                let (b_re, b_im) = (re[start + i + h], im[start + i + h]);
+
+                re[start + i] = simd.add_f64s(a_re, b_re);
+                im[start + i] = simd.add_f64s(a_im, b_im);
+                (re[start + i + h], im[start + i + h]) = rotate(
+                    simd,
+                    fwd,
+                    8 * i / h,
+                    simd.sub_f64s(a_re, b_re),
+                    simd.sub_f64s(a_im, b_im),
+                );
+            }
+        }
+        h /= 2;
+    }
+
+    // the network produces its outputs in bit-reversed order
+    for i in 0..R {
+        let j = bit_rev::<R>(i);
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+}
+
+// interleaves the registers of `x` in units of `STRIDE` lanes, so that the unit `p` of the register
+// `k` ends up at the unit `R * p + k` of the output. all the indices are known at compile time, so
+// this compiles down to register shuffles.
+#[inline(always)]
+fn interleave<S: Simd, const R: usize, const STRIDE: usize>(x: [S::f64s; R]) -> [S::f64s; R] {
+    let lanes = lane_count::<S>();
+    let mut y = x;
+    {
+        let x: &[f64] = bytemuck::cast_slice(&x);
+        let y: &mut [f64] = bytemuck::cast_slice_mut(&mut y);
+        for p in 0..lanes / STRIDE {
+            for k in 0..R {
+                for r in 0..STRIDE {
+                    y[STRIDE * (R * p + k) + r] = x[lanes * k + STRIDE * p + r];
+                }
+            }
+        }
+    }
+    y
+}
+
+// inverse of [`interleave`]
+#[inline(always)]
+fn deinterleave<S: Simd, const R: usize, const STRIDE: usize>(y: [S::f64s; R]) -> [S::f64s; R] {
+    let lanes = lane_count::<S>();
+    let mut x = y;
+    {
+        let y: &[f64] = bytemuck::cast_slice(&y);
+        let x: &mut [f64] = bytemuck::cast_slice_mut(&mut x);
+        for p in 0..lanes / STRIDE {
+            for k in 0..R {
+                for r in 0..STRIDE {
+                    x[lanes * k + STRIDE * p + r] = y[STRIDE * (R * p + k) + r];
+                }
+            }
+        }
+    }
+    x
+}
+
+// loads the twiddles of the butterflies `j..j + lanes` of a pass with stride `STRIDE`, where `w`
+// starts at the twiddle of the butterfly `j`. the twiddle of the butterfly `q + STRIDE * p` only
+// depends on `p`.
+#[inline(always)]
+fn load_twiddles<S: Simd, const STRIDE: usize>(simd: S, w: &[f64]) -> S::f64s {
+    let lanes = lane_count::<S>();
+    if STRIDE == 1 {
+        return S::as_simd_f64s(&w[..lanes]).0[0];
+    }
+
+    let mut v = simd.splat_f64s(0.0);
+    let lanes_mut: &mut [f64] = bytemuck::cast_slice_mut(core::slice::from_mut(&mut v));
+    for (e, lane) in lanes_mut.iter_mut().enumerate() {
+        *lane = w[e - e % STRIDE];
+    }
+    v
+}
+
+// radix-`R` decimation-in-frequency pass with a stride `STRIDE` smaller than the number of lanes
+#[inline(always)]
+fn dif_pass_small<S: Simd, const R: usize, const STRIDE: usize>(
+    simd: S,
+    fwd: bool,
+    src_re: &[f64],
+    src_im: &[f64],
+    dst_re: &mut [f64],
+    dst_im: &mut [f64],
+    w_re: &[f64],
+    w_im: &[f64],
+) {
+    let lanes = lane_count::<S>();
+    let m = src_re.len() / R;
+    let simd_m = m / lanes;
+
+    let src_re = S::as_simd_f64s(src_re).0;
+    let src_im = S::as_simd_f64s(src_im).0;
+    let dst_re = S::as_mut_simd_f64s(dst_re).0;
+    let dst_im = S::as_mut_simd_f64s(dst_im).0;
+
+    for j in 0..simd_m {
+        let mut re = [simd.splat_f64s(0.0); R];
+        let mut im = [simd.splat_f64s(0.0); R];
+        for k in 0..R {
+            re[k] = src_re[k * simd_m + j];
+            im[k] = src_im[k * simd_m + j];
+        }
+
+        butterfly::<S, R>(simd, fwd, &mut re, &mut im);
+
+        for k in 1..R {
+            let offset = (k - 1) * m + j * lanes;
+            let w_re = load_twiddles::<S, STRIDE>(simd, &w_re[offset..]);
+            let w_im = load_twiddles::<S, STRIDE>(simd, &w_im[offset..]);
+            let w_im = if fwd { w_im } else { simd.neg_f64s(w_im) };
+            (re[k], im[k]) = mul(simd, re[k], im[k], w_re, w_im);
+        }
+
+        let re = interleave::<S, R, STRIDE>(re);
+        let im = interleave::<S, R, STRIDE>(im);
+        for k in 0..R {
+            dst_re[R * j + k] = re[k];
+            dst_im[R * j + k] = im[k];
+        }
+    }
+}
+
+// radix-`R` decimation-in-frequency pass with stride `s`, reading from `src` and writing to `dst`
+#[inline(always)]
+fn dif_pass<S: Simd, const R: usize>(
+    simd: S,
+    fwd: bool,
+    s: usize,
+    src_re: &[f64],
+    src_im: &[f64],
+    dst_re: &mut [f64],
+    dst_im: &mut [f64],
+    w_re: &[f64],
+    w_im: &[f64],
+) {
+    let lanes = lane_count::<S>();
+    if s < lanes {
+        match s {
+            1 => dif_pass_small::<S, R, 1>(simd, fwd, src_re, src_im, dst_re, dst_im, w_re, w_im),
+            2 => dif_pass_small::<S, R, 2>(simd, fwd, src_re, src_im, dst_re, dst_im, w_re, w_im),
+            _ => dif_pass_small::<S, R, 4>(simd, fwd, src_re, src_im, dst_re, dst_im, w_re, w_im),
+        }
+        return;
+    }
+
+    let m = src_re.len() / R;
+    let simd_m = m / lanes;
+    let simd_s = s / lanes;
+
+    let src_re = S::as_simd_f64s(src_re).0;
+    let src_im = S::as_simd_f64s(src_im).0;
+    let dst_re = S::as_mut_simd_f64s(dst_re).0;
+    let dst_im = S::as_mut_simd_f64s(dst_im).0;
+
+    for p in 0..m / s {
+        let mut tw_re = [simd.splat_f64s(1.0); R];
+        let mut tw_im = [simd.splat_f64s(0.0); R];
+        for k in 1..R {
+            let w_im = w_im[(k - 1) * m + s * p];
+            tw_re[k] = simd.splat_f64s(w_re[(k - 1) * m + s * p]);
+            tw_im[k] = simd.splat_f64s(if fwd { w_im } else { -w_im });
+        }
+
+        for q in 0..simd_s {
+            let mut re = [simd.splat_f64s(0.0); R];
+            let mut im = [simd.splat_f64s(0.0); R];
+            for k in 0..R {
+                re[k] = src_re[k * simd_m + simd_s * p + q];
+                im[k] = src_im[k * simd_m + simd_s * p + q];
+            }
+
+            butterfly::<S, R>(simd, fwd, &mut re, &mut im);
+
+            for k in 0..R {
+                let (re, im) = if k == 0 {
+                    (re[0], im[0])
+                } else {
+                    mul(simd, re[k], im[k], tw_re[k], tw_im[k])
+                };
+                dst_re[simd_s * (R * p + k) + q] = re;
+                dst_im[simd_s * (R * p + k) + q] = im;
+            }
+        }
+    }
+}
+
+// radix-`R` decimation-in-time pass with a stride `STRIDE` smaller than the number of lanes
+#[inline(always)]
+fn dit_pass_small<S: Simd, const R: usize, const STRIDE: usize>(
+    simd: S,
+    fwd: bool,
+    src_re: &[f64],
+    src_im: &[f64],
+    dst_re: &mut [f64],
+    dst_im: &mut [f64],
+    w_re: &[f64],
+    w_im: &[f64],
+) {
+    let lanes = lane_count::<S>();
+    let m = src_re.len() / R;
+    let simd_m = m / lanes;
+
+    let src_re = S::as_simd_f64s(src_re).0;
+    let src_im = S::as_simd_f64s(src_im).0;
+    let dst_re = S::as_mut_simd_f64s(dst_re).0;
+    let dst_im = S::as_mut_simd_f64s(dst_im).0;
+
+    for j in 0..simd_m {
+        let mut re = [simd.splat_f64s(0.0); R];
+        let mut im = [simd.splat_f64s(0.0); R];
+        for k in 0..R {
+            re[k] = src_re[R * j + k];
+            im[k] = src_im[R * j + k];
+        }
+        let mut re = deinterleave::<S, R, STRIDE>(re);
+        let mut im = deinterleave::<S, R, STRIDE>(im);
+
+        for k in 1..R {
+            let offset = (k - 1) * m + j * lanes;
+            let w_re = load_twiddles::<S, STRIDE>(simd, &w_re[offset..]);
+            let w_im = load_twiddles::<S, STRIDE>(simd, &w_im[offset..]);
+            let w_im = if fwd { w_im } else { simd.neg_f64s(w_im) };
+            (re[k], im[k]) = mul(simd, re[k], im[k], w_re, w_im);
+        }
+
+        butterfly::<S, R>(simd, fwd, &mut re, &mut im);
+
+        for k in 0..R {
+            dst_re[k * simd_m + j] = re[k];
+            dst_im[k * simd_m + j] = im[k];
+        }
+    }
+}
+
+// radix-`R` decimation-in-time pass with stride `s`, reading from `src` and writing to `dst`
+#[inline(always)]
+fn dit_pass<S: Simd, const R: usize>(
+    simd: S,
+    fwd: bool,
+    s: usize,
+    src_re: &[f64],
+    src_im: &[f64],
+    dst_re: &mut [f64],
+    dst_im: &mut [f64],
+    w_re: &[f64],
+    w_im: &[f64],
+) {
+    let lanes = lane_count::<S>();
+    if s < lanes {
+        match s {
+            1 => dit_pass_small::<S, R, 1>(simd, fwd, src_re, src_im, dst_re, dst_im, w_re, w_im),
+            2 => dit_pass_small::<S, R, 2>(simd, fwd, src_re, src_im, dst_re, dst_im, w_re, w_im),
+            _ => dit_pass_small::<S, R, 4>(simd, fwd, src_re, src_im, dst_re, dst_im, w_re, w_im),
+        }
+        return;
+    }
+
+    let m = src_re.len() / R;
+    let simd_m = m / lanes;
+    let simd_s = s / lanes;
+
+    let src_re = S::as_simd_f64s(src_re).0;
+    let src_im = S::as_simd_f64s(src_im).0;
+    let dst_re = S::as_mut_simd_f64s(dst_re).0;
+    let dst_im = S::as_mut_simd_f64s(dst_im).0;
+
+    for p in 0..m / s {
+        let mut tw_re = [simd.splat_f64s(1.0); R];
+        let mut tw_im = [simd.splat_f64s(0.0); R];
+        for k in 1..R {
+            let w_im = w_im[(k - 1) * m + s * p];
+            tw_re[k] = simd.splat_f64s(w_re[(k - 1) * m + s * p]);
+            tw_im[k] = simd.splat_f64s(if fwd { w_im } else { -w_im });
+        }
+
+        for q in 0..simd_s {
+            let mut re = [simd.splat_f64s(0.0); R];
+            let mut im = [simd.splat_f64s(0.0); R];
+            for k in 0..R {
+                let (x_re, x_im) = (
+                    src_re[simd_s * (R * p + k) + q],
+                    src_im[simd_s * (R * p + k) + q],
+                );
+                (re[k], im[k]) = if k == 0 {
+                    (x_re, x_im)
+                } else {
+                    mul(simd, x_re, x_im, tw_re[k], tw_im[k])
+                };
+            }
+
+            butterfly::<S, R>(simd, fwd, &mut re, &mut im);
+
+            for k in 0..R {
+                dst_re[k * simd_m + simd_s * p + q] = re[k];
+                dst_im[k * simd_m + simd_s * p + q] = im[k];
+            }
+        }
+    }
+}
+
+// radix-`R` pass without twiddles, with stride `n / R`. this is the last pass of the decimation in
+// frequency transform and the first one of the decimation in time transform, and it is computed in
+// place if `src` is `None`.
+#[inline(always)]
+fn end_pass<S: Simd, const R: usize>(
+    simd: S,
+    fwd: bool,
+    src: Option<(&[f64], &[f64])>,
+    dst_re: &mut [f64],
+    dst_im: &mut [f64],
+) {
+    let simd_s = dst_re.len() / R / lane_count::<S>();
+    let dst_re = S::as_mut_simd_f64s(dst_re).0;
+    let dst_im = S::as_mut_simd_f64s(dst_im).0;
+    let src = src.map(|(re, im)| (S::as_simd_f64s(re).0, S::as_simd_f64s(im).0));
+
+    for q in 0..simd_s {
+        let mut re = [simd.splat_f64s(0.0); R];
+        let mut im = [simd.splat_f64s(0.0); R];
+        for k in 0..R {
+            (re[k], im[k]) = match src {
+                Some((src_re, src_im)) => (src_re[k * simd_s + q], src_im[k * simd_s + q]),
+                None => (dst_re[k * simd_s + q], dst_im[k * simd_s + q]),
+            };
+        }
+
+        butterfly::<S, R>(simd, fwd, &mut re, &mut im);
+
+        for k in 0..R {
+            dst_re[k * simd_s + q] = re[k];
+            dst_im[k * simd_s + q] = im[k];
+        }
+    }
+}
+
+#[inline(always)]
+fn end_pass_dispatch<S: Simd>(
+    simd: S,
+    fwd: bool,
+    r: usize,
+    src: Option<(&[f64], &[f64])>,
+    dst_re: &mut [f64],
+    dst_im: &mut [f64],
+) {
+    match r {
+        2 => end_pass::<S, 2>(simd, fwd, src, dst_re, dst_im),
+        4 => end_pass::<S, 4>(simd, fwd, src, dst_re, dst_im),
+        8 => end_pass::<S, 8>(simd, fwd, src, dst_re, dst_im),
+        _ => end_pass::<S, 16>(simd, fwd, src, dst_re, dst_im),
+    }
+}
+
+#[inline(always)]
+fn ordered_impl<S: Simd, const R: usize, const DIT: bool>(
+    simd: S,
+    fwd: bool,
+    re: &mut [f64],
+    im: &mut [f64],
+    scratch_re: &mut [f64],
+    scratch_im: &mut [f64],
+    w_re: &[f64],
+    w_im: &[f64],
+) {
+    let n = re.len();
+    if n == 1 {
+        return;
+    }
+
+    // number of radix-`R` passes, the remaining factor of `n` being handled by the pass without
+    // twiddles
+    let log_r = R.trailing_zeros();
+    let count = (n.trailing_zeros() - 1) / log_r;
+    let r_end = n >> (count * log_r);
+
+    // the passes alternate between the buffer and the scratch space, and their number determines
+    // whether the pass without twiddles is computed in place, so that the result ends up in the
+    // buffer
+    if !DIT {
+        let mut s = 1;
+        for i in 0..count {
+            if i % 2 == 0 {
+                dif_pass::<S, R>(simd, fwd, s, re, im, scratch_re, scratch_im, w_re, w_im);
+            } else {
+                dif_pass::<S, R>(simd, fwd, s, scratch_re, scratch_im, re, im, w_re, w_im);
+            }
+            s *= R;
+        }
+
+        if count.is_multiple_of(2) {
+            end_pass_dispatch(simd, fwd, r_end, None, re, im);
+        } else {
+            end_pass_dispatch(simd, fwd, r_end, Some((scratch_re, scratch_im)), re, im);
+        }
+    } else {
+        if count.is_multiple_of(2) {
+            end_pass_dispatch(simd, fwd, r_end, None, re, im);
+        } else {
+            end_pass_dispatch(simd, fwd, r_end, Some((re, im)), scratch_re, scratch_im);
+        }
+
+        let mut s = n / r_end;
+        for i in (0..count).rev() {
+            s /= R;
+            if i % 2 == 0 {
+                dit_pass::<S, R>(simd, fwd, s, scratch_re, scratch_im, re, im, w_re, w_im);
+            } else {
+                dit_pass::<S, R>(simd, fwd, s, re, im, scratch_re, scratch_im, w_re, w_im);
+            }
+        }
+    }
+}
+
+fn ordered_kernel<S: SplitSimd, const R: usize, const DIT: bool, const FWD: bool>(
+    re: &mut [f64],
+    im: &mut [f64],
+    scratch_re: &mut [f64],
+    scratch_im: &mut [f64],
+    w_re: &[f64],
+    w_im: &[f64],
+) {
+    struct Impl<'a, S, const R: usize, const DIT: bool, const FWD: bool> {
+        simd: S,
+        re: &'a mut [f64],
+        im: &'a mut [f64],
+        scratch_re: &'a mut [f64],
+        scratch_im: &'a mut [f64],
+        w_re: &'a [f64],
+        w_im: &'a [f64],
+    }
+
+    // we use NullaryFnOnce instead of a closure because we need the #[inline(always)] annotation
+    impl<S: Simd, const R: usize, const DIT: bool, const FWD: bool> pulp::NullaryFnOnce
+        for Impl<'_, S, R, DIT, FWD>
+    {
+        type Output = ();
+
+        #[inline(always)]
+        fn call(self) -> Self::Output {
+            let Self {
+                simd,
+                re,
+                im,
+                scratch_re,
+                scratch_im,
+                w_re,
+                w_im,
+            } = self;
+            ordered_impl::<S, R, DIT>(simd, FWD, re, im, scratch_re, scratch_im, w_re, w_im);
+        }
+    }
+
+    let simd = S::get();
+    simd.vectorize(Impl::<S, R, DIT, FWD> {
+        simd,
+        re,
+        im,
+        scratch_re,
+        scratch_im,
+        w_re,
+        w_im,
+    });
+}
+
+fn ordered_fn_ptrs<S: SplitSimd>(algo: FftAlgo) -> [OrderedFn; 2] {
+    use FftAlgo::*;
+    match algo {
+        Dif2 => [
+            ordered_kernel::<S, 2, false, true>,
+            ordered_kernel::<S, 2, false, false>,
+        ],
+        Dit2 => [
+            ordered_kernel::<S, 2, true, true>,
+            ordered_kernel::<S, 2, true, false>,
+        ],
+        Dif4 => [
+            ordered_kernel::<S, 4, false, true>,
+            ordered_kernel::<S, 4, false, false>,
+        ],
+        Dit4 => [
+            ordered_kernel::<S, 4, true, true>,
+            ordered_kernel::<S, 4, true, false>,
+        ],
+        Dif8 => [
+            ordered_kernel::<S, 8, false, true>,
+            ordered_kernel::<S, 8, false, false>,
+        ],
+        Dit8 => [
+            ordered_kernel::<S, 8, true, true>,
+            ordered_kernel::<S, 8, true, false>,
+        ],
+        Dif16 => [
+            ordered_kernel::<S, 16, false, true>,
+            ordered_kernel::<S, 16, false, false>,
+        ],
+        Dit16 => [
+            ordered_kernel::<S, 16, true, true>,
+            ordered_kernel::<S, 16, true, false>,
+        ],
+    }
+}
+
+// returns the forward and inverse kernels, instantiated with the best simd instruction set that is
+// allowed by `level` and available at runtime, and whose registers are small enough for `n`
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")),
+    allow(unused_variables)
+)]
+fn get_ordered_fn_ptrs(algo: FftAlgo, n: usize, level: SimdLevel) -> [OrderedFn; 2] {
+    let r = radix(algo);
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    {
+        #[cfg(feature = "avx512")]
+        if level.v4().is_some() && n >= r * lane_count::<pulp::x86::V4>() {
+            return ordered_fn_ptrs::<pulp::x86::V4>(algo);
+        }
+        if level.v3().is_some() && n >= r * lane_count::<pulp::x86::V3>() {
+            return ordered_fn_ptrs::<pulp::x86::V3>(algo);
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if level.neon().is_some() && n >= r * lane_count::<pulp::aarch64::Neon>() {
+            return ordered_fn_ptrs::<pulp::aarch64::Neon>(algo);
+        }
+    }
+
+    ordered_fn_ptrs::<pulp::Scalar>(algo)
+}
+
+// returns the radix-`r` twiddles of a transform of size `n`
+fn new_twiddles(r: usize, n: usize) -> (ABox<[f64]>, ABox<[f64]>) {
+    let m = n / r;
+    let mut w_re = avec![0.0; (r - 1) * m].into_boxed_slice();
+    let mut w_im = avec![0.0; (r - 1) * m].into_boxed_slice();
+
+    let theta = 2.0 / n as f64;
+    for k in 1..r {
+        for j in 0..m {
+            let (s, c) = sincospi64(theta * (k * j) as f64);
+            w_re[(k - 1) * m + j] = c;
+            w_im[(k - 1) * m + j] = -s;
+        }
+    }
+    (w_re, w_im)
+}
+
+/// Ordered split-complex FFT of a fixed power-of-two size.
+#[derive(Clone)]
+pub(crate) struct SplitFft {
+    fwd: OrderedFn,
+    inv: OrderedFn,
+    twiddles_re: ABox<[f64]>,
+    twiddles_im: ABox<[f64]>,
+    n: usize,
+}
+
+impl SplitFft {
+    pub fn new(algo: FftAlgo, n: usize, simd: SimdLevel) -> Self {
+        assert!(n.is_power_of_two());
+
+        let [fwd, inv] = get_ordered_fn_ptrs(algo, n, simd);
+        let (twiddles_re, twiddles_im) = new_twiddles(radix(algo), n);
+        Self {
+            fwd,
+            inv,
+            twiddles_re,
+            twiddles_im,
+            n,
+        }
+    }
+
+    pub fn fft_size(&self) -> usize {
+        self.n
+    }
+
+    /// Forward FFT in standard order, using `scratch_re` and `scratch_im` as scratch space.
+    pub fn fwd(
+        &self,
+        re: &mut [f64],
+        im: &mut [f64],
+        scratch_re: &mut [f64],
+        scratch_im: &mut [f64],
+    ) {
+        self.process(self.fwd, re, im, scratch_re, scratch_im);
+    }
+
+    /// Inverse FFT in standard order, using `scratch_re` and `scratch_im` as scratch space.
+    pub fn inv(
+        &self,
+        re: &mut [f64],
+        im: &mut [f64],
+        scratch_re: &mut [f64],
+        scratch_im: &mut [f64],
+    ) {
+        self.process(self.inv, re, im, scratch_re, scratch_im);
+    }
+
+    fn process(
+        &self,
+        f: OrderedFn,
+        re: &mut [f64],
+        im: &mut [f64],
+        scratch_re: &mut [f64],
+        scratch_im: &mut [f64],
+    ) {
+        let n = self.n;
+        assert_eq!(re.len(), n);
+        assert_eq!(im.len(), n);
+        assert_eq!(scratch_re.len(), n);
+        assert_eq!(scratch_im.len(), n);
+        f(
+            re,
+            im,
+            scratch_re,
+            scratch_im,
+            &self.twiddles_re,
+            &self.twiddles_im,
+        );
+    }
+}
+
+// radix-`R` pass of the unordered transform, computed in place. like the interleaved passes of the
+// unordered module, the forward pass stores the output `k` of its butterflies in the section whose
+// index is `k` bit-reversed, and the inverse pass reads them from there.
+#[inline(always)]
+fn unordered_pass_impl<S: Simd, const R: usize>(
+    simd: S,
+    fwd: bool,
+    z_re: &mut [f64],
+    z_im: &mut [f64],
+    w_re: &[f64],
+    w_im: &[f64],
+) {
+    let lanes = lane_count::<S>();
+    let m = z_re.len() / R;
+    let simd_m = m / lanes;
+
+    let z_re = S::as_mut_simd_f64s(z_re).0;
+    let z_im = S::as_mut_simd_f64s(z_im).0;
+    let w_re = S::as_simd_f64s(w_re).0;
+    let w_im = S::as_simd_f64s(w_im).0;
+
+    for j in 0..simd_m {
+        let mut re = [simd.splat_f64s(0.0); R];
+        let mut im = [simd.splat_f64s(0.0); R];
+
+        if fwd {
+            for k in 0..R {
+                re[k] = z_re[k * simd_m + j];
+                im[k] = z_im[k * simd_m + j];
+            }
+
+            butterfly::<S, R>(simd, true, &mut re, &mut im);
+
+            for k in 0..R {
+                let (re, im) = if k == 0 {
+                    (re[0], im[0])
+                } else {
+                    let idx = (k - 1) * simd_m + j;
+                    mul(simd, re[k], im[k], w_re[idx], w_im[idx])
+                };
+                z_re[bit_rev::<R>(k) * simd_m + j] = re;
+                z_im[bit_rev::<R>(k) * simd_m + j] = im;
+            }
+        } else {
+            for k in 0..R {
+                let (x_re, x_im) = (
+                    z_re[bit_rev::<R>(k) * simd_m + j],
+                    z_im[bit_rev::<R>(k) * simd_m + j],
+                );
+                (re[k], im[k]) = if k == 0 {
+                    (x_re, x_im)
+                } else {
+                    let idx = (k - 1) * simd_m + j;
+                    mul(simd, x_re, x_im, w_re[idx], simd.neg_f64s(w_im[idx]))
+                };
+            }
+
+            butterfly::<S, R>(simd, false, &mut re, &mut im);
+
+            for k in 0..R {
+                z_re[k * simd_m + j] = re[k];
+                z_im[k * simd_m + j] = im[k];
+            }
+        }
+    }
+}
+
+fn unordered_pass<S: SplitSimd, const R: usize, const FWD: bool>(
+    z_re: &mut [f64],
+    z_im: &mut [f64],
+    w_re: &[f64],
+    w_im: &[f64],
+) {
+    struct Impl<'a, S, const R: usize, const FWD: bool> {
+        simd: S,
+        z_re: &'a mut [f64],
+        z_im: &'a mut [f64],
+        w_re: &'a [f64],
+        w_im: &'a [f64],
+    }
+
+    impl<S: Simd, const R: usize, const FWD: bool> pulp::NullaryFnOnce for Impl<'_, S, R, FWD> {
+        type Output = ();
+
+        #[inline(always)]
+        fn call(self) -> Self::Output {
+            let Self {
+                simd,
+                z_re,
+                z_im,
+                w_re,
+                w_im,
+            } = self;
+            unordered_pass_impl::<S, R>(simd, FWD, z_re, z_im, w_re, w_im);
+        }
+    }
+
+    let simd = S::get();
+    simd.vectorize(Impl::<S, R, FWD> {
+        simd,
+        z_re,
+        z_im,
+        w_re,
+        w_im,
+    });
+}
+
+fn unordered_fn_ptrs<S: SplitSimd>() -> [[PassFn; 3]; 2] {
+    [
+        [
+            unordered_pass::<S, 2, true>,
+            unordered_pass::<S, 4, true>,
+            unordered_pass::<S, 8, true>,
+        ],
+        [
+            unordered_pass::<S, 2, false>,
+            unordered_pass::<S, 4, false>,
+            unordered_pass::<S, 8, false>,
+        ],
+    ]
+}
+
+// returns the forward and inverse radix-2, 4 and 8 passes. the sections of the passes are at least
+// as large as the base size of the unordered plan, which is large enough for every instruction set
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")),
+    allow(unused_variables)
+)]
+fn get_unordered_fn_ptrs(level: SimdLevel) -> [[PassFn; 3]; 2] {
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    {
+        #[cfg(feature = "avx512")]
+        if level.v4().is_some() {
+            return unordered_fn_ptrs::<pulp::x86::V4>();
+        }
+        if level.v3().is_some() {
+            return unordered_fn_ptrs::<pulp::x86::V3>();
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if level.neon().is_some() {
+            return unordered_fn_ptrs::<pulp::aarch64::Neon>();
+        }
+    }
+
+    unordered_fn_ptrs::<pulp::Scalar>()
+}
+
+// radix of the unordered pass of size `n`
+fn unordered_radix(n: usize, base_n: usize) -> usize {
+    if n == 2 * base_n {
+        2
+    } else if n == 4 * base_n {
+        4
+    } else {
+        8
+    }
+}
+
+/// Unordered split-complex FFT of a fixed power-of-two size, with the same output order as the
+/// interleaved unordered FFT with the same base algorithm and size.
+#[derive(Clone)]
+pub(crate) struct UnorderedSplitFft {
+    base: SplitFft,
+    fwd_process: [PassFn; 3],
+    inv_process: [PassFn; 3],
+    twiddles_re: ABox<[f64]>,
+    twiddles_im: ABox<[f64]>,
+    n: usize,
+}
+
+impl UnorderedSplitFft {
+    pub fn new(n: usize, base_algo: FftAlgo, base_n: usize, simd: SimdLevel) -> Self {
+        assert!(n.is_power_of_two());
+        assert!(base_n.is_power_of_two());
+        assert!(base_n <= n);
+
+        // the twiddles of each pass, from the outermost one to the innermost one
+        let mut len = 0;
+        let mut m = n;
+        while m > base_n {
+            let r = unordered_radix(m, base_n);
+            len += (r - 1) * (m / r);
+            m /= r;
+        }
+
+        let mut twiddles_re = avec![0.0; len].into_boxed_slice();
+        let mut twiddles_im = avec![0.0; len].into_boxed_slice();
+        let mut offset = 0;
+        let mut m = n;
+        while m > base_n {
+            let r = unordered_radix(m, base_n);
+            let (w_re, w_im) = new_twiddles(r, m);
+            twiddles_re[offset..][..w_re.len()].copy_from_slice(&w_re);
+            twiddles_im[offset..][..w_im.len()].copy_from_slice(&w_im);
+            offset += w_re.len();
+            m /= r;
+        }
+
+        let [fwd_process, inv_process] = get_unordered_fn_ptrs(simd);
+        Self {
+            base: SplitFft::new(base_algo, base_n, simd),
+            fwd_process,
+            inv_process,
+            twiddles_re,
+            twiddles_im,
+            n,
+        }
+    }
+
+    pub fn fft_size(&self) -> usize {
+        self.n
+    }
+
+    pub fn base_size(&self) -> usize {
+        self.base.fft_size()
+    }
+
+    /// Forward FFT in unordered order, using `scratch_re` and `scratch_im`, of the base size, as
+    /// scratch space.
+    pub fn fwd(
+        &self,
+        re: &mut [f64],
+        im: &mut [f64],
+        scratch_re: &mut [f64],
+        scratch_im: &mut [f64],
+    ) {
+        assert_eq!(re.len(), self.n);
+        assert_eq!(im.len(), self.n);
+        self.fwd_depth(
+            re,
+            im,
+            scratch_re,
+            scratch_im,
+            &self.twiddles_re,
+            &self.twiddles_im,
+        );
+    }
+
+    /// Inverse FFT from unordered order, using `scratch_re` and `scratch_im`, of the base size, as
+    /// scratch space.
+    pub fn inv(
+        &self,
+        re: &mut [f64],
+        im: &mut [f64],
+        scratch_re: &mut [f64],
+        scratch_im: &mut [f64],
+    ) {
+        assert_eq!(re.len(), self.n);
+        assert_eq!(im.len(), self.n);
+        self.inv_depth(
+            re,
+            im,
+            scratch_re,
+            scratch_im,
+            &self.twiddles_re,
+            &self.twiddles_im,
+        );
+    }
+
+    fn fwd_depth(
+        &self,
+        re: &mut [f64],
+        im: &mut [f64],
+        scratch_re: &mut [f64],
+        scratch_im: &mut [f64],
+        w_re: &[f64],
+        w_im: &[f64],
+    ) {
+        let n = re.len();
+        let base_n = self.base.fft_size();
+        if n == base_n {
+            self.base.fwd(re, im, scratch_re, scratch_im);
+        } else {
+            let r = unordered_radix(n, base_n);
+            let m = n / r;
+            let (w_head_re, w_tail_re) = w_re.split_at((r - 1) * m);
+            let (w_head_im, w_tail_im) = w_im.split_at((r - 1) * m);
+
+            (self.fwd_process[r.trailing_zeros() as usize - 1])(re, im, w_head_re, w_head_im);
+
+            for (re, im) in izip!(re.chunks_exact_mut(m), im.chunks_exact_mut(m)) {
+                self.fwd_depth(re, im, scratch_re, scratch_im, w_tail_re, w_tail_im);
+            }
+        }
+    }
+
+    fn inv_depth(
+        &self,
+        re: &mut [f64],
+        im: &mut [f64],
+        scratch_re: &mut [f64],
+        scratch_im: &mut [f64],
+        w_re: &[f64],
+        w_im: &[f64],
+    ) {
+        let n = re.len();
+        let base_n = self.base.fft_size();
+        if n == base_n {
+            self.base.inv(re, im, scratch_re, scratch_im);
+        } else {
+            let r = unordered_radix(n, base_n);
+            let m = n / r;
+            let (w_head_re, w_tail_re) = w_re.split_at((r - 1) * m);
+            let (w_head_im, w_tail_im) = w_im.split_at((r - 1) * m);
+
+            for (re, im) in izip!(re.chunks_exact_mut(m), im.chunks_exact_mut(m)) {
+                self.inv_depth(re, im, scratch_re, scratch_im, w_tail_re, w_tail_im);
+            }
+
+            (self.inv_process[r.trailing_zeros() as usize - 1])(re, im, w_head_re, w_head_im);
+        }
+    }
+}
@@ -7,12 +7,18 @@
 //! aforementioned order, and outputs the inverse FFT in the standard order.
 
 use crate::{
-    c64,
+    c32, c64, check_power_of_two, check_simd_level,
     dif2::{split_2, split_mut_2},
-    dif4::split_mut_4,
-    dif8::split_mut_8,
-    fft_simd::{init_wt, sincospi64, FftSimd, FftSimdExt, Pod},
-    ordered::FftAlgo,
+    dif4::{split_4, split_mut_4},
+    dif8::{split_8, split_mut_8},
+    fft_simd::{
+        add_assign, init_wt, mul_add_assign, mul_assign, mul_conj_add_assign, sincospi64, Complex,
+        FftSimd, FftSimdExt, Pod,
+    },
+    ordered::{FftAlgo, FftComplex},
+    split::UnorderedSplitFft,
+    wisdom::Wisdom,
+    PlanError, SimdLevel,
 };
 use aligned_vec::{avec, ABox, CACHELINE_ALIGN};
 #[cfg(feature = "std")]
@@ -219,7 +225,7 @@ fn inv_butterfly_x8<c64xN: Pod>(
 }
 
 #[inline(always)]
-fn fwd_process_x2<c64xN: Pod>(simd: impl FftSimd<c64xN>, z: &mut [c64], w: &[c64]) {
+fn fwd_process_x2<c64xN: Pod>(simd: impl FftSimd<c64xN>, z: &mut [c64xN::Lane], w: &[c64xN::Lane]) {
     let z: &mut [c64xN] = bytemuck::cast_slice_mut(z);
     let w: &[[c64xN; 1]] = bytemuck::cast_slice(w);
     let (z0, z1) = split_mut_2(z);
@@ -230,7 +236,7 @@ fn fwd_process_x2<c64xN: Pod>(simd: impl FftSimd<c64xN>, z: &mut [c64], w: &[c64
 }
 
 #[inline(always)]
-fn inv_process_x2<c64xN: Pod>(simd: impl FftSimd<c64xN>, z: &mut [c64], w: &[c64]) {
+fn inv_process_x2<c64xN: Pod>(simd: impl FftSimd<c64xN>, z: &mut [c64xN::Lane], w: &[c64xN::Lane]) {
     let z: &mut [c64xN] = bytemuck::cast_slice_mut(z);
     let w: &[[c64xN; 1]] = bytemuck::cast_slice(w);
     let (z0, z1) = split_mut_2(z);
@@ -241,7 +247,7 @@ fn inv_process_x2<c64xN: Pod>(simd: impl FftSimd<c64xN>, z: &mut [c64], w: &[c64
 }
 
 #[inline(always)]
-fn fwd_process_x4<c64xN: Pod>(simd: impl FftSimd<c64xN>, z: &mut [c64], w: &[c64]) {
+fn fwd_process_x4<c64xN: Pod>(simd: impl FftSimd<c64xN>, z: &mut [c64xN::Lane], w: &[c64xN::Lane]) {
     let z: &mut [c64xN] = bytemuck::cast_slice_mut(z);
     let w: &[[c64xN; 3]] = bytemuck::cast_slice(w);
     let (z0, z1, z2, z3) = split_mut_4(z);
@@ -252,7 +258,7 @@ fn fwd_process_x4<c64xN: Pod>(simd: impl FftSimd<c64xN>, z: &mut [c64], w: &[c64
 }
 
 #[inline(always)]
-fn inv_process_x4<c64xN: Pod>(simd: impl FftSimd<c64xN>, z: &mut [c64], w: &[c64]) {
+fn inv_process_x4<c64xN: Pod>(simd: impl FftSimd<c64xN>, z: &mut [c64xN::Lane], w: &[c64xN::Lane]) {
     let z: &mut [c64xN] = bytemuck::cast_slice_mut(z);
     let w: &[[c64xN; 3]] = bytemuck::cast_slice(w);
     let (z0, z1, z2, z3) = split_mut_4(z);
@@ -263,7 +269,7 @@ fn inv_process_x4<c64xN: Pod>(simd: impl FftSimd<c64xN>, z: &mut [c64], w: &[c64
 }
 
 #[inline(always)]
-fn fwd_process_x8<c64xN: Pod>(simd: impl FftSimd<c64xN>, z: &mut [c64], w: &[c64]) {
+fn fwd_process_x8<c64xN: Pod>(simd: impl FftSimd<c64xN>, z: &mut [c64xN::Lane], w: &[c64xN::Lane]) {
     let z: &mut [c64xN] = bytemuck::cast_slice_mut(z);
     let w: &[[c64xN; 7]] = bytemuck::cast_slice(w);
     let (z0, z1, z2, z3, z4, z5, z6, z7) = split_mut_8(z);
@@ -278,7 +284,7 @@ fn fwd_process_x8<c64xN: Pod>(simd: impl FftSimd<c64xN>, z: &mut [c64], w: &[c64
 }
 
 #[inline(always)]
-fn inv_process_x8<c64xN: Pod>(simd: impl FftSimd<c64xN>, z: &mut [c64], w: &[c64]) {
+fn inv_process_x8<c64xN: Pod>(simd: impl FftSimd<c64xN>, z: &mut [c64xN::Lane], w: &[c64xN::Lane]) {
     let z: &mut [c64xN] = bytemuck::cast_slice_mut(z);
     let w: &[[c64xN; 7]] = bytemuck::cast_slice(w);
     let (z0, z1, z2, z3, z4, z5, z6, z7) = split_mut_8(z);
@@ -292,36 +298,239 @@ fn inv_process_x8<c64xN: Pod>(simd: impl FftSimd<c64xN>, z: &mut [c64], w: &[c64
     }
 }
 
-macro_rules! dispatcher {
-    ($name: ident, $impl: ident) => {
-        fn $name() -> fn(&mut [c64], &[c64]) {
-            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-            {
-                #[cfg(feature = "nightly")]
-                if pulp::x86::V4::try_new().is_some() {
-                    return |z, w| {
-                        let simd = pulp::x86::V4::try_new().unwrap();
-                        simd.vectorize(
-                            #[inline(always)]
-                            || $impl(simd, z, w),
-                        );
-                    };
-                }
+// the out-of-place passes read their input from `x` instead of `z`, and are used as the first pass
+// of the out-of-place forward transform
 
-                if pulp::x86::V3::try_new().is_some() {
-                    return |z, w| {
-                        let simd = pulp::x86::V3::try_new().unwrap();
-                        simd.vectorize(
-                            #[inline(always)]
-                            || $impl(simd, z, w),
-                        );
-                    };
-                }
+#[inline(always)]
+fn fwd_process_x2_out_of_place<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    z: &mut [c64xN::Lane],
+    x: &[c64xN::Lane],
+    w: &[c64xN::Lane],
+) {
+    let z: &mut [c64xN] = bytemuck::cast_slice_mut(z);
+    let x: &[c64xN] = bytemuck::cast_slice(x);
+    let w: &[[c64xN; 1]] = bytemuck::cast_slice(w);
+    let (z0, z1) = split_mut_2(z);
+    let (x0, x1) = split_2(x);
+
+    for (z0, z1, x0, x1, &[w1]) in izip!(z0, z1, x0, x1, w) {
+        (*z0, *z1) = fwd_butterfly_x2(simd, *x0, *x1, w1);
+    }
+}
+
+#[inline(always)]
+fn fwd_process_x4_out_of_place<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    z: &mut [c64xN::Lane],
+    x: &[c64xN::Lane],
+    w: &[c64xN::Lane],
+) {
+    let z: &mut [c64xN] = bytemuck::cast_slice_mut(z);
+    let x: &[c64xN] = bytemuck::cast_slice(x);
+    let w: &[[c64xN; 3]] = bytemuck::cast_slice(w);
+    let (z0, z1, z2, z3) = split_mut_4(z);
+    let (x0, x1, x2, x3) = split_4(x);
+
+    for (z0, z1, z2, z3, x0, x1, x2, x3, &[w1, w2, w3]) in izip!(z0, z1, z2, z3, x0, x1, x2, x3, w)
+    {
+        (*z0, *z2, *z1, *z3) = fwd_butterfly_x4(simd, *x0, *x1, *x2, *x3, w1, w2, w3);
+    }
+}
+
+#[inline(always)]
+fn fwd_process_x8_out_of_place<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    z: &mut [c64xN::Lane],
+    x: &[c64xN::Lane],
+    w: &[c64xN::Lane],
+) {
+    let z: &mut [c64xN] = bytemuck::cast_slice_mut(z);
+    let x: &[c64xN] = bytemuck::cast_slice(x);
+    let w: &[[c64xN; 7]] = bytemuck::cast_slice(w);
+    let (z0, z1, z2, z3, z4, z5, z6, z7) = split_mut_8(z);
+    let (x0, x1, x2, x3, x4, x5, x6, x7) = split_8(x);
+
+    for (
+        (z0, z1, z2, z3, z4, z5, z6, z7),
+        (x0, x1, x2, x3, x4, x5, x6, x7),
+        &[w1, w2, w3, w4, w5, w6, w7],
+    ) in izip!(
+        izip!(z0, z1, z2, z3, z4, z5, z6, z7),
+        izip!(x0, x1, x2, x3, x4, x5, x6, x7),
+        w,
+    ) {
+        (*z0, *z4, *z2, *z6, *z1, *z5, *z3, *z7) = fwd_butterfly_x8(
+            simd, *x0, *x1, *x2, *x3, *x4, *x5, *x6, *x7, w1, w2, w3, w4, w5, w6, w7,
+        );
+    }
+}
+
+// the batched passes take a buffer containing several consecutive FFTs of the same size, and
+// apply the pass to all of them one tile of twiddles at a time. each tile stays in the L1 cache
+// while it is applied to every FFT, and the butterflies of each FFT are computed on contiguous
+// registers
+
+#[inline(always)]
+fn fwd_process_x2_batch<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    z: &mut [c64xN::Lane],
+    w: &[c64xN::Lane],
+) {
+    let z: &mut [c64xN] = bytemuck::cast_slice_mut(z);
+    let w: &[[c64xN; 1]] = bytemuck::cast_slice(w);
+    let m = w.len();
+    let tile = BATCH_TILE_SIZE / simd.lane_count();
+
+    for (start, w) in (0..m).step_by(tile).zip(w.chunks(tile)) {
+        for z in z.chunks_exact_mut(2 * m) {
+            let (z0, z1) = split_mut_2(z);
+            for (z0, z1, &[w1]) in izip!(&mut z0[start..], &mut z1[start..], w) {
+                (*z0, *z1) = fwd_butterfly_x2(simd, *z0, *z1, w1);
+            }
+        }
+    }
+}
+
+#[inline(always)]
+fn inv_process_x2_batch<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    z: &mut [c64xN::Lane],
+    w: &[c64xN::Lane],
+) {
+    let z: &mut [c64xN] = bytemuck::cast_slice_mut(z);
+    let w: &[[c64xN; 1]] = bytemuck::cast_slice(w);
+    let m = w.len();
+    let tile = BATCH_TILE_SIZE / simd.lane_count();
+
+    for (start, w) in (0..m).step_by(tile).zip(w.chunks(tile)) {
+        for z in z.chunks_exact_mut(2 * m) {
+            let (z0, z1) = split_mut_2(z);
+            for (z0, z1, &[w1]) in izip!(&mut z0[start..], &mut z1[start..], w) {
+                (*z0, *z1) = inv_butterfly_x2(simd, *z0, *z1, w1);
             }
+        }
+    }
+}
 
-            |z, w| $impl(crate::fft_simd::Scalar, z, w)
+#[inline(always)]
+fn fwd_process_x4_batch<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    z: &mut [c64xN::Lane],
+    w: &[c64xN::Lane],
+) {
+    let z: &mut [c64xN] = bytemuck::cast_slice_mut(z);
+    let w: &[[c64xN; 3]] = bytemuck::cast_slice(w);
+    let m = w.len();
+    let tile = BATCH_TILE_SIZE / simd.lane_count();
+
+    for (start, w) in (0..m).step_by(tile).zip(w.chunks(tile)) {
+        for z in z.chunks_exact_mut(4 * m) {
+            let (z0, z1, z2, z3) = split_mut_4(z);
+            for (z0, z1, z2, z3, &[w1, w2, w3]) in izip!(
+                &mut z0[start..],
+                &mut z1[start..],
+                &mut z2[start..],
+                &mut z3[start..],
+                w,
+            ) {
+                (*z0, *z2, *z1, *z3) = fwd_butterfly_x4(simd, *z0, *z1, *z2, *z3, w1, w2, w3);
+            }
         }
-    };
+    }
+}
+
+#[inline(always)]
+fn inv_process_x4_batch<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    z: &mut [c64xN::Lane],
+    w: &[c64xN::Lane],
+) {
+    let z: &mut [c64xN] = bytemuck::cast_slice_mut(z);
+    let w: &[[c64xN; 3]] = bytemuck::cast_slice(w);
+    let m = w.len();
+    let tile = BATCH_TILE_SIZE / simd.lane_count();
+
+    for (start, w) in (0..m).step_by(tile).zip(w.chunks(tile)) {
+        for z in z.chunks_exact_mut(4 * m) {
+            let (z0, z1, z2, z3) = split_mut_4(z);
+            for (z0, z1, z2, z3, &[w1, w2, w3]) in izip!(
+                &mut z0[start..],
+                &mut z1[start..],
+                &mut z2[start..],
+                &mut z3[start..],
+                w,
+            ) {
+                (*z0, *z1, *z2, *z3) = inv_butterfly_x4(simd, *z0, *z2, *z1, *z3, w1, w2, w3);
+            }
+        }
+    }
+}
+
+#[inline(always)]
+fn fwd_process_x8_batch<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    z: &mut [c64xN::Lane],
+    w: &[c64xN::Lane],
+) {
+    let z: &mut [c64xN] = bytemuck::cast_slice_mut(z);
+    let w: &[[c64xN; 7]] = bytemuck::cast_slice(w);
+    let m = w.len();
+    let tile = BATCH_TILE_SIZE / simd.lane_count();
+
+    for (start, w) in (0..m).step_by(tile).zip(w.chunks(tile)) {
+        for z in z.chunks_exact_mut(8 * m) {
+            let (z0, z1, z2, z3, z4, z5, z6, z7) = split_mut_8(z);
+            for (z0, z1, z2, z3, z4, z5, z6, z7, &[w1, w2, w3, w4, w5, w6, w7]) in izip!(
+                &mut z0[start..],
+                &mut z1[start..],
+                &mut z2[start..],
+                &mut z3[start..],
+                &mut z4[start..],
+                &mut z5[start..],
+                &mut z6[start..],
+                &mut z7[start..],
+                w,
+            ) {
+                (*z0, *z4, *z2, *z6, *z1, *z5, *z3, *z7) = fwd_butterfly_x8(
+                    simd, *z0, *z1, *z2, *z3, *z4, *z5, *z6, *z7, w1, w2, w3, w4, w5, w6, w7,
+                );
+            }
+        }
+    }
+}
+
+#[inline(always)]
+fn inv_process_x8_batch<c64xN: Pod>(
+    simd: impl FftSimd<c64xN>,
+    z: &mut [c64xN::Lane],
+    w: &[c64xN::Lane],
+) {
+    let z: &mut [c64xN] = bytemuck::cast_slice_mut(z);
+    let w: &[[c64xN; 7]] = bytemuck::cast_slice(w);
+    let m = w.len();
+    let tile = BATCH_TILE_SIZE / simd.lane_count();
+
+    for (start, w) in (0..m).step_by(tile).zip(w.chunks(tile)) {
+        for z in z.chunks_exact_mut(8 * m) {
+            let (z0, z1, z2, z3, z4, z5, z6, z7) = split_mut_8(z);
+            for (z0, z1, z2, z3, z4, z5, z6, z7, &[w1, w2, w3, w4, w5, w6, w7]) in izip!(
+                &mut z0[start..],
+                &mut z1[start..],
+                &mut z2[start..],
+                &mut z3[start..],
+                &mut z4[start..],
+                &mut z5[start..],
+                &mut z6[start..],
+                &mut z7[start..],
+                w,
+            ) {
+                (*z0, *z1, *z2, *z3, *z4, *z5, *z6, *z7) = inv_butterfly_x8(
+                    simd, *z0, *z4, *z2, *z6, *z1, *z5, *z3, *z7, w1, w2, w3, w4, w5, w6, w7,
+                );
+            }
+        }
+    }
 }
 
 dispatcher!(get_fwd_process_x2, fwd_process_x2);
@@ -332,27 +541,147 @@ dispatcher!(get_inv_process_x2, inv_process_x2);
 dispatcher!(get_inv_process_x4, inv_process_x4);
 dispatcher!(get_inv_process_x8, inv_process_x8);
 
-fn get_complex_per_reg() -> usize {
+dispatcher!(
+    acc,
+    get_fwd_process_x2_out_of_place,
+    fwd_process_x2_out_of_place
+);
+dispatcher!(
+    acc,
+    get_fwd_process_x4_out_of_place,
+    fwd_process_x4_out_of_place
+);
+dispatcher!(
+    acc,
+    get_fwd_process_x8_out_of_place,
+    fwd_process_x8_out_of_place
+);
+
+dispatcher!(get_fwd_process_x2_batch, fwd_process_x2_batch);
+dispatcher!(get_fwd_process_x4_batch, fwd_process_x4_batch);
+dispatcher!(get_fwd_process_x8_batch, fwd_process_x8_batch);
+
+dispatcher!(get_inv_process_x2_batch, inv_process_x2_batch);
+dispatcher!(get_inv_process_x4_batch, inv_process_x4_batch);
+dispatcher!(get_inv_process_x8_batch, inv_process_x8_batch);
+
+dispatcher!(get_mul_assign, mul_assign);
+dispatcher!(get_add_assign, add_assign);
+dispatcher!(acc, get_mul_add_assign, mul_add_assign);
+dispatcher!(acc, get_mul_conj_add_assign, mul_conj_add_assign);
+
+dispatcher!(c32, get_fwd_process_x2_c32, fwd_process_x2);
+dispatcher!(c32, get_fwd_process_x4_c32, fwd_process_x4);
+dispatcher!(c32, get_fwd_process_x8_c32, fwd_process_x8);
+
+dispatcher!(c32, get_inv_process_x2_c32, inv_process_x2);
+dispatcher!(c32, get_inv_process_x4_c32, inv_process_x4);
+dispatcher!(c32, get_inv_process_x8_c32, inv_process_x8);
+
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")),
+    allow(unused_variables)
+)]
+fn get_complex_per_reg(level: SimdLevel) -> usize {
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    {
+        #[cfg(feature = "avx512")]
+        if let Some(simd) = level.v4() {
+            return FftSimd::<crate::fft_simd::c64x4>::lane_count(simd);
+        }
+        if let Some(simd) = level.v3() {
+            return FftSimd::<crate::fft_simd::c64x2>::lane_count(simd);
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if let Some(simd) = level.neon() {
+            return FftSimd::<crate::fft_simd::c64x1>::lane_count(simd);
+        }
+    }
+    FftSimd::<c64>::lane_count(crate::fft_simd::Scalar)
+}
+
+#[cfg_attr(
+    not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")),
+    allow(unused_variables)
+)]
+fn get_complex_per_reg_c32(level: SimdLevel) -> usize {
     #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
     {
-        #[cfg(feature = "nightly")]
-        if let Some(simd) = pulp::x86::V4::try_new() {
-            return simd.lane_count();
+        #[cfg(feature = "avx512")]
+        if let Some(simd) = level.v4() {
+            return FftSimd::<crate::fft_simd::c32x8>::lane_count(simd);
         }
-        if let Some(simd) = pulp::x86::V3::try_new() {
-            return simd.lane_count();
+        if let Some(simd) = level.v3() {
+            return FftSimd::<crate::fft_simd::c32x4>::lane_count(simd);
         }
     }
-    crate::fft_simd::Scalar.lane_count()
+    #[cfg(target_arch = "aarch64")]
+    {
+        if let Some(simd) = level.neon() {
+            return FftSimd::<crate::fft_simd::c32x2>::lane_count(simd);
+        }
+    }
+    FftSimd::<c32>::lane_count(crate::fft_simd::Scalar)
+}
+
+/// Complex type on which the unordered FFT can be computed, either [`c64`] or [`c32`].
+pub(crate) trait UnorderedFftComplex: FftComplex {
+    /// Returns the forward radix-2, 4 and 8 passes.
+    fn get_fwd_process(simd: SimdLevel) -> [fn(&mut [Self], &[Self]); 3];
+    /// Returns the inverse radix-2, 4 and 8 passes.
+    fn get_inv_process(simd: SimdLevel) -> [fn(&mut [Self], &[Self]); 3];
+    fn get_complex_per_reg(simd: SimdLevel) -> usize;
+}
+
+impl UnorderedFftComplex for c64 {
+    fn get_fwd_process(simd: SimdLevel) -> [fn(&mut [Self], &[Self]); 3] {
+        [
+            get_fwd_process_x2(simd),
+            get_fwd_process_x4(simd),
+            get_fwd_process_x8(simd),
+        ]
+    }
+    fn get_inv_process(simd: SimdLevel) -> [fn(&mut [Self], &[Self]); 3] {
+        [
+            get_inv_process_x2(simd),
+            get_inv_process_x4(simd),
+            get_inv_process_x8(simd),
+        ]
+    }
+    fn get_complex_per_reg(simd: SimdLevel) -> usize {
+        get_complex_per_reg(simd)
+    }
+}
+
+impl UnorderedFftComplex for c32 {
+    fn get_fwd_process(simd: SimdLevel) -> [fn(&mut [Self], &[Self]); 3] {
+        [
+            get_fwd_process_x2_c32(simd),
+            get_fwd_process_x4_c32(simd),
+            get_fwd_process_x8_c32(simd),
+        ]
+    }
+    fn get_inv_process(simd: SimdLevel) -> [fn(&mut [Self], &[Self]); 3] {
+        [
+            get_inv_process_x2_c32(simd),
+            get_inv_process_x4_c32(simd),
+            get_inv_process_x8_c32(simd),
+        ]
+    }
+    fn get_complex_per_reg(simd: SimdLevel) -> usize {
+        get_complex_per_reg_c32(simd)
+    }
 }
 
-fn init_twiddles(
+fn init_twiddles<T: Complex>(
     n: usize,
     complex_per_reg: usize,
     base_n: usize,
     base_r: usize,
-    w: &mut [c64],
-    w_inv: &mut [c64],
+    w: &mut [T],
+    w_inv: &mut [T],
 ) {
     let theta = 2.0 / n as f64;
     if n <= base_n {
@@ -376,8 +705,8 @@ fn init_twiddles(
                 for k in 1..r {
                     let (sk, ck) = sincospi64(theta * (k * (p + i)) as f64);
                     let idx = (r - 1) * p + (k - 1) * complex_per_reg + i;
-                    w[idx] = c64 { re: ck, im: -sk };
-                    w_inv[idx] = c64 { re: ck, im: sk };
+                    w[idx] = T::from_c64(c64 { re: ck, im: -sk });
+                    w_inv[idx] = T::from_c64(c64 { re: ck, im: sk });
                 }
             }
 
@@ -389,20 +718,20 @@ fn init_twiddles(
 }
 
 #[inline(never)]
-fn fwd_depth(
-    z: &mut [c64],
-    w: &[c64],
-    base_fn: fn(&mut [c64], &mut [c64], &[c64], &[c64]),
+fn fwd_depth<T>(
+    z: &mut [T],
+    w: &[T],
+    base_fn: fn(Option<&[T]>, &mut [T], &mut [T], &[T], &[T]),
     base_n: usize,
-    base_scratch: &mut [c64],
-    fwd_process_x2: fn(&mut [c64], &[c64]),
-    fwd_process_x4: fn(&mut [c64], &[c64]),
-    fwd_process_x8: fn(&mut [c64], &[c64]),
+    base_scratch: &mut [T],
+    fwd_process_x2: fn(&mut [T], &[T]),
+    fwd_process_x4: fn(&mut [T], &[T]),
+    fwd_process_x8: fn(&mut [T], &[T]),
 ) {
     let n = z.len();
     if n == base_n {
         let (w_init, w) = split_2(w);
-        base_fn(z, base_scratch, w_init, w);
+        base_fn(None, z, base_scratch, w_init, w);
     } else {
         let r = if n == 2 * base_n {
             2
@@ -439,21 +768,21 @@ fn fwd_depth(
 }
 
 #[inline(never)]
-fn inv_depth(
-    z: &mut [c64],
-    w: &[c64],
-    base_fn: fn(&mut [c64], &mut [c64], &[c64], &[c64]),
+fn inv_depth<T>(
+    z: &mut [T],
+    w: &[T],
+    base_fn: fn(Option<&[T]>, &mut [T], &mut [T], &[T], &[T]),
     base_n: usize,
-    base_scratch: &mut [c64],
-    inv_process_x2: fn(&mut [c64], &[c64]),
-    inv_process_x4: fn(&mut [c64], &[c64]),
-    inv_process_x8: fn(&mut [c64], &[c64]),
+    base_scratch: &mut [T],
+    inv_process_x2: fn(&mut [T], &[T]),
+    inv_process_x4: fn(&mut [T], &[T]),
+    inv_process_x8: fn(&mut [T], &[T]),
 ) {
     let n = z.len();
 
     if n == base_n {
         let (w_init, w) = split_2(w);
-        base_fn(z, base_scratch, w_init, w);
+        base_fn(None, z, base_scratch, w_init, w);
     } else {
         let r = if n == 2 * base_n {
             2
@@ -488,287 +817,2121 @@ fn inv_depth(
     }
 }
 
-/// Unordered FFT plan.
-///
-/// This type holds a forward and inverse FFT plan and twiddling factors for a specific size.
-/// The size must be a power of two.
-#[derive(Clone)]
-pub struct Plan {
-    monomial_twiddles: ABox<[c64]>,
-    indices: ABox<[usize]>,
-    twiddles: ABox<[c64]>,
-    twiddles_inv: ABox<[c64]>,
-    fwd_process_x2: fn(&mut [c64], &[c64]),
-    fwd_process_x4: fn(&mut [c64], &[c64]),
-    fwd_process_x8: fn(&mut [c64], &[c64]),
-    inv_process_x2: fn(&mut [c64], &[c64]),
-    inv_process_x4: fn(&mut [c64], &[c64]),
-    inv_process_x8: fn(&mut [c64], &[c64]),
+/// Same as [`fwd_depth`], but the input is read from `src` instead of `z`. `src` is only read by
+/// the first pass, which writes to `z`, and the remaining passes are computed in place.
+#[inline(never)]
+fn fwd_depth_out_of_place<T>(
+    src: &[T],
+    z: &mut [T],
+    w: &[T],
+    base_fn: fn(Option<&[T]>, &mut [T], &mut [T], &[T], &[T]),
     base_n: usize,
-    base_fn_fwd: fn(&mut [c64], &mut [c64], &[c64], &[c64]),
-    base_fn_inv: fn(&mut [c64], &mut [c64], &[c64], &[c64]),
-    base_algo: FftAlgo,
-    n: usize,
-}
+    base_scratch: &mut [T],
+    fwd_process_x2: fn(&mut [T], &[T]),
+    fwd_process_x4: fn(&mut [T], &[T]),
+    fwd_process_x8: fn(&mut [T], &[T]),
+    fwd_process_out_of_place: [fn(&mut [T], &[T], &[T]); 3],
+) {
+    let n = z.len();
+    assert_eq!(src.len(), n);
+    if n == base_n {
+        let (w_init, w) = split_2(w);
+        base_fn(Some(src), z, base_scratch, w_init, w);
+    } else {
+        let r = if n == 2 * base_n {
+            2
+        } else if n == 4 * base_n {
+            4
+        } else {
+            8
+        };
 
-impl core::fmt::Debug for Plan {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.debug_struct("Plan")
-            .field("base_algo", &self.base_algo)
-            .field("base_size", &self.base_n)
-            .field("fft_size", &self.fft_size())
-            .finish()
-    }
-}
+        let m = n / r;
+        let (w_head, w_tail) = w.split_at((r - 1) * m);
 
-/// Method for selecting the unordered FFT plan.
-#[derive(Clone, Copy, Debug)]
-pub enum Method {
-    /// Select the FFT plan by manually providing the underlying algorithm.
-    /// The unordered FFT works by using an internal ordered FFT plan, whose size and algorithm can
-    /// be specified by the user.
-    UserProvided { base_algo: FftAlgo, base_n: usize },
-    /// Select the FFT plan by measuring the running time of all the possible plans and selecting
-    /// the fastest one. The provided duration specifies how long the benchmark of each plan should
-    /// last.
-    #[cfg(feature = "std")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-    Measure(Duration),
-}
+        let [fwd_process_x2_out_of_place, fwd_process_x4_out_of_place, fwd_process_x8_out_of_place] =
+            fwd_process_out_of_place;
+        if r == 2 {
+            fwd_process_x2_out_of_place(z, src, w_head);
+        } else if r == 4 {
+            fwd_process_x4_out_of_place(z, src, w_head);
+        } else {
+            fwd_process_x8_out_of_place(z, src, w_head);
+        }
 
-#[cfg(feature = "std")]
-fn measure_fastest_scratch(n: usize) -> StackReq {
-    if n <= 512 {
-        crate::ordered::measure_fastest_scratch(n)
-    } else {
-        let base_n = 4096;
-        crate::ordered::measure_fastest_scratch(base_n)
-            .and(StackReq::new_aligned::<c64>(n + base_n, CACHELINE_ALIGN)) // twiddles
-            .and(StackReq::new_aligned::<c64>(n, CACHELINE_ALIGN)) // buf
-            .and(StackReq::new_aligned::<c64>(base_n, CACHELINE_ALIGN)) // scratch
+        for z in z.chunks_exact_mut(m) {
+            fwd_depth(
+                z,
+                w_tail,
+                base_fn,
+                base_n,
+                base_scratch,
+                fwd_process_x2,
+                fwd_process_x4,
+                fwd_process_x8,
+            );
+        }
     }
 }
 
-#[cfg(feature = "std")]
-fn measure_fastest(
-    mut min_bench_duration_per_algo: Duration,
-    n: usize,
-    mut stack: PodStack,
-) -> (FftAlgo, usize, Duration) {
-    const MIN_DURATION: Duration = Duration::from_millis(1);
-    min_bench_duration_per_algo = min_bench_duration_per_algo.max(MIN_DURATION);
-
-    if n <= 256 {
-        let (algo, duration) =
-            crate::ordered::measure_fastest(min_bench_duration_per_algo, n, stack);
-        (algo, n, duration)
+/// Same as [`inv_depth`], but the input is read from `src` instead of `z`. `src` is only read by
+/// the base FFTs, which write to `z`, and the remaining passes are computed in place.
+#[inline(never)]
+fn inv_depth_out_of_place<T>(
+    src: &[T],
+    z: &mut [T],
+    w: &[T],
+    base_fn: fn(Option<&[T]>, &mut [T], &mut [T], &[T], &[T]),
+    base_n: usize,
+    base_scratch: &mut [T],
+    inv_process_x2: fn(&mut [T], &[T]),
+    inv_process_x4: fn(&mut [T], &[T]),
+    inv_process_x8: fn(&mut [T], &[T]),
+) {
+    let n = z.len();
+    assert_eq!(src.len(), n);
+    if n == base_n {
+        let (w_init, w) = split_2(w);
+        base_fn(Some(src), z, base_scratch, w_init, w);
     } else {
-        // bench
-
-        let bases = [512, 1024];
-        let mut algos: [Option<FftAlgo>; 4] = [None; 4];
-        let mut avg_durations: [Option<Duration>; 4] = [None; 4];
-        let fwd_process_x2 = get_fwd_process_x2();
-        let fwd_process_x4 = get_fwd_process_x4();
-        let fwd_process_x8 = get_fwd_process_x8();
-
-        let mut n_algos = 0;
-        for (i, base_n) in bases.into_iter().enumerate() {
-            if n < base_n {
-                break;
-            }
-
-            n_algos += 1;
+        let r = if n == 2 * base_n {
+            2
+        } else if n == 4 * base_n {
+            4
+        } else {
+            8
+        };
 
-            // we'll measure the corresponding plan
-            let (base_algo, duration) = crate::ordered::measure_fastest(
-                min_bench_duration_per_algo,
+        let m = n / r;
+        let (w_head, w_tail) = w.split_at(w.len() - (r - 1) * m);
+        for (src, z) in src.chunks_exact(m).zip(z.chunks_exact_mut(m)) {
+            inv_depth_out_of_place(
+                src,
+                z,
+                w_head,
+                base_fn,
                 base_n,
-                stack.rb_mut(),
+                base_scratch,
+                inv_process_x2,
+                inv_process_x4,
+                inv_process_x8,
             );
+        }
 
-            algos[i] = Some(base_algo);
-
-            if n == base_n {
-                avg_durations[i] = Some(duration);
-                continue;
-            }
+        if r == 2 {
+            inv_process_x2(z, w_tail);
+        } else if r == 4 {
+            inv_process_x4(z, w_tail);
+        } else {
+            inv_process_x8(z, w_tail);
+        }
+    }
+}
 
-            // get the forward base algo
-            let base_fn = crate::ordered::get_fn_ptr(base_algo, base_n)[0];
+/// Number of complex numbers that are processed together by the batched transforms, chosen so
+/// that they fit in the L2 cache.
+const BATCH_BLOCK_SIZE: usize = 1 << 14;
 
-            let f = |_| c64 { re: 0.0, im: 0.0 };
-            let align = CACHELINE_ALIGN;
-            let (w, stack) = stack
-                .rb_mut()
-                .make_aligned_with::<c64, _>(n + base_n, align, f);
-            let (scratch, stack) = stack.make_aligned_with::<c64, _>(base_n, align, f);
-            let (z, _) = stack.make_aligned_with::<c64, _>(n, align, f);
+/// FFT size up to which the batched transforms process each vector on its own, since a vector of
+/// that size fits in the L1 cache together with its twiddles.
+const BATCH_DEPTH_SIZE: usize = 1 << 10;
 
-            let n_runs = min_bench_duration_per_algo.as_secs_f64()
-                / (duration.as_secs_f64() * (n / base_n) as f64);
+/// Number of twiddles that are applied to all the vectors of a batch before moving on to the next
+/// ones.
+const BATCH_TILE_SIZE: usize = 64;
 
-            let n_runs = n_runs.ceil() as u32;
+/// Performs the same computation as [`fwd_depth`] on each of the consecutive FFTs of size `n` that
+/// are contained in `z`. The radix passes are applied to all the FFTs at once, until their size is
+/// small enough for the remaining passes to be computed one FFT at a time.
+#[inline(never)]
+fn fwd_breadth<T>(
+    z: &mut [T],
+    n: usize,
+    w: &[T],
+    base_fn: fn(Option<&[T]>, &mut [T], &mut [T], &[T], &[T]),
+    base_n: usize,
+    base_scratch: &mut [T],
+    fwd_process: [fn(&mut [T], &[T]); 3],
+    fwd_process_batch: [fn(&mut [T], &[T]); 3],
+) {
+    if n == base_n {
+        let (w_init, w) = split_2(w);
+        for z in z.chunks_exact_mut(n) {
+            base_fn(None, z, base_scratch, w_init, w);
+        }
+    } else if n <= BATCH_DEPTH_SIZE {
+        let [fwd_process_x2, fwd_process_x4, fwd_process_x8] = fwd_process;
+        for z in z.chunks_exact_mut(n) {
+            fwd_depth(
+                z,
+                w,
+                base_fn,
+                base_n,
+                base_scratch,
+                fwd_process_x2,
+                fwd_process_x4,
+                fwd_process_x8,
+            );
+        }
+    } else {
+        let r = if n == 2 * base_n {
+            2
+        } else if n == 4 * base_n {
+            4
+        } else {
+            8
+        };
 
-            // For wasm we have a dedicated implementation going through js-sys
-            use crate::time::Instant;
-            let now = Instant::now();
-            for _ in 0..n_runs {
-                fwd_depth(
+        let m = n / r;
+        let (w_head, w_tail) = w.split_at((r - 1) * m);
+
+        let [fwd_process_x2, fwd_process_x4, fwd_process_x8] = fwd_process_batch;
+        if r == 2 {
+            fwd_process_x2(z, w_head);
+        } else if r == 4 {
+            fwd_process_x4(z, w_head);
+        } else {
+            fwd_process_x8(z, w_head);
+        }
+
+        // each FFT of size `n` is now made up of `r` consecutive FFTs of size `m`
+        fwd_breadth(
+            z,
+            m,
+            w_tail,
+            base_fn,
+            base_n,
+            base_scratch,
+            fwd_process,
+            fwd_process_batch,
+        );
+    }
+}
+
+/// Performs the same computation as [`inv_depth`] on each of the consecutive FFTs of size `n` that
+/// are contained in `z`. The radix passes are applied to all the FFTs at once, once their halves
+/// have been computed one FFT at a time.
+#[inline(never)]
+fn inv_breadth<T>(
+    z: &mut [T],
+    n: usize,
+    w: &[T],
+    base_fn: fn(Option<&[T]>, &mut [T], &mut [T], &[T], &[T]),
+    base_n: usize,
+    base_scratch: &mut [T],
+    inv_process: [fn(&mut [T], &[T]); 3],
+    inv_process_batch: [fn(&mut [T], &[T]); 3],
+) {
+    if n == base_n {
+        let (w_init, w) = split_2(w);
+        for z in z.chunks_exact_mut(n) {
+            base_fn(None, z, base_scratch, w_init, w);
+        }
+    } else if n <= BATCH_DEPTH_SIZE {
+        let [inv_process_x2, inv_process_x4, inv_process_x8] = inv_process;
+        for z in z.chunks_exact_mut(n) {
+            inv_depth(
+                z,
+                w,
+                base_fn,
+                base_n,
+                base_scratch,
+                inv_process_x2,
+                inv_process_x4,
+                inv_process_x8,
+            );
+        }
+    } else {
+        let r = if n == 2 * base_n {
+            2
+        } else if n == 4 * base_n {
+            4
+        } else {
+            8
+        };
+
+        let m = n / r;
+        let (w_head, w_tail) = w.split_at(w.len() - (r - 1) * m);
+        inv_breadth(
+            z,
+            m,
+            w_head,
+            base_fn,
+            base_n,
+            base_scratch,
+            inv_process,
+            inv_process_batch,
+        );
+
+        let [inv_process_x2, inv_process_x4, inv_process_x8] = inv_process_batch;
+        if r == 2 {
+            inv_process_x2(z, w_tail);
+        } else if r == 4 {
+            inv_process_x4(z, w_tail);
+        } else {
+            inv_process_x8(z, w_tail);
+        }
+    }
+}
+
+/// Default FFT size starting from which the unordered plan dispatches its work to multiple
+/// threads.
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub const DEFAULT_PARALLEL_THRESHOLD: usize = 1 << 16;
+
+/// Returns the number of subproblems of [`fwd_depth_parallel`] and [`inv_depth_parallel`] that can
+/// be processed concurrently, each of which needs its own base scratch.
+#[cfg(feature = "rayon")]
+fn parallel_scratch_count(n: usize, base_n: usize, parallel_threshold: usize) -> usize {
+    let mut n = n;
+    let mut count = 1;
+    while n != base_n && n >= parallel_threshold {
+        let r = if n == 2 * base_n {
+            2
+        } else if n == 4 * base_n {
+            4
+        } else {
+            8
+        };
+        n /= r;
+        count *= r;
+    }
+    count
+}
+
+/// Same as [`fwd_depth`], but the subproblems of size greater than or equal to
+/// `parallel_threshold` are processed in parallel. `base_scratch` must contain
+/// `parallel_scratch_count(n, base_n, parallel_threshold)` scratch buffers of size `base_n`.
+#[cfg(feature = "rayon")]
+fn fwd_depth_parallel<T: Send + Sync>(
+    z: &mut [T],
+    w: &[T],
+    base_fn: fn(Option<&[T]>, &mut [T], &mut [T], &[T], &[T]),
+    base_n: usize,
+    base_scratch: &mut [T],
+    fwd_process_x2: fn(&mut [T], &[T]),
+    fwd_process_x4: fn(&mut [T], &[T]),
+    fwd_process_x8: fn(&mut [T], &[T]),
+    parallel_threshold: usize,
+) {
+    use rayon::prelude::*;
+
+    let n = z.len();
+    if n == base_n || n < parallel_threshold {
+        fwd_depth(
+            z,
+            w,
+            base_fn,
+            base_n,
+            &mut base_scratch[..base_n],
+            fwd_process_x2,
+            fwd_process_x4,
+            fwd_process_x8,
+        );
+    } else {
+        let r = if n == 2 * base_n {
+            2
+        } else if n == 4 * base_n {
+            4
+        } else {
+            8
+        };
+
+        let m = n / r;
+        let (w_head, w_tail) = w.split_at((r - 1) * m);
+
+        if r == 2 {
+            fwd_process_x2(z, w_head);
+        } else if r == 4 {
+            fwd_process_x4(z, w_head);
+        } else {
+            fwd_process_x8(z, w_head);
+        }
+
+        let scratch_len = base_scratch.len() / r;
+        z.par_chunks_exact_mut(m)
+            .zip(base_scratch.par_chunks_exact_mut(scratch_len))
+            .for_each(|(z, base_scratch)| {
+                fwd_depth_parallel(
                     z,
-                    w,
+                    w_tail,
                     base_fn,
                     base_n,
-                    scratch,
+                    base_scratch,
                     fwd_process_x2,
                     fwd_process_x4,
                     fwd_process_x8,
-                );
-            }
-            let duration = now.elapsed();
-            avg_durations[i] = Some(duration / n_runs);
+                    parallel_threshold,
+                )
+            });
+    }
+}
+
+/// Same as [`inv_depth`], but the subproblems of size greater than or equal to
+/// `parallel_threshold` are processed in parallel. `base_scratch` must contain
+/// `parallel_scratch_count(n, base_n, parallel_threshold)` scratch buffers of size `base_n`.
+#[cfg(feature = "rayon")]
+fn inv_depth_parallel<T: Send + Sync>(
+    z: &mut [T],
+    w: &[T],
+    base_fn: fn(Option<&[T]>, &mut [T], &mut [T], &[T], &[T]),
+    base_n: usize,
+    base_scratch: &mut [T],
+    inv_process_x2: fn(&mut [T], &[T]),
+    inv_process_x4: fn(&mut [T], &[T]),
+    inv_process_x8: fn(&mut [T], &[T]),
+    parallel_threshold: usize,
+) {
+    use rayon::prelude::*;
+
+    let n = z.len();
+    if n == base_n || n < parallel_threshold {
+        inv_depth(
+            z,
+            w,
+            base_fn,
+            base_n,
+            &mut base_scratch[..base_n],
+            inv_process_x2,
+            inv_process_x4,
+            inv_process_x8,
+        );
+    } else {
+        let r = if n == 2 * base_n {
+            2
+        } else if n == 4 * base_n {
+            4
+        } else {
+            8
+        };
+
+        let m = n / r;
+        let (w_head, w_tail) = w.split_at(w.len() - (r - 1) * m);
+
+        let scratch_len = base_scratch.len() / r;
+        z.par_chunks_exact_mut(m)
+            .zip(base_scratch.par_chunks_exact_mut(scratch_len))
+            .for_each(|(z, base_scratch)| {
+                inv_depth_parallel(
+                    z,
+                    w_head,
+                    base_fn,
+                    base_n,
+                    base_scratch,
+                    inv_process_x2,
+                    inv_process_x4,
+                    inv_process_x8,
+                    parallel_threshold,
+                )
+            });
+
+        if r == 2 {
+            inv_process_x2(z, w_tail);
+        } else if r == 4 {
+            inv_process_x4(z, w_tail);
+        } else {
+            inv_process_x8(z, w_tail);
         }
+    }
+}
 
-        let best_time = avg_durations[..n_algos].iter().min().unwrap().unwrap();
-        let best_index = avg_durations[..n_algos]
-            .iter()
-            .position(|elem| elem.unwrap() == best_time)
-            .unwrap();
+/// Same as [`fwd_depth_out_of_place`], but the subproblems of size greater than or equal to
+/// `parallel_threshold` are processed in parallel, as in [`fwd_depth_parallel`].
+#[cfg(feature = "rayon")]
+fn fwd_depth_out_of_place_parallel<T: Send + Sync>(
+    src: &[T],
+    z: &mut [T],
+    w: &[T],
+    base_fn: fn(Option<&[T]>, &mut [T], &mut [T], &[T], &[T]),
+    base_n: usize,
+    base_scratch: &mut [T],
+    fwd_process_x2: fn(&mut [T], &[T]),
+    fwd_process_x4: fn(&mut [T], &[T]),
+    fwd_process_x8: fn(&mut [T], &[T]),
+    fwd_process_out_of_place: [fn(&mut [T], &[T], &[T]); 3],
+    parallel_threshold: usize,
+) {
+    use rayon::prelude::*;
 
-        (algos[best_index].unwrap(), bases[best_index], best_time)
+    let n = z.len();
+    if n == base_n || n < parallel_threshold {
+        fwd_depth_out_of_place(
+            src,
+            z,
+            w,
+            base_fn,
+            base_n,
+            &mut base_scratch[..base_n],
+            fwd_process_x2,
+            fwd_process_x4,
+            fwd_process_x8,
+            fwd_process_out_of_place,
+        );
+    } else {
+        let r = if n == 2 * base_n {
+            2
+        } else if n == 4 * base_n {
+            4
+        } else {
+            8
+        };
+
+        let m = n / r;
+        let (w_head, w_tail) = w.split_at((r - 1) * m);
+
+        let [fwd_process_x2_out_of_place, fwd_process_x4_out_of_place, fwd_process_x8_out_of_place] =
+            fwd_process_out_of_place;
+        if r == 2 {
+            fwd_process_x2_out_of_place(z, src, w_head);
+        } else if r == 4 {
+            fwd_process_x4_out_of_place(z, src, w_head);
+        } else {
+            fwd_process_x8_out_of_place(z, src, w_head);
+        }
+
+        let scratch_len = base_scratch.len() / r;
+        z.par_chunks_exact_mut(m)
+            .zip(base_scratch.par_chunks_exact_mut(scratch_len))
+            .for_each(|(z, base_scratch)| {
+                fwd_depth_parallel(
+                    z,
+                    w_tail,
+                    base_fn,
+                    base_n,
+                    base_scratch,
+                    fwd_process_x2,
+                    fwd_process_x4,
+                    fwd_process_x8,
+                    parallel_threshold,
+                )
+            });
+    }
+}
+
+/// Same as [`inv_depth_out_of_place`], but the subproblems of size greater than or equal to
+/// `parallel_threshold` are processed in parallel, as in [`inv_depth_parallel`].
+#[cfg(feature = "rayon")]
+fn inv_depth_out_of_place_parallel<T: Send + Sync>(
+    src: &[T],
+    z: &mut [T],
+    w: &[T],
+    base_fn: fn(Option<&[T]>, &mut [T], &mut [T], &[T], &[T]),
+    base_n: usize,
+    base_scratch: &mut [T],
+    inv_process_x2: fn(&mut [T], &[T]),
+    inv_process_x4: fn(&mut [T], &[T]),
+    inv_process_x8: fn(&mut [T], &[T]),
+    parallel_threshold: usize,
+) {
+    use rayon::prelude::*;
+
+    let n = z.len();
+    if n == base_n || n < parallel_threshold {
+        inv_depth_out_of_place(
+            src,
+            z,
+            w,
+            base_fn,
+            base_n,
+            &mut base_scratch[..base_n],
+            inv_process_x2,
+            inv_process_x4,
+            inv_process_x8,
+        );
+    } else {
+        let r = if n == 2 * base_n {
+            2
+        } else if n == 4 * base_n {
+            4
+        } else {
+            8
+        };
+
+        let m = n / r;
+        let (w_head, w_tail) = w.split_at(w.len() - (r - 1) * m);
+
+        let scratch_len = base_scratch.len() / r;
+        src.par_chunks_exact(m)
+            .zip(z.par_chunks_exact_mut(m))
+            .zip(base_scratch.par_chunks_exact_mut(scratch_len))
+            .for_each(|((src, z), base_scratch)| {
+                inv_depth_out_of_place_parallel(
+                    src,
+                    z,
+                    w_head,
+                    base_fn,
+                    base_n,
+                    base_scratch,
+                    inv_process_x2,
+                    inv_process_x4,
+                    inv_process_x8,
+                    parallel_threshold,
+                )
+            });
+
+        if r == 2 {
+            inv_process_x2(z, w_tail);
+        } else if r == 4 {
+            inv_process_x4(z, w_tail);
+        } else {
+            inv_process_x8(z, w_tail);
+        }
+    }
+}
+
+/// Unordered FFT plan.
+///
+/// This type holds a forward and inverse FFT plan and twiddling factors for a specific size.
+/// The size must be a power of two.
+#[derive(Clone)]
+pub struct Plan {
+    monomial_twiddles: ABox<[c64]>,
+    indices: ABox<[usize]>,
+    twiddles: ABox<[c64]>,
+    twiddles_inv: ABox<[c64]>,
+    fwd_process_x2: fn(&mut [c64], &[c64]),
+    fwd_process_x4: fn(&mut [c64], &[c64]),
+    fwd_process_x8: fn(&mut [c64], &[c64]),
+    fwd_process_out_of_place: [fn(&mut [c64], &[c64], &[c64]); 3],
+    inv_process_x2: fn(&mut [c64], &[c64]),
+    inv_process_x4: fn(&mut [c64], &[c64]),
+    inv_process_x8: fn(&mut [c64], &[c64]),
+    fwd_process_batch: [fn(&mut [c64], &[c64]); 3],
+    inv_process_batch: [fn(&mut [c64], &[c64]); 3],
+    base_n: usize,
+    base_fn_fwd: fn(Option<&[c64]>, &mut [c64], &mut [c64], &[c64], &[c64]),
+    base_fn_inv: fn(Option<&[c64]>, &mut [c64], &mut [c64], &[c64], &[c64]),
+    base_algo: FftAlgo,
+    n: usize,
+    simd: SimdLevel,
+    mul_assign: fn(&mut [c64], &[c64]),
+    add_assign: fn(&mut [c64], &[c64]),
+    mul_add_assign: fn(&mut [c64], &[c64], &[c64]),
+    mul_conj_add_assign: fn(&mut [c64], &[c64], &[c64]),
+    #[cfg(feature = "rayon")]
+    parallel_threshold: usize,
+    // only built the first time that a split-complex FFT is performed
+    #[cfg(feature = "std")]
+    split: std::sync::OnceLock<SplitPlan>,
+}
+
+impl core::fmt::Debug for Plan {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Plan")
+            .field("base_algo", &self.base_algo)
+            .field("base_size", &self.base_n)
+            .field("simd", &self.simd)
+            .field("fft_size", &self.fft_size())
+            .finish()
+    }
+}
+
+/// Method for selecting the unordered FFT plan.
+#[derive(Clone, Copy, Debug)]
+pub enum Method<'a> {
+    /// Select the FFT plan by manually providing the underlying algorithm.
+    /// The unordered FFT works by using an internal ordered FFT plan, whose size and algorithm can
+    /// be specified by the user.
+    UserProvided { base_algo: FftAlgo, base_n: usize },
+    /// Select the FFT plan by looking up the internal ordered FFT plan previously measured for the
+    /// vector size and the SIMD instruction set of the current machine.
+    Wisdom(&'a Wisdom),
+    /// Select the FFT plan by measuring the running time of all the possible plans and selecting
+    /// the fastest one. The provided duration specifies how long the benchmark of each plan should
+    /// last.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    Measure(Duration),
+}
+
+#[cfg(feature = "std")]
+fn measure_fastest_scratch<T: UnorderedFftComplex>(n: usize) -> StackReq {
+    if n <= 512 {
+        crate::ordered::measure_fastest_scratch::<T>(n)
+    } else {
+        let base_n = 4096;
+        crate::ordered::measure_fastest_scratch::<T>(base_n)
+            .and(StackReq::new_aligned::<T>(n + base_n, CACHELINE_ALIGN)) // twiddles
+            .and(StackReq::new_aligned::<T>(n, CACHELINE_ALIGN)) // buf
+            .and(StackReq::new_aligned::<T>(base_n, CACHELINE_ALIGN)) // scratch
+    }
+}
+
+#[cfg(feature = "std")]
+fn measure_fastest<T: UnorderedFftComplex>(
+    mut min_bench_duration_per_algo: Duration,
+    n: usize,
+    simd: SimdLevel,
+    mut stack: PodStack,
+) -> (FftAlgo, usize, Duration) {
+    const MIN_DURATION: Duration = Duration::from_millis(1);
+    min_bench_duration_per_algo = min_bench_duration_per_algo.max(MIN_DURATION);
+
+    if n <= 256 {
+        let (algo, duration) =
+            crate::ordered::measure_fastest::<T>(min_bench_duration_per_algo, n, simd, stack);
+        (algo, n, duration)
+    } else {
+        // bench
+
+        let bases = [512, 1024];
+        let mut algos: [Option<FftAlgo>; 4] = [None; 4];
+        let mut avg_durations: [Option<Duration>; 4] = [None; 4];
+        let [fwd_process_x2, fwd_process_x4, fwd_process_x8] = T::get_fwd_process(simd);
+
+        let mut n_algos = 0;
+        for (i, base_n) in bases.into_iter().enumerate() {
+            if n < base_n {
+                break;
+            }
+
+            n_algos += 1;
+
+            // we'll measure the corresponding plan
+            let (base_algo, duration) = crate::ordered::measure_fastest::<T>(
+                min_bench_duration_per_algo,
+                base_n,
+                simd,
+                stack.rb_mut(),
+            );
+
+            algos[i] = Some(base_algo);
+
+            if n == base_n {
+                avg_durations[i] = Some(duration);
+                continue;
+            }
+
+            // get the forward base algo
+            let base_fn = T::get_fn_ptr(base_algo, base_n, simd)[0];
+
+            let f = |_| T::default();
+            let align = CACHELINE_ALIGN;
+            let (w, stack) = stack
+                .rb_mut()
+                .make_aligned_with::<T, _>(n + base_n, align, f);
+            let (scratch, stack) = stack.make_aligned_with::<T, _>(base_n, align, f);
+            let (z, _) = stack.make_aligned_with::<T, _>(n, align, f);
+
+            let n_runs = min_bench_duration_per_algo.as_secs_f64()
+                / (duration.as_secs_f64() * (n / base_n) as f64);
+
+            let n_runs = n_runs.ceil() as u32;
+
+            // For wasm we have a dedicated implementation going through js-sys
+            use crate::time::Instant;
+            let now = Instant::now();
+            for _ in 0..n_runs {
+                fwd_depth(
+                    z,
+                    w,
+                    base_fn,
+                    base_n,
+                    scratch,
+                    fwd_process_x2,
+                    fwd_process_x4,
+                    fwd_process_x8,
+                );
+            }
+            let duration = now.elapsed();
+            avg_durations[i] = Some(duration / n_runs);
+        }
+
+        let best_time = avg_durations[..n_algos].iter().min().unwrap().unwrap();
+        let best_index = avg_durations[..n_algos]
+            .iter()
+            .position(|elem| elem.unwrap() == best_time)
+            .unwrap();
+
+        (algos[best_index].unwrap(), bases[best_index], best_time)
+    }
+}
+
+/// Returns the algorithm and size of the internal ordered FFT plan of an unordered FFT of size
+/// `n`.
+fn select_base<T: UnorderedFftComplex>(
+    n: usize,
+    method: Method,
+    simd: SimdLevel,
+) -> Result<(FftAlgo, usize), PlanError> {
+    check_power_of_two(n)?;
+    check_simd_level(simd)?;
+
+    let (base_algo, base_n) = match method {
+        Method::UserProvided { base_algo, base_n } => (base_algo, base_n),
+        Method::Wisdom(wisdom) => wisdom
+            .unordered(n, simd)
+            .ok_or(PlanError::MissingWisdom { size: n })?,
+        #[cfg(feature = "std")]
+        Method::Measure(duration) => return Ok(measure_base::<T>(n, duration, simd)),
+    };
+
+    check_power_of_two(base_n)?;
+    if base_n > n {
+        return Err(PlanError::TooLarge {
+            size: base_n,
+            max: n,
+        });
+    }
+    if base_n != n && base_n < 32 {
+        return Err(PlanError::BaseTooSmall { base_n, min: 32 });
+    }
+    if base_n > 1 << 10 {
+        return Err(PlanError::TooLarge {
+            size: base_n,
+            max: 1 << 10,
+        });
+    }
+    Ok((base_algo, base_n))
+}
+
+/// Returns the fastest internal ordered FFT plan for an unordered FFT of size `n`.
+#[cfg(feature = "std")]
+pub(crate) fn measure_base<T: UnorderedFftComplex>(
+    n: usize,
+    duration: Duration,
+    simd: SimdLevel,
+) -> (FftAlgo, usize) {
+    let (algo, base_n, _) = measure_fastest::<T>(
+        duration,
+        n,
+        simd,
+        PodStack::new(&mut GlobalPodBuffer::new(measure_fastest_scratch::<T>(n))),
+    );
+    (algo, base_n)
+}
+
+/// Returns the forward and inverse twiddles of an unordered FFT of size `n`.
+fn new_twiddles<T: UnorderedFftComplex>(
+    n: usize,
+    base_algo: FftAlgo,
+    base_n: usize,
+    simd: SimdLevel,
+) -> (ABox<[T]>, ABox<[T]>) {
+    let nan = T::from_c64(c64 {
+        re: f64::NAN,
+        im: f64::NAN,
+    });
+    let mut twiddles = avec![nan; n + base_n].into_boxed_slice();
+    let mut twiddles_inv = avec![nan; n + base_n].into_boxed_slice();
+
+    use crate::ordered::FftAlgo::*;
+    let base_r = match base_algo {
+        Dif2 | Dit2 => 2,
+        Dif4 | Dit4 => 4,
+        Dif8 | Dit8 => 8,
+        Dif16 | Dit16 => 16,
+    };
+
+    init_twiddles(
+        n,
+        T::get_complex_per_reg(simd),
+        base_n,
+        base_r,
+        &mut twiddles,
+        &mut twiddles_inv,
+    );
+
+    (twiddles, twiddles_inv)
+}
+
+impl Plan {
+    /// Returns a new FFT plan for the given vector size, selected by the provided method.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `n` is not a power of two.
+    /// - If the method is user-provided, panics if the base FFT size is not a power of two, or if
+    /// it is larger than `n` or than `1024`.
+    /// - If the method is user-provided, panics if `n` is not equal to the base ordered FFT size,
+    /// and the base FFT size is less than `32`.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::unordered::{Method, Plan};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    /// ```
+    #[track_caller]
+    pub fn new(n: usize, method: Method) -> Self {
+        match Self::try_new(n, method) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Returns a new FFT plan for the given vector size, selected by the provided method, or an
+    /// error if the plan is not supported. See [`Plan::new`] for the requirements on the sizes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::FftAlgo,
+    ///     unordered::{Method, Plan},
+    ///     PlanError,
+    /// };
+    ///
+    /// let method = |base_n| Method::UserProvided {
+    ///     base_algo: FftAlgo::Dif4,
+    ///     base_n,
+    /// };
+    /// assert!(Plan::try_new(64, method(32)).is_ok());
+    /// assert_eq!(
+    ///     Plan::try_new(64, method(16)).unwrap_err(),
+    ///     PlanError::BaseTooSmall {
+    ///         base_n: 16,
+    ///         min: 32
+    ///     },
+    /// );
+    /// ```
+    pub fn try_new(n: usize, method: Method) -> Result<Self, PlanError> {
+        Self::try_new_with_simd(n, method, SimdLevel::detect())
+    }
+
+    /// Returns a new FFT plan for the given vector size, selected by the provided method, whose
+    /// kernels use the provided SIMD instruction set instead of the best one available at runtime.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the plan is not supported, see [`Plan::new`].
+    /// - Panics if `simd` is not available on the current machine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::FftAlgo,
+    ///     unordered::{Method, Plan},
+    ///     SimdLevel,
+    /// };
+    ///
+    /// let method = Method::UserProvided {
+    ///     base_algo: FftAlgo::Dif4,
+    ///     base_n: 32,
+    /// };
+    /// let plan = Plan::new_with_simd(64, method, SimdLevel::Scalar);
+    /// assert_eq!(plan.simd_level(), SimdLevel::Scalar);
+    /// ```
+    #[track_caller]
+    pub fn new_with_simd(n: usize, method: Method, simd: SimdLevel) -> Self {
+        match Self::try_new_with_simd(n, method, simd) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Returns a new FFT plan for the given vector size, selected by the provided method, whose
+    /// kernels use the provided SIMD instruction set, or an error if the plan is not supported or
+    /// if `simd` is not available on the current machine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::FftAlgo,
+    ///     unordered::{Method, Plan},
+    ///     PlanError, SimdLevel,
+    /// };
+    ///
+    /// let method = Method::UserProvided {
+    ///     base_algo: FftAlgo::Dif4,
+    ///     base_n: 32,
+    /// };
+    /// assert!(Plan::try_new_with_simd(64, method, SimdLevel::Scalar).is_ok());
+    /// if !SimdLevel::Avx512.is_available() {
+    ///     assert_eq!(
+    ///         Plan::try_new_with_simd(64, method, SimdLevel::Avx512).unwrap_err(),
+    ///         PlanError::SimdUnavailable {
+    ///             simd: SimdLevel::Avx512
+    ///         },
+    ///     );
+    /// }
+    /// ```
+    pub fn try_new_with_simd(n: usize, method: Method, simd: SimdLevel) -> Result<Self, PlanError> {
+        let (base_algo, base_n) = select_base::<c64>(n, method, simd)?;
+        let [base_fn_fwd, base_fn_inv] = crate::ordered::get_fn_ptr(base_algo, base_n, simd);
+        let (twiddles, twiddles_inv) = new_twiddles::<c64>(n, base_algo, base_n, simd);
+
+        let nan = c64 {
+            re: f64::NAN,
+            im: f64::NAN,
+        };
+        let mut monomial_twiddles = avec![nan; n].into_boxed_slice();
+
+        let theta = -2.0 / n as f64;
+        for (i, twid) in monomial_twiddles.iter_mut().enumerate() {
+            let (s, c) = sincospi64(theta * i as f64);
+            *twid = c64 { re: c, im: s };
+        }
+        let mut indices = avec![0usize; n].into_boxed_slice();
+
+        let nbits = n.trailing_zeros();
+        let base_nbits = base_n.trailing_zeros();
+
+        for (i, idx) in indices.iter_mut().enumerate() {
+            *idx = bit_rev_twice_inv(nbits, base_nbits, i);
+        }
+
+        Ok(Self {
+            twiddles,
+            twiddles_inv,
+            fwd_process_x2: get_fwd_process_x2(simd),
+            fwd_process_x4: get_fwd_process_x4(simd),
+            fwd_process_x8: get_fwd_process_x8(simd),
+            fwd_process_out_of_place: [
+                get_fwd_process_x2_out_of_place(simd),
+                get_fwd_process_x4_out_of_place(simd),
+                get_fwd_process_x8_out_of_place(simd),
+            ],
+            inv_process_x2: get_inv_process_x2(simd),
+            inv_process_x4: get_inv_process_x4(simd),
+            inv_process_x8: get_inv_process_x8(simd),
+            fwd_process_batch: [
+                get_fwd_process_x2_batch(simd),
+                get_fwd_process_x4_batch(simd),
+                get_fwd_process_x8_batch(simd),
+            ],
+            inv_process_batch: [
+                get_inv_process_x2_batch(simd),
+                get_inv_process_x4_batch(simd),
+                get_inv_process_x8_batch(simd),
+            ],
+            base_n,
+            base_fn_fwd,
+            base_fn_inv,
+            n,
+            base_algo,
+            simd,
+            monomial_twiddles,
+            indices,
+            mul_assign: get_mul_assign(simd),
+            add_assign: get_add_assign(simd),
+            mul_add_assign: get_mul_add_assign(simd),
+            mul_conj_add_assign: get_mul_conj_add_assign(simd),
+            #[cfg(feature = "rayon")]
+            parallel_threshold: DEFAULT_PARALLEL_THRESHOLD,
+            #[cfg(feature = "std")]
+            split: std::sync::OnceLock::new(),
+        })
+    }
+
+    /// Returns the vector size of the FFT.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::unordered::{Method, Plan};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    /// assert_eq!(plan.fft_size(), 4);
+    /// ```
+    pub fn fft_size(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the algorithm and size of the internal ordered FFT plan.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::FftAlgo,
+    ///     unordered::{Method, Plan},
+    /// };
+    ///
+    /// let plan = Plan::new(
+    ///     4,
+    ///     Method::UserProvided {
+    ///         base_algo: FftAlgo::Dif2,
+    ///         base_n: 4,
+    ///     },
+    /// );
+    /// assert_eq!(plan.algo(), (FftAlgo::Dif2, 4));
+    /// ```
+    pub fn algo(&self) -> (FftAlgo, usize) {
+        (self.base_algo, self.base_n)
+    }
+
+    /// Returns the SIMD instruction set that's internally used by the FFT.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::FftAlgo,
+    ///     unordered::{Method, Plan},
+    ///     SimdLevel,
+    /// };
+    ///
+    /// let plan = Plan::new(
+    ///     4,
+    ///     Method::UserProvided {
+    ///         base_algo: FftAlgo::Dif2,
+    ///         base_n: 4,
+    ///     },
+    /// );
+    /// assert_eq!(plan.simd_level(), SimdLevel::detect());
+    /// ```
+    pub fn simd_level(&self) -> SimdLevel {
+        self.simd
+    }
+
+    /// Returns the size and alignment of the scratch memory needed to perform an FFT.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::unordered::{Method, Plan};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    /// let scratch = plan.fft_scratch().unwrap();
+    /// ```
+    pub fn fft_scratch(&self) -> Result<StackReq, SizeOverflow> {
+        StackReq::try_new_aligned::<c64>(self.scratch_len(), CACHELINE_ALIGN)
+    }
+
+    /// Returns the number of complex numbers needed as scratch space by [`Plan::fwd`] and
+    /// [`Plan::inv`].
+    fn scratch_len(&self) -> usize {
+        #[cfg(feature = "rayon")]
+        {
+            self.base_n * parallel_scratch_count(self.n, self.base_n, self.parallel_threshold)
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            self.base_n
+        }
+    }
+
+    /// Returns the FFT size starting from which the work is dispatched to multiple threads.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    pub fn parallel_threshold(&self) -> usize {
+        self.parallel_threshold
+    }
+
+    /// Sets the FFT size starting from which the work is dispatched to multiple threads.
+    ///
+    /// The default value is [`DEFAULT_PARALLEL_THRESHOLD`]. Lowering the threshold may increase
+    /// the scratch requirement of the plan, so [`Plan::fft_scratch`] should be queried after
+    /// calling this function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::FftAlgo,
+    ///     unordered::{Method, Plan},
+    /// };
+    ///
+    /// let mut plan = Plan::new(
+    ///     1 << 12,
+    ///     Method::UserProvided {
+    ///         base_algo: FftAlgo::Dif4,
+    ///         base_n: 1 << 8,
+    ///     },
+    /// );
+    /// plan.set_parallel_threshold(1 << 10);
+    /// assert_eq!(plan.parallel_threshold(), 1 << 10);
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    pub fn set_parallel_threshold(&mut self, parallel_threshold: usize) {
+        self.parallel_threshold = parallel_threshold;
+    }
+
+    /// Performs a forward FFT in place, using the provided stack as scratch space.
+    ///
+    /// # Note
+    ///
+    /// The values in `buf` must be in standard order prior to calling this function.
+    /// When this function returns, the values in `buf` will contain the terms of the forward
+    /// transform in permuted order.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::unordered::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [c64::default(); 4];
+    /// plan.fwd(&mut buf, stack);
+    /// ```
+    pub fn fwd(&self, buf: &mut [c64], stack: PodStack) {
+        assert_eq!(self.fft_size(), buf.len());
+        let (scratch, _) = stack.make_aligned_raw::<c64>(self.scratch_len(), CACHELINE_ALIGN);
+        #[cfg(feature = "rayon")]
+        fwd_depth_parallel(
+            buf,
+            &self.twiddles,
+            self.base_fn_fwd,
+            self.base_n,
+            scratch,
+            self.fwd_process_x2,
+            self.fwd_process_x4,
+            self.fwd_process_x8,
+            self.parallel_threshold,
+        );
+        #[cfg(not(feature = "rayon"))]
+        fwd_depth(
+            buf,
+            &self.twiddles,
+            self.base_fn_fwd,
+            self.base_n,
+            scratch,
+            self.fwd_process_x2,
+            self.fwd_process_x4,
+            self.fwd_process_x8,
+        );
+    }
+
+    /// Performs a forward FFT on the implicit polynomial `X^degree`, storing the result in `buf`.
+    /// The coefficients are permuted so that they're compatible with other FFTs produced by the
+    /// same plan.
+    pub fn fwd_monomial(&self, degree: usize, buf: &mut [c64]) {
+        struct Impl<'a> {
+            this: &'a Plan,
+            degree: usize,
+            buf: &'a mut [c64],
+        }
+
+        impl pulp::WithSimd for Impl<'_> {
+            type Output = ();
+
+            #[inline(always)]
+            fn with_simd<S: pulp::Simd>(self, simd: S) -> Self::Output {
+                let Self { this, degree, buf } = self;
+                let _ = simd;
+                assert_eq!(this.fft_size(), buf.len());
+                assert!(degree < this.fft_size());
+
+                let twiddles = &*this.monomial_twiddles;
+                let indices = &*this.indices;
+
+                let n = this.fft_size();
+                let base_n = this.base_n;
+                let n_mask = n - 1;
+
+                assert!(n.is_power_of_two());
+                assert_eq!(twiddles.len(), n);
+
+                match n / base_n {
+                    1 => {
+                        // n == base_n
+                        for (i, z) in buf.iter_mut().enumerate() {
+                            *z = twiddles[(i * degree) & n_mask];
+                        }
+                    }
+                    2 => {
+                        // n == 2 * base_n
+                        let (z0, z1) = buf.split_at_mut(n / 2);
+                        for (i, (z0, z1)) in izip!(z0, z1).enumerate() {
+                            *z0 = twiddles[((2 * i) * degree) & n_mask];
+                            *z1 = twiddles[((2 * i + 1) * degree) & n_mask];
+                        }
+                    }
+                    _ => {
+                        for (z, &idx) in buf.iter_mut().zip(indices.iter()) {
+                            *z = twiddles[(idx * degree) & n_mask];
+                        }
+                    }
+                }
+            }
+        }
+
+        self.simd.arch().dispatch(Impl {
+            this: self,
+            degree,
+            buf,
+        })
+    }
+
+    /// Performs an inverse FFT in place, using the provided stack as scratch space.
+    ///
+    /// # Note
+    ///
+    /// The values in `buf` must be in permuted order prior to calling this function.
+    /// When this function returns, the values in `buf` will contain the terms of the forward
+    /// transform in standard order.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::unordered::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer, ReborrowMut};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let mut stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [c64::default(); 4];
+    /// plan.fwd(&mut buf, stack.rb_mut());
+    /// plan.inv(&mut buf, stack);
+    /// ```
+    pub fn inv(&self, buf: &mut [c64], stack: PodStack) {
+        assert_eq!(self.fft_size(), buf.len());
+        let (scratch, _) = stack.make_aligned_raw::<c64>(self.scratch_len(), CACHELINE_ALIGN);
+        #[cfg(feature = "rayon")]
+        inv_depth_parallel(
+            buf,
+            &self.twiddles_inv,
+            self.base_fn_inv,
+            self.base_n,
+            scratch,
+            self.inv_process_x2,
+            self.inv_process_x4,
+            self.inv_process_x8,
+            self.parallel_threshold,
+        );
+        #[cfg(not(feature = "rayon"))]
+        inv_depth(
+            buf,
+            &self.twiddles_inv,
+            self.base_fn_inv,
+            self.base_n,
+            scratch,
+            self.inv_process_x2,
+            self.inv_process_x4,
+            self.inv_process_x8,
+        );
+    }
+
+    /// Performs a forward FFT of `input`, storing the result in `output`, and using the provided
+    /// stack as scratch space.
+    ///
+    /// The scratch space requirement is the same as that of [`Plan::fwd`].
+    ///
+    /// # Note
+    ///
+    /// The values in `input` must be in standard order. When this function returns, the values in
+    /// `output` will contain the terms of the forward transform in permuted order.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `input` or `output` is not equal to the FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::unordered::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// let input = [c64::default(); 4];
+    /// let mut output = [c64::default(); 4];
+    /// plan.fwd_out_of_place(&input, &mut output, stack);
+    /// ```
+    pub fn fwd_out_of_place(&self, input: &[c64], output: &mut [c64], stack: PodStack) {
+        assert_eq!(self.fft_size(), input.len());
+        assert_eq!(self.fft_size(), output.len());
+        let (scratch, _) = stack.make_aligned_raw::<c64>(self.scratch_len(), CACHELINE_ALIGN);
+        #[cfg(feature = "rayon")]
+        fwd_depth_out_of_place_parallel(
+            input,
+            output,
+            &self.twiddles,
+            self.base_fn_fwd,
+            self.base_n,
+            scratch,
+            self.fwd_process_x2,
+            self.fwd_process_x4,
+            self.fwd_process_x8,
+            self.fwd_process_out_of_place,
+            self.parallel_threshold,
+        );
+        #[cfg(not(feature = "rayon"))]
+        fwd_depth_out_of_place(
+            input,
+            output,
+            &self.twiddles,
+            self.base_fn_fwd,
+            self.base_n,
+            scratch,
+            self.fwd_process_x2,
+            self.fwd_process_x4,
+            self.fwd_process_x8,
+            self.fwd_process_out_of_place,
+        );
+    }
+
+    /// Performs an inverse FFT of `input`, storing the result in `output`, and using the provided
+    /// stack as scratch space.
+    ///
+    /// The scratch space requirement is the same as that of [`Plan::inv`].
+    ///
+    /// # Note
+    ///
+    /// The values in `input` must be in the order produced by a forward FFT of the same plan.
+    /// When this function returns, the values in `output` will contain the terms of the inverse
+    /// transform in standard order.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `input` or `output` is not equal to the FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::unordered::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer, ReborrowMut};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let mut stack = PodStack::new(&mut memory);
+    ///
+    /// let input = [c64::default(); 4];
+    /// let mut transformed = [c64::default(); 4];
+    /// let mut output = [c64::default(); 4];
+    /// plan.fwd_out_of_place(&input, &mut transformed, stack.rb_mut());
+    /// plan.inv_out_of_place(&transformed, &mut output, stack);
+    /// ```
+    pub fn inv_out_of_place(&self, input: &[c64], output: &mut [c64], stack: PodStack) {
+        assert_eq!(self.fft_size(), input.len());
+        assert_eq!(self.fft_size(), output.len());
+        let (scratch, _) = stack.make_aligned_raw::<c64>(self.scratch_len(), CACHELINE_ALIGN);
+        #[cfg(feature = "rayon")]
+        inv_depth_out_of_place_parallel(
+            input,
+            output,
+            &self.twiddles_inv,
+            self.base_fn_inv,
+            self.base_n,
+            scratch,
+            self.inv_process_x2,
+            self.inv_process_x4,
+            self.inv_process_x8,
+            self.parallel_threshold,
+        );
+        #[cfg(not(feature = "rayon"))]
+        inv_depth_out_of_place(
+            input,
+            output,
+            &self.twiddles_inv,
+            self.base_fn_inv,
+            self.base_n,
+            scratch,
+            self.inv_process_x2,
+            self.inv_process_x4,
+            self.inv_process_x8,
+        );
+    }
+
+    /// Performs a forward FFT in place on each of the `count` consecutive vectors of `buf`, using
+    /// the provided stack as scratch space.
+    ///
+    /// This computes the same result as calling [`Plan::fwd`] on each vector. The vectors are
+    /// split into groups that fit in the L2 cache, and the outer radix passes are applied to a whole
+    /// group one tile of twiddles at a time, so that each twiddle is loaded once per group. The
+    /// inner passes, whose data fits in the L1 cache, are then computed one vector at a time.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `buf` is not equal to `count` times the FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::unordered::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [c64::default(); 3 * 4];
+    /// plan.fwd_batch(&mut buf, 3, stack);
+    /// ```
+    pub fn fwd_batch(&self, buf: &mut [c64], count: usize, stack: PodStack) {
+        let n = self.fft_size();
+        assert_eq!(Some(buf.len()), n.checked_mul(count));
+        let (scratch, _) = stack.make_aligned_raw::<c64>(self.algo().1, CACHELINE_ALIGN);
+        let batch_len = Ord::max(BATCH_BLOCK_SIZE / n, 1) * n;
+        for buf in buf.chunks_mut(batch_len) {
+            fwd_breadth(
+                buf,
+                n,
+                &self.twiddles,
+                self.base_fn_fwd,
+                self.base_n,
+                scratch,
+                [
+                    self.fwd_process_x2,
+                    self.fwd_process_x4,
+                    self.fwd_process_x8,
+                ],
+                self.fwd_process_batch,
+            );
+        }
+    }
+
+    /// Performs an inverse FFT in place on each of the `count` consecutive vectors of `buf`, using
+    /// the provided stack as scratch space.
+    ///
+    /// This computes the same result as calling [`Plan::inv`] on each vector. The vectors are
+    /// split into groups that fit in the L2 cache, and the outer radix passes are applied to a whole
+    /// group one tile of twiddles at a time, so that each twiddle is loaded once per group. The
+    /// inner passes, whose data fits in the L1 cache, are then computed one vector at a time.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `buf` is not equal to `count` times the FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::unordered::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer, ReborrowMut};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let mut stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [c64::default(); 3 * 4];
+    /// plan.fwd_batch(&mut buf, 3, stack.rb_mut());
+    /// plan.inv_batch(&mut buf, 3, stack);
+    /// ```
+    pub fn inv_batch(&self, buf: &mut [c64], count: usize, stack: PodStack) {
+        let n = self.fft_size();
+        assert_eq!(Some(buf.len()), n.checked_mul(count));
+        let (scratch, _) = stack.make_aligned_raw::<c64>(self.algo().1, CACHELINE_ALIGN);
+        let batch_len = Ord::max(BATCH_BLOCK_SIZE / n, 1) * n;
+        for buf in buf.chunks_mut(batch_len) {
+            inv_breadth(
+                buf,
+                n,
+                &self.twiddles_inv,
+                self.base_fn_inv,
+                self.base_n,
+                scratch,
+                [
+                    self.inv_process_x2,
+                    self.inv_process_x4,
+                    self.inv_process_x8,
+                ],
+                self.inv_process_batch,
+            );
+        }
+    }
+
+    /// Returns the size and alignment of the scratch memory needed to perform a split-complex FFT
+    /// with [`Plan::fwd_split`] or [`Plan::inv_split`].
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::unordered::{Method, Plan};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    /// let scratch = plan.fft_split_scratch().unwrap();
+    /// ```
+    pub fn fft_split_scratch(&self) -> Result<StackReq, SizeOverflow> {
+        #[cfg(feature = "std")]
+        {
+            self.split_plan().fft_scratch()
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            StackReq::try_new_aligned::<c64>(self.n, CACHELINE_ALIGN)?.try_and(self.fft_scratch()?)
+        }
+    }
+
+    /// Performs a forward FFT in place on the split-complex vector whose real and imaginary parts
+    /// are stored in `re` and `im`, using the provided stack as scratch space.
+    ///
+    /// This computes the same transform as [`SplitPlan::fwd`]. The [`SplitPlan`] is built the
+    /// first time that a split-complex FFT is performed with this plan, and is reused afterwards.
+    /// Without the `std` feature, the vector is instead interleaved into a complex buffer and
+    /// transformed with [`Plan::fwd`].
+    ///
+    /// # Note
+    ///
+    /// The values in `re` and `im` must be in standard order prior to calling this function.
+    /// When this function returns, the values in `re` and `im` will contain the terms of the
+    /// forward transform in the same permuted order as the output of [`Plan::fwd`].
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `re` or `im` is not equal to the FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::unordered::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_split_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// let mut re = [0.0; 4];
+    /// let mut im = [0.0; 4];
+    /// plan.fwd_split(&mut re, &mut im, stack);
+    /// ```
+    pub fn fwd_split(&self, re: &mut [f64], im: &mut [f64], stack: PodStack) {
+        #[cfg(feature = "std")]
+        {
+            self.split_plan().fwd(re, im, stack)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            self.interleaved_split(true, re, im, stack)
+        }
+    }
+
+    /// Performs an inverse FFT in place on the split-complex vector whose real and imaginary parts
+    /// are stored in `re` and `im`, using the provided stack as scratch space.
+    ///
+    /// This computes the same transform as [`SplitPlan::inv`].
+    ///
+    /// # Note
+    ///
+    /// The values in `re` and `im` must be in the permuted order produced by [`Plan::fwd_split`]
+    /// or [`Plan::fwd`] prior to calling this function. When this function returns, the values in
+    /// `re` and `im` will contain the terms of the inverse transform in standard order.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `re` or `im` is not equal to the FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::unordered::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer, ReborrowMut};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_split_scratch().unwrap());
+    /// let mut stack = PodStack::new(&mut memory);
+    ///
+    /// let mut re = [0.0; 4];
+    /// let mut im = [0.0; 4];
+    /// plan.fwd_split(&mut re, &mut im, stack.rb_mut());
+    /// plan.inv_split(&mut re, &mut im, stack);
+    /// ```
+    pub fn inv_split(&self, re: &mut [f64], im: &mut [f64], stack: PodStack) {
+        #[cfg(feature = "std")]
+        {
+            self.split_plan().inv(re, im, stack)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            self.interleaved_split(false, re, im, stack)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn split_plan(&self) -> &SplitPlan {
+        self.split.get_or_init(|| SplitPlan::new(self))
+    }
+
+    // split-complex FFT, computed by interleaving the vector into a complex buffer
+    #[cfg(not(feature = "std"))]
+    fn interleaved_split(&self, fwd: bool, re: &mut [f64], im: &mut [f64], stack: PodStack) {
+        assert_eq!(self.fft_size(), re.len());
+        assert_eq!(self.fft_size(), im.len());
+
+        let (z, stack) = stack.make_aligned_raw::<c64>(self.n, CACHELINE_ALIGN);
+        for (z, (&re, &im)) in z.iter_mut().zip(re.iter().zip(&*im)) {
+            *z = c64 { re, im };
+        }
+        if fwd {
+            self.fwd(z, stack);
+        } else {
+            self.inv(z, stack);
+        }
+        for (z, (re, im)) in z.iter().zip(re.iter_mut().zip(im)) {
+            *re = z.re;
+            *im = z.im;
+        }
+    }
+
+    /// Computes `lhs[i] *= rhs[i]` for all `i`.
+    ///
+    /// Since the product is computed elementwise, the result is valid for vectors in the
+    /// plan-specific permuted order produced by [`Plan::fwd`].
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `lhs` or `rhs` is not equal to the FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::unordered::{Method, Plan};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut lhs = [c64::new(1.0, 2.0); 4];
+    /// let rhs = [c64::new(3.0, 4.0); 4];
+    /// plan.pointwise_mul(&mut lhs, &rhs);
+    /// assert_eq!(lhs, [c64::new(-5.0, 10.0); 4]);
+    /// ```
+    pub fn pointwise_mul(&self, lhs: &mut [c64], rhs: &[c64]) {
+        assert_eq!(self.fft_size(), lhs.len());
+        assert_eq!(self.fft_size(), rhs.len());
+        (self.mul_assign)(lhs, rhs);
+    }
+
+    /// Computes `acc[i] += lhs[i] * rhs[i]` for all `i`.
+    ///
+    /// Since the product is computed elementwise, the result is valid for vectors in the
+    /// plan-specific permuted order produced by [`Plan::fwd`].
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `acc`, `lhs` or `rhs` is not equal to the FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::unordered::{Method, Plan};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut acc = [c64::new(1.0, 0.0); 4];
+    /// let lhs = [c64::new(1.0, 2.0); 4];
+    /// let rhs = [c64::new(3.0, 4.0); 4];
+    /// plan.pointwise_mul_add(&mut acc, &lhs, &rhs);
+    /// assert_eq!(acc, [c64::new(-4.0, 10.0); 4]);
+    /// ```
+    pub fn pointwise_mul_add(&self, acc: &mut [c64], lhs: &[c64], rhs: &[c64]) {
+        assert_eq!(self.fft_size(), acc.len());
+        assert_eq!(self.fft_size(), lhs.len());
+        assert_eq!(self.fft_size(), rhs.len());
+        (self.mul_add_assign)(acc, lhs, rhs);
+    }
+
+    /// Computes `acc[i] += lhs[i] * conj(rhs[i])` for all `i`.
+    ///
+    /// Since the product is computed elementwise, the result is valid for vectors in the
+    /// plan-specific permuted order produced by [`Plan::fwd`]. This is typically used to compute
+    /// correlations instead of convolutions.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `acc`, `lhs` or `rhs` is not equal to the FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::unordered::{Method, Plan};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut acc = [c64::new(1.0, 0.0); 4];
+    /// let lhs = [c64::new(1.0, 2.0); 4];
+    /// let rhs = [c64::new(3.0, 4.0); 4];
+    /// plan.pointwise_mul_conj_add(&mut acc, &lhs, &rhs);
+    /// assert_eq!(acc, [c64::new(12.0, 2.0); 4]);
+    /// ```
+    pub fn pointwise_mul_conj_add(&self, acc: &mut [c64], lhs: &[c64], rhs: &[c64]) {
+        assert_eq!(self.fft_size(), acc.len());
+        assert_eq!(self.fft_size(), lhs.len());
+        assert_eq!(self.fft_size(), rhs.len());
+        (self.mul_conj_add_assign)(acc, lhs, rhs);
+    }
+
+    /// Performs an inverse FFT in place on `fourier`, then adds the result to `acc`, using the
+    /// provided stack as scratch space.
+    ///
+    /// The scratch space requirement is the same as that of [`Plan::inv`].
+    ///
+    /// # Note
+    ///
+    /// The values in `fourier` must be in the order produced by a forward FFT of the same plan.
+    /// When this function returns, `fourier` contains the terms of the inverse transform in
+    /// standard order, and these terms have been added to the values in `acc`.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `acc` or `fourier` is not equal to the FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c64;
+    /// use concrete_fft::unordered::{Method, Plan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer, ReborrowMut};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let mut stack = PodStack::new(&mut memory);
+    ///
+    /// let mut acc = [c64::default(); 4];
+    /// let mut buf = [c64::default(); 4];
+    /// plan.fwd(&mut buf, stack.rb_mut());
+    /// plan.inv_then_add(&mut acc, &mut buf, stack);
+    /// ```
+    pub fn inv_then_add(&self, acc: &mut [c64], fourier: &mut [c64], stack: PodStack) {
+        assert_eq!(self.fft_size(), acc.len());
+        self.inv(fourier, stack);
+        (self.add_assign)(acc, fourier);
+    }
+
+    /// Copies the data in the Fourier domain from `src`, which is stored in the plan-specific
+    /// permuted order, into `dst`, so that it is stored in the standard order.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `src` or `dst` is not equal to the FFT size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     c64,
+    ///     ordered::FftAlgo,
+    ///     unordered::{Method, Plan},
+    /// };
+    /// use dyn_stack::{GlobalPodBuffer, PodStack};
+    ///
+    /// let plan = Plan::new(
+    ///     64,
+    ///     Method::UserProvided {
+    ///         base_algo: FftAlgo::Dif4,
+    ///         base_n: 32,
+    ///     },
+    /// );
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [c64::default(); 64];
+    /// buf[0] = c64::new(1.0, 0.0);
+    /// plan.fwd(&mut buf, stack);
+    ///
+    /// let mut spectrum = [c64::default(); 64];
+    /// plan.to_standard_order(&buf, &mut spectrum);
+    /// ```
+    pub fn to_standard_order(&self, src: &[c64], dst: &mut [c64]) {
+        assert_eq!(self.n, src.len());
+        assert_eq!(self.n, dst.len());
+        permute(&self.indices, src, dst, false);
+    }
+
+    /// Copies the data in the Fourier domain from `src`, which is stored in the standard order,
+    /// into `dst`, so that it is stored in the plan-specific permuted order.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `src` or `dst` is not equal to the FFT size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     c64,
+    ///     ordered::FftAlgo,
+    ///     unordered::{Method, Plan},
+    /// };
+    /// use dyn_stack::{GlobalPodBuffer, PodStack};
+    ///
+    /// let plan = Plan::new(
+    ///     64,
+    ///     Method::UserProvided {
+    ///         base_algo: FftAlgo::Dif4,
+    ///         base_n: 32,
+    ///     },
+    /// );
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// let spectrum = [c64::new(1.0, 0.0); 64];
+    /// let mut buf = [c64::default(); 64];
+    /// plan.from_standard_order(&spectrum, &mut buf);
+    /// plan.inv(&mut buf, stack);
+    /// ```
+    pub fn from_standard_order(&self, src: &[c64], dst: &mut [c64]) {
+        assert_eq!(self.n, src.len());
+        assert_eq!(self.n, dst.len());
+        permute(&self.indices, src, dst, true);
+    }
+
+    /// Copies the data in the Fourier domain from `src`, which is stored in the permuted order of
+    /// `other`, into `dst`, so that it is stored in the permuted order of `self`.
+    ///
+    /// This allows the inverse transform to be computed by `self` on data that was produced by
+    /// the forward transform of `other`, even if their internal base FFT sizes are different.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the FFT sizes of `self` and `other` are not equal.
+    /// - Panics if the length of `src` or `dst` is not equal to the FFT size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     c64,
+    ///     ordered::FftAlgo,
+    ///     unordered::{Method, Plan},
+    /// };
+    /// use dyn_stack::{GlobalPodBuffer, PodStack, ReborrowMut};
+    ///
+    /// let plan1 = Plan::new(
+    ///     64,
+    ///     Method::UserProvided {
+    ///         base_algo: FftAlgo::Dif4,
+    ///         base_n: 32,
+    ///     },
+    /// );
+    /// let plan2 = Plan::new(
+    ///     64,
+    ///     Method::UserProvided {
+    ///         base_algo: FftAlgo::Dif4,
+    ///         base_n: 64,
+    ///     },
+    /// );
+    ///
+    /// let scratch = plan1
+    ///     .fft_scratch()
+    ///     .unwrap()
+    ///     .or(plan2.fft_scratch().unwrap());
+    /// let mut memory = GlobalPodBuffer::new(scratch);
+    /// let mut stack = PodStack::new(&mut memory);
+    ///
+    /// let mut src = [c64::default(); 64];
+    /// let mut dst = [c64::default(); 64];
+    /// plan1.fwd(&mut src, stack.rb_mut());
+    /// plan2.repermute_from(&plan1, &src, &mut dst);
+    /// plan2.inv(&mut dst, stack);
+    /// ```
+    pub fn repermute_from(&self, other: &Plan, src: &[c64], dst: &mut [c64]) {
+        let n = self.n;
+        assert_eq!(n, other.n);
+        assert_eq!(n, src.len());
+        assert_eq!(n, dst.len());
+
+        if self.base_n == other.base_n {
+            dst.copy_from_slice(src);
+            return;
+        }
+
+        let nbits = n.trailing_zeros();
+        let other_base_nbits = other.base_n.trailing_zeros();
+
+        // `self.indices[i]` is the index in standard order of the value at index `i`
+        for (dst, &idx) in dst.iter_mut().zip(&*self.indices) {
+            *dst = src[bit_rev_twice(nbits, other_base_nbits, idx)];
+        }
+    }
+
+    /// Serialize a buffer containing data in the Fourier domain that is stored in the
+    /// plan-specific permuted order, and store the result with the serializer in the standard
+    /// order.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `buf` is not equal to the FFT size.
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn serialize_fourier_buffer<S: serde::Serializer>(
+        &self,
+        serializer: S,
+        buf: &[c64],
+    ) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        let n = self.n;
+        let base_n = self.base_n;
+        assert_eq!(n, buf.len());
+
+        let mut seq = serializer.serialize_seq(Some(n))?;
+
+        let nbits = n.trailing_zeros();
+        let base_nbits = base_n.trailing_zeros();
+
+        for i in 0..n {
+            seq.serialize_element(&buf[bit_rev_twice(nbits, base_nbits, i)])?;
+        }
+
+        seq.end()
     }
-}
 
-impl Plan {
-    /// Returns a new FFT plan for the given vector size, selected by the provided method.
+    /// Deserialize data in the Fourier domain that is produced by the deserializer in the standard
+    /// order into a buffer so that it will contain the data in the plan-specific permuted order
     ///
     /// # Panics
     ///
-    /// - Panics if `n` is not a power of two.
-    /// - If the method is user-provided, panics if `n` is not equal to the base ordered FFT size,
-    /// and the base FFT size is less than `32`.
-    ///
-    /// # Example
-    #[cfg_attr(feature = "std", doc = " ```")]
-    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
-    /// use concrete_fft::unordered::{Method, Plan};
-    /// use core::time::Duration;
-    ///
-    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
-    /// ```
-    pub fn new(n: usize, method: Method) -> Self {
-        assert!(n.is_power_of_two());
-
-        let (base_algo, base_n) = match method {
-            Method::UserProvided { base_algo, base_n } => {
-                assert!(base_n.is_power_of_two());
-                assert!(base_n <= n);
-                if base_n != n {
-                    assert!(base_n >= 32);
-                }
-                assert!(base_n.trailing_zeros() <= 10);
-                (base_algo, base_n)
-            }
+    /// - Panics if the length of `buf` is not equal to the FFT size.
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn deserialize_fourier_buffer<'de, D: serde::Deserializer<'de>>(
+        &self,
+        deserializer: D,
+        buf: &mut [c64],
+    ) -> Result<(), D::Error> {
+        use serde::de::{SeqAccess, Visitor};
 
-            #[cfg(feature = "std")]
-            Method::Measure(duration) => {
-                let (algo, base_n, _) = measure_fastest(
-                    duration,
-                    n,
-                    PodStack::new(&mut GlobalPodBuffer::new(measure_fastest_scratch(n))),
-                );
-                (algo, base_n)
+        let n = self.n;
+        let base_n = self.base_n;
+        assert_eq!(n, buf.len());
+
+        struct SeqVisitor<'a> {
+            buf: &'a mut [c64],
+            base_n: usize,
+        }
+
+        impl<'de, 'a> Visitor<'de> for SeqVisitor<'a> {
+            type Value = ();
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                write!(
+                    formatter,
+                    "a sequence of {} 64-bit complex numbers",
+                    self.buf.len()
+                )
             }
-        };
 
-        let [base_fn_fwd, base_fn_inv] = crate::ordered::get_fn_ptr(base_algo, base_n);
+            fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+            where
+                S: SeqAccess<'de>,
+            {
+                let n = self.buf.len();
+                let nbits = n.trailing_zeros();
+                let base_nbits = self.base_n.trailing_zeros();
 
-        let nan = c64 {
-            re: f64::NAN,
-            im: f64::NAN,
-        };
-        let mut twiddles = avec![nan; n + base_n].into_boxed_slice();
-        let mut twiddles_inv = avec![nan; n + base_n].into_boxed_slice();
-
-        use crate::ordered::FftAlgo::*;
-        let base_r = match base_algo {
-            Dif2 | Dit2 => 2,
-            Dif4 | Dit4 => 4,
-            Dif8 | Dit8 => 8,
-            Dif16 | Dit16 => 16,
-        };
+                let mut i = 0;
 
-        init_twiddles(
-            n,
-            get_complex_per_reg(),
-            base_n,
-            base_r,
-            &mut twiddles,
-            &mut twiddles_inv,
-        );
+                while let Some(value) = seq.next_element::<c64>()? {
+                    if i < n {
+                        self.buf[bit_rev_twice(nbits, base_nbits, i)] = value;
+                    }
 
-        let nan = c64 {
-            re: f64::NAN,
-            im: f64::NAN,
-        };
-        let mut monomial_twiddles = avec![nan; n].into_boxed_slice();
+                    i += 1;
+                }
 
-        let theta = -2.0 / n as f64;
-        for (i, twid) in monomial_twiddles.iter_mut().enumerate() {
-            let (s, c) = sincospi64(theta * i as f64);
-            *twid = c64 { re: c, im: s };
+                if i != n {
+                    Err(serde::de::Error::invalid_length(i, &self))
+                } else {
+                    Ok(())
+                }
+            }
         }
-        let mut indices = avec![0usize; n].into_boxed_slice();
 
-        let nbits = n.trailing_zeros();
-        let base_nbits = base_n.trailing_zeros();
+        deserializer.deserialize_seq(SeqVisitor { buf, base_n })
+    }
+}
 
-        for (i, idx) in indices.iter_mut().enumerate() {
-            *idx = bit_rev_twice_inv(nbits, base_nbits, i);
+// configuration of a plan that is sent over the wire. the twiddles and the function pointers are
+// always rebuilt from it when deserializing
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Plan")]
+struct PlanConfig {
+    n: usize,
+    base_algo: FftAlgo,
+    base_n: usize,
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for Plan {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        PlanConfig {
+            n: self.n,
+            base_algo: self.base_algo,
+            base_n: self.base_n,
         }
+        .serialize(serializer)
+    }
+}
 
-        Self {
-            twiddles,
-            twiddles_inv,
-            fwd_process_x2: get_fwd_process_x2(),
-            fwd_process_x4: get_fwd_process_x4(),
-            fwd_process_x8: get_fwd_process_x8(),
-            inv_process_x2: get_inv_process_x2(),
-            inv_process_x4: get_inv_process_x4(),
-            inv_process_x8: get_inv_process_x8(),
-            base_n,
-            base_fn_fwd,
-            base_fn_inv,
+/// The SIMD instruction set of the plan is not serialized, since the plan may be deserialized on a
+/// different machine. The deserialized plan uses the best instruction set available at runtime.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for Plan {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let PlanConfig {
             n,
             base_algo,
-            monomial_twiddles,
-            indices,
-        }
+            base_n,
+        } = PlanConfig::deserialize(deserializer)?;
+        Self::try_new(n, Method::UserProvided { base_algo, base_n })
+            .map_err(serde::de::Error::custom)
     }
+}
 
-    /// Returns the vector size of the FFT.
-    ///
-    /// # Example
-    #[cfg_attr(feature = "std", doc = " ```")]
-    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
-    /// use concrete_fft::unordered::{Method, Plan};
-    /// use core::time::Duration;
-    ///
-    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
-    /// assert_eq!(plan.fft_size(), 4);
-    /// ```
-    pub fn fft_size(&self) -> usize {
-        self.n
+/// Unordered FFT plan for split-complex vectors, whose real and imaginary parts are stored in two
+/// separate slices.
+///
+/// This type is created from a [`Plan`], and uses the same base algorithm, base size and SIMD
+/// instruction set. Its forward transform outputs the terms of the FFT in the same order as
+/// [`Plan::fwd`], and its inverse transform takes them in that order. Its twiddling factors are
+/// only computed when it is created, so plans that never perform split-complex FFTs don't pay for
+/// them.
+#[derive(Clone)]
+pub struct SplitPlan {
+    fft: UnorderedSplitFft,
+    base_algo: FftAlgo,
+}
+
+impl core::fmt::Debug for SplitPlan {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SplitPlan")
+            .field("base_algo", &self.base_algo)
+            .field("base_size", &self.fft.base_size())
+            .field("fft_size", &self.fft_size())
+            .finish()
     }
+}
 
-    /// Returns the algorithm and size of the internal ordered FFT plan.
+impl SplitPlan {
+    /// Returns a new split-complex FFT plan with the same size, base algorithm and base size as
+    /// `plan`.
     ///
     /// # Example
     ///
     /// ```
     /// use concrete_fft::{
     ///     ordered::FftAlgo,
-    ///     unordered::{Method, Plan},
+    ///     unordered::{Method, Plan, SplitPlan},
     /// };
     ///
     /// let plan = Plan::new(
@@ -778,261 +2941,441 @@ impl Plan {
     ///         base_n: 4,
     ///     },
     /// );
-    /// assert_eq!(plan.algo(), (FftAlgo::Dif2, 4));
+    /// let split = SplitPlan::new(&plan);
     /// ```
-    pub fn algo(&self) -> (FftAlgo, usize) {
-        (self.base_algo, self.base_n)
+    pub fn new(plan: &Plan) -> Self {
+        Self {
+            fft: UnorderedSplitFft::new(plan.n, plan.base_algo, plan.base_n, plan.simd),
+            base_algo: plan.base_algo,
+        }
     }
 
-    /// Returns the size and alignment of the scratch memory needed to perform an FFT.
+    /// Returns the vector size of the FFT.
     ///
     /// # Example
-    #[cfg_attr(feature = "std", doc = " ```")]
-    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
-    /// use concrete_fft::unordered::{Method, Plan};
-    /// use core::time::Duration;
     ///
-    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
-    /// let scratch = plan.fft_scratch().unwrap();
     /// ```
-    pub fn fft_scratch(&self) -> Result<StackReq, SizeOverflow> {
-        StackReq::try_new_aligned::<c64>(self.algo().1, CACHELINE_ALIGN)
-    }
-
-    /// Performs a forward FFT in place, using the provided stack as scratch space.
-    ///
-    /// # Note
-    ///
-    /// The values in `buf` must be in standard order prior to calling this function.
-    /// When this function returns, the values in `buf` will contain the terms of the forward
-    /// transform in permuted order.
-    ///
-    /// # Example
-    #[cfg_attr(feature = "std", doc = " ```")]
-    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
-    /// use concrete_fft::c64;
-    /// use concrete_fft::unordered::{Method, Plan};
-    /// use dyn_stack::{PodStack, GlobalPodBuffer};
-    /// use core::time::Duration;
-    ///
-    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
-    ///
-    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
-    /// let stack = PodStack::new(&mut memory);
+    /// use concrete_fft::{
+    ///     ordered::FftAlgo,
+    ///     unordered::{Method, Plan, SplitPlan},
+    /// };
     ///
-    /// let mut buf = [c64::default(); 4];
-    /// plan.fwd(&mut buf, stack);
+    /// let plan = Plan::new(
+    ///     4,
+    ///     Method::UserProvided {
+    ///         base_algo: FftAlgo::Dif2,
+    ///         base_n: 4,
+    ///     },
+    /// );
+    /// assert_eq!(SplitPlan::new(&plan).fft_size(), 4);
     /// ```
-    pub fn fwd(&self, buf: &mut [c64], stack: PodStack) {
-        assert_eq!(self.fft_size(), buf.len());
-        let (scratch, _) = stack.make_aligned_raw::<c64>(self.algo().1, CACHELINE_ALIGN);
-        fwd_depth(
-            buf,
-            &self.twiddles,
-            self.base_fn_fwd,
-            self.base_n,
-            scratch,
-            self.fwd_process_x2,
-            self.fwd_process_x4,
-            self.fwd_process_x8,
-        );
+    pub fn fft_size(&self) -> usize {
+        self.fft.fft_size()
     }
 
-    /// Performs a forward FFT on the implicit polynomial `X^degree`, storing the result in `buf`.
-    /// The coefficients are permuted so that they're compatible with other FFTs produced by the
-    /// same plan.
-    pub fn fwd_monomial(&self, degree: usize, buf: &mut [c64]) {
-        struct Impl<'a> {
-            this: &'a Plan,
-            degree: usize,
-            buf: &'a mut [c64],
-        }
-
-        impl pulp::WithSimd for Impl<'_> {
-            type Output = ();
-
-            #[inline(always)]
-            fn with_simd<S: pulp::Simd>(self, simd: S) -> Self::Output {
-                let Self { this, degree, buf } = self;
-                let _ = simd;
-                assert_eq!(this.fft_size(), buf.len());
-                assert!(degree < this.fft_size());
-
-                let twiddles = &*this.monomial_twiddles;
-                let indices = &*this.indices;
-
-                let n = this.fft_size();
-                let base_n = this.base_n;
-                let n_mask = n - 1;
-
-                assert!(n.is_power_of_two());
-                assert_eq!(twiddles.len(), n);
-
-                match n / base_n {
-                    1 => {
-                        // n == base_n
-                        for (i, z) in buf.iter_mut().enumerate() {
-                            *z = twiddles[(i * degree) & n_mask];
-                        }
-                    }
-                    2 => {
-                        // n == 2 * base_n
-                        let (z0, z1) = buf.split_at_mut(n / 2);
-                        for (i, (z0, z1)) in izip!(z0, z1).enumerate() {
-                            *z0 = twiddles[((2 * i) * degree) & n_mask];
-                            *z1 = twiddles[((2 * i + 1) * degree) & n_mask];
-                        }
-                    }
-                    _ => {
-                        for (z, &idx) in buf.iter_mut().zip(indices.iter()) {
-                            *z = twiddles[(idx * degree) & n_mask];
-                        }
-                    }
-                }
-            }
-        }
-
-        pulp::Arch::new().dispatch(Impl {
-            this: self,
-            degree,
-            buf,
-        })
+    /// Returns the algorithm and size of the internal ordered FFT plan.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::FftAlgo,
+    ///     unordered::{Method, Plan, SplitPlan},
+    /// };
+    ///
+    /// let plan = Plan::new(
+    ///     4,
+    ///     Method::UserProvided {
+    ///         base_algo: FftAlgo::Dif2,
+    ///         base_n: 4,
+    ///     },
+    /// );
+    /// assert_eq!(SplitPlan::new(&plan).algo(), (FftAlgo::Dif2, 4));
+    /// ```
+    pub fn algo(&self) -> (FftAlgo, usize) {
+        (self.base_algo, self.fft.base_size())
     }
 
-    /// Performs an inverse FFT in place, using the provided stack as scratch space.
+    /// Returns the size and alignment of the scratch memory needed to perform an FFT.
+    pub fn fft_scratch(&self) -> Result<StackReq, SizeOverflow> {
+        let scratch = StackReq::try_new_aligned::<f64>(self.fft.base_size(), CACHELINE_ALIGN)?;
+        scratch.try_and(scratch)
+    }
+
+    /// Performs a forward FFT in place on the split-complex vector whose real and imaginary parts
+    /// are stored in `re` and `im`, using the provided stack as scratch space.
     ///
     /// # Note
     ///
-    /// The values in `buf` must be in permuted order prior to calling this function.
-    /// When this function returns, the values in `buf` will contain the terms of the forward
-    /// transform in standard order.
+    /// The values in `re` and `im` must be in standard order prior to calling this function.
+    /// When this function returns, the values in `re` and `im` will contain the terms of the
+    /// forward transform in the same permuted order as the output of [`Plan::fwd`].
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the length of `re` or `im` is not equal to the FFT size.
     ///
     /// # Example
     #[cfg_attr(feature = "std", doc = " ```")]
     #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
-    /// use concrete_fft::c64;
-    /// use concrete_fft::unordered::{Method, Plan};
-    /// use dyn_stack::{PodStack, GlobalPodBuffer, ReborrowMut};
+    /// use concrete_fft::unordered::{Method, Plan, SplitPlan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer};
     /// use core::time::Duration;
     ///
-    /// let plan = Plan::new(4, Method::Measure(Duration::from_millis(10)));
+    /// let plan = SplitPlan::new(&Plan::new(4, Method::Measure(Duration::from_millis(10))));
     ///
     /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
-    /// let mut stack = PodStack::new(&mut memory);
+    /// let stack = PodStack::new(&mut memory);
     ///
-    /// let mut buf = [c64::default(); 4];
-    /// plan.fwd(&mut buf, stack.rb_mut());
-    /// plan.inv(&mut buf, stack);
+    /// let mut re = [0.0; 4];
+    /// let mut im = [0.0; 4];
+    /// plan.fwd(&mut re, &mut im, stack);
     /// ```
-    pub fn inv(&self, buf: &mut [c64], stack: PodStack) {
-        assert_eq!(self.fft_size(), buf.len());
-        let (scratch, _) = stack.make_aligned_raw::<c64>(self.algo().1, CACHELINE_ALIGN);
-        inv_depth(
-            buf,
-            &self.twiddles_inv,
-            self.base_fn_inv,
-            self.base_n,
-            scratch,
-            self.inv_process_x2,
-            self.inv_process_x4,
-            self.inv_process_x8,
-        );
+    pub fn fwd(&self, re: &mut [f64], im: &mut [f64], stack: PodStack) {
+        let base_n = self.fft.base_size();
+        let (scratch_re, stack) = stack.make_aligned_raw::<f64>(base_n, CACHELINE_ALIGN);
+        let (scratch_im, _) = stack.make_aligned_raw::<f64>(base_n, CACHELINE_ALIGN);
+        self.fft.fwd(re, im, scratch_re, scratch_im);
     }
 
-    /// Serialize a buffer containing data in the Fourier domain that is stored in the
-    /// plan-specific permuted order, and store the result with the serializer in the standard
-    /// order.
+    /// Performs an inverse FFT in place on the split-complex vector whose real and imaginary parts
+    /// are stored in `re` and `im`, using the provided stack as scratch space.
+    ///
+    /// # Note
+    ///
+    /// The values in `re` and `im` must be in the permuted order produced by [`SplitPlan::fwd`]
+    /// or [`Plan::fwd`] prior to calling this function. When this function returns, the values in
+    /// `re` and `im` will contain the terms of the inverse transform in standard order.
     ///
     /// # Panics
     ///
-    /// - Panics if the length of `buf` is not equal to the FFT size.
-    #[cfg(feature = "serde")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-    pub fn serialize_fourier_buffer<S: serde::Serializer>(
-        &self,
-        serializer: S,
-        buf: &[c64],
-    ) -> Result<S::Ok, S::Error> {
-        use serde::ser::SerializeSeq;
-
-        let n = self.n;
-        let base_n = self.base_n;
-        assert_eq!(n, buf.len());
+    /// - Panics if the length of `re` or `im` is not equal to the FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::unordered::{Method, Plan, SplitPlan};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer, ReborrowMut};
+    /// use core::time::Duration;
+    ///
+    /// let plan = SplitPlan::new(&Plan::new(4, Method::Measure(Duration::from_millis(10))));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let mut stack = PodStack::new(&mut memory);
+    ///
+    /// let mut re = [0.0; 4];
+    /// let mut im = [0.0; 4];
+    /// plan.fwd(&mut re, &mut im, stack.rb_mut());
+    /// plan.inv(&mut re, &mut im, stack);
+    /// ```
+    pub fn inv(&self, re: &mut [f64], im: &mut [f64], stack: PodStack) {
+        let base_n = self.fft.base_size();
+        let (scratch_re, stack) = stack.make_aligned_raw::<f64>(base_n, CACHELINE_ALIGN);
+        let (scratch_im, _) = stack.make_aligned_raw::<f64>(base_n, CACHELINE_ALIGN);
+        self.fft.inv(re, im, scratch_re, scratch_im);
+    }
+}
 
-        let mut seq = serializer.serialize_seq(Some(n))?;
+/// Unordered FFT plan for 32-bit complex numbers.
+///
+/// This type is the single precision counterpart of [`Plan`], and holds a forward and inverse FFT
+/// plan and twiddling factors for a specific size. The size must be a power of two.
+#[derive(Clone)]
+pub struct Plan32 {
+    twiddles: ABox<[c32]>,
+    twiddles_inv: ABox<[c32]>,
+    fwd_process: [fn(&mut [c32], &[c32]); 3],
+    inv_process: [fn(&mut [c32], &[c32]); 3],
+    base_n: usize,
+    base_fn_fwd: fn(Option<&[c32]>, &mut [c32], &mut [c32], &[c32], &[c32]),
+    base_fn_inv: fn(Option<&[c32]>, &mut [c32], &mut [c32], &[c32], &[c32]),
+    base_algo: FftAlgo,
+    n: usize,
+    simd: SimdLevel,
+}
 
-        let nbits = n.trailing_zeros();
-        let base_nbits = base_n.trailing_zeros();
+impl core::fmt::Debug for Plan32 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Plan32")
+            .field("base_algo", &self.base_algo)
+            .field("base_size", &self.base_n)
+            .field("simd", &self.simd)
+            .field("fft_size", &self.fft_size())
+            .finish()
+    }
+}
 
-        for i in 0..n {
-            seq.serialize_element(&buf[bit_rev_twice(nbits, base_nbits, i)])?;
+impl Plan32 {
+    /// Returns a new FFT plan for the given vector size, selected by the provided method.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `n` is not a power of two.
+    /// - If the method is user-provided, panics if the base FFT size is not a power of two, or if
+    /// it is larger than `n` or than `1024`.
+    /// - If the method is user-provided, panics if `n` is not equal to the base ordered FFT size,
+    /// and the base FFT size is less than `32`.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::unordered::{Method, Plan32};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan32::new(4, Method::Measure(Duration::from_millis(10)));
+    /// ```
+    #[track_caller]
+    pub fn new(n: usize, method: Method) -> Self {
+        match Self::try_new(n, method) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
         }
+    }
 
-        seq.end()
+    /// Returns a new FFT plan for the given vector size, selected by the provided method, or an
+    /// error if the plan is not supported. See [`Plan32::new`] for the requirements on the sizes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::FftAlgo,
+    ///     unordered::{Method, Plan32},
+    ///     PlanError,
+    /// };
+    ///
+    /// let method = |base_n| Method::UserProvided {
+    ///     base_algo: FftAlgo::Dif4,
+    ///     base_n,
+    /// };
+    /// assert!(Plan32::try_new(64, method(32)).is_ok());
+    /// assert_eq!(
+    ///     Plan32::try_new(64, method(16)).unwrap_err(),
+    ///     PlanError::BaseTooSmall {
+    ///         base_n: 16,
+    ///         min: 32
+    ///     },
+    /// );
+    /// ```
+    pub fn try_new(n: usize, method: Method) -> Result<Self, PlanError> {
+        Self::try_new_with_simd(n, method, SimdLevel::detect())
     }
 
-    /// Deserialize data in the Fourier domain that is produced by the deserializer in the standard
-    /// order into a buffer so that it will contain the data in the plan-specific permuted order
+    /// Returns a new FFT plan for the given vector size, selected by the provided method, whose
+    /// kernels use the provided SIMD instruction set instead of the best one available at runtime.
     ///
     /// # Panics
     ///
-    /// - Panics if the length of `buf` is not equal to the FFT size.
-    #[cfg(feature = "serde")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-    pub fn deserialize_fourier_buffer<'de, D: serde::Deserializer<'de>>(
-        &self,
-        deserializer: D,
-        buf: &mut [c64],
-    ) -> Result<(), D::Error> {
-        use serde::de::{SeqAccess, Visitor};
-
-        let n = self.n;
-        let base_n = self.base_n;
-        assert_eq!(n, buf.len());
-
-        struct SeqVisitor<'a> {
-            buf: &'a mut [c64],
-            base_n: usize,
+    /// - Panics if the plan is not supported, see [`Plan32::new`].
+    /// - Panics if `simd` is not available on the current machine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::FftAlgo,
+    ///     unordered::{Method, Plan32},
+    ///     SimdLevel,
+    /// };
+    ///
+    /// let method = Method::UserProvided {
+    ///     base_algo: FftAlgo::Dif4,
+    ///     base_n: 32,
+    /// };
+    /// let plan = Plan32::new_with_simd(64, method, SimdLevel::Scalar);
+    /// assert_eq!(plan.simd_level(), SimdLevel::Scalar);
+    /// ```
+    #[track_caller]
+    pub fn new_with_simd(n: usize, method: Method, simd: SimdLevel) -> Self {
+        match Self::try_new_with_simd(n, method, simd) {
+            Ok(plan) => plan,
+            Err(err) => panic!("{err}"),
         }
+    }
 
-        impl<'de, 'a> Visitor<'de> for SeqVisitor<'a> {
-            type Value = ();
-
-            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
-                write!(
-                    formatter,
-                    "a sequence of {} 64-bit complex numbers",
-                    self.buf.len()
-                )
-            }
-
-            fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
-            where
-                S: SeqAccess<'de>,
-            {
-                let n = self.buf.len();
-                let nbits = n.trailing_zeros();
-                let base_nbits = self.base_n.trailing_zeros();
+    /// Returns a new FFT plan for the given vector size, selected by the provided method, whose
+    /// kernels use the provided SIMD instruction set, or an error if the plan is not supported or
+    /// if `simd` is not available on the current machine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::FftAlgo,
+    ///     unordered::{Method, Plan32},
+    ///     PlanError, SimdLevel,
+    /// };
+    ///
+    /// let method = Method::UserProvided {
+    ///     base_algo: FftAlgo::Dif4,
+    ///     base_n: 32,
+    /// };
+    /// assert!(Plan32::try_new_with_simd(64, method, SimdLevel::Scalar).is_ok());
+    /// if !SimdLevel::Avx512.is_available() {
+    ///     assert_eq!(
+    ///         Plan32::try_new_with_simd(64, method, SimdLevel::Avx512).unwrap_err(),
+    ///         PlanError::SimdUnavailable {
+    ///             simd: SimdLevel::Avx512
+    ///         },
+    ///     );
+    /// }
+    /// ```
+    pub fn try_new_with_simd(n: usize, method: Method, simd: SimdLevel) -> Result<Self, PlanError> {
+        let (base_algo, base_n) = select_base::<c32>(n, method, simd)?;
+        let [base_fn_fwd, base_fn_inv] = crate::ordered::get_fn_ptr_c32(base_algo, base_n, simd);
+        let (twiddles, twiddles_inv) = new_twiddles::<c32>(n, base_algo, base_n, simd);
 
-                let mut i = 0;
+        Ok(Self {
+            twiddles,
+            twiddles_inv,
+            fwd_process: c32::get_fwd_process(simd),
+            inv_process: c32::get_inv_process(simd),
+            base_n,
+            base_fn_fwd,
+            base_fn_inv,
+            base_algo,
+            n,
+            simd,
+        })
+    }
 
-                while let Some(value) = seq.next_element::<c64>()? {
-                    if i < n {
-                        self.buf[bit_rev_twice(nbits, base_nbits, i)] = value;
-                    }
+    /// Returns the vector size of the FFT.
+    pub fn fft_size(&self) -> usize {
+        self.n
+    }
 
-                    i += 1;
-                }
+    /// Returns the algorithm and size of the internal ordered FFT plan.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::FftAlgo,
+    ///     unordered::{Method, Plan32},
+    /// };
+    ///
+    /// let plan = Plan32::new(
+    ///     4,
+    ///     Method::UserProvided {
+    ///         base_algo: FftAlgo::Dif2,
+    ///         base_n: 4,
+    ///     },
+    /// );
+    /// assert_eq!(plan.algo(), (FftAlgo::Dif2, 4));
+    /// ```
+    pub fn algo(&self) -> (FftAlgo, usize) {
+        (self.base_algo, self.base_n)
+    }
 
-                if i != n {
-                    Err(serde::de::Error::invalid_length(i, &self))
-                } else {
-                    Ok(())
-                }
-            }
-        }
+    /// Returns the SIMD instruction set that's internally used by the FFT.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_fft::{
+    ///     ordered::FftAlgo,
+    ///     unordered::{Method, Plan32},
+    ///     SimdLevel,
+    /// };
+    ///
+    /// let plan = Plan32::new(
+    ///     4,
+    ///     Method::UserProvided {
+    ///         base_algo: FftAlgo::Dif2,
+    ///         base_n: 4,
+    ///     },
+    /// );
+    /// assert_eq!(plan.simd_level(), SimdLevel::detect());
+    /// ```
+    pub fn simd_level(&self) -> SimdLevel {
+        self.simd
+    }
 
-        deserializer.deserialize_seq(SeqVisitor { buf, base_n })
+    /// Returns the size and alignment of the scratch memory needed to perform an FFT.
+    pub fn fft_scratch(&self) -> Result<StackReq, SizeOverflow> {
+        StackReq::try_new_aligned::<c32>(self.algo().1, CACHELINE_ALIGN)
+    }
+
+    /// Performs a forward FFT in place, using the provided stack as scratch space.
+    ///
+    /// # Note
+    ///
+    /// The values in `buf` must be in standard order prior to calling this function.
+    /// When this function returns, the values in `buf` will contain the terms of the forward
+    /// transform in permuted order. This order is the same as the one of a 64-bit [`Plan`] with
+    /// the same base FFT size.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c32;
+    /// use concrete_fft::unordered::{Method, Plan32};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan32::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [c32::default(); 4];
+    /// plan.fwd(&mut buf, stack);
+    /// ```
+    pub fn fwd(&self, buf: &mut [c32], stack: PodStack) {
+        assert_eq!(self.fft_size(), buf.len());
+        let (scratch, _) = stack.make_aligned_raw::<c32>(self.algo().1, CACHELINE_ALIGN);
+        let [fwd_process_x2, fwd_process_x4, fwd_process_x8] = self.fwd_process;
+        fwd_depth(
+            buf,
+            &self.twiddles,
+            self.base_fn_fwd,
+            self.base_n,
+            scratch,
+            fwd_process_x2,
+            fwd_process_x4,
+            fwd_process_x8,
+        );
+    }
+
+    /// Performs an inverse FFT in place, using the provided stack as scratch space.
+    ///
+    /// # Note
+    ///
+    /// The values in `buf` must be in permuted order prior to calling this function.
+    /// When this function returns, the values in `buf` will contain the terms of the forward
+    /// transform in standard order.
+    ///
+    /// # Example
+    #[cfg_attr(feature = "std", doc = " ```")]
+    #[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+    /// use concrete_fft::c32;
+    /// use concrete_fft::unordered::{Method, Plan32};
+    /// use dyn_stack::{PodStack, GlobalPodBuffer, ReborrowMut};
+    /// use core::time::Duration;
+    ///
+    /// let plan = Plan32::new(4, Method::Measure(Duration::from_millis(10)));
+    ///
+    /// let mut memory = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+    /// let mut stack = PodStack::new(&mut memory);
+    ///
+    /// let mut buf = [c32::default(); 4];
+    /// plan.fwd(&mut buf, stack.rb_mut());
+    /// plan.inv(&mut buf, stack);
+    /// ```
+    pub fn inv(&self, buf: &mut [c32], stack: PodStack) {
+        assert_eq!(self.fft_size(), buf.len());
+        let (scratch, _) = stack.make_aligned_raw::<c32>(self.algo().1, CACHELINE_ALIGN);
+        let [inv_process_x2, inv_process_x4, inv_process_x8] = self.inv_process;
+        inv_depth(
+            buf,
+            &self.twiddles_inv,
+            self.base_fn_inv,
+            self.base_n,
+            scratch,
+            inv_process_x2,
+            inv_process_x4,
+            inv_process_x8,
+        );
     }
 }
 
@@ -1041,7 +3384,23 @@ fn bit_rev(nbits: u32, i: usize) -> usize {
     i.reverse_bits() >> (usize::BITS - nbits)
 }
 
-#[cfg(any(test, feature = "serde"))]
+// computes `dst[i] = src[indices[i]]` if `gather` is true, and `dst[indices[i]] = src[i]`
+// otherwise
+fn permute(indices: &[usize], src: &[c64], dst: &mut [c64], gather: bool) {
+    assert_eq!(indices.len(), src.len());
+    assert_eq!(indices.len(), dst.len());
+
+    if gather {
+        for (dst, &idx) in dst.iter_mut().zip(indices) {
+            *dst = src[idx];
+        }
+    } else {
+        for (&src, &idx) in src.iter().zip(indices) {
+            dst[idx] = src;
+        }
+    }
+}
+
 #[inline]
 fn bit_rev_twice(nbits: u32, base_nbits: u32, i: usize) -> usize {
     let i_rev = bit_rev(nbits, i);
@@ -1103,6 +3462,47 @@ mod tests {
         }
     }
 
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_standard_order() {
+        for n in [32, 128, 1024] {
+            for base_n in [32, n / 2, n] {
+                let base_n = base_n.max(32);
+                let mut z = vec![c64::default(); n];
+                for z in &mut z {
+                    z.re = random();
+                    z.im = random();
+                }
+
+                let mut z_target = z.clone();
+                let mut planner = rustfft::FftPlanner::new();
+                let fwd = planner.plan_fft_forward(n);
+                fwd.process(&mut z_target);
+
+                let plan = Plan::new(
+                    n,
+                    Method::UserProvided {
+                        base_algo: FftAlgo::Dif4,
+                        base_n,
+                    },
+                );
+                let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+                let stack = PodStack::new(&mut mem);
+                plan.fwd(&mut z, stack);
+
+                let mut standard = vec![c64::default(); n];
+                plan.to_standard_order(&z, &mut standard);
+                for (actual, expected) in standard.iter().zip(&z_target) {
+                    assert!((actual - expected).abs() < 1e-12);
+                }
+
+                let mut permuted = vec![c64::default(); n];
+                plan.from_standard_order(&standard, &mut permuted);
+                assert_eq!(permuted, z);
+            }
+        }
+    }
+
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
     #[test]
     fn test_fwd_monomial() {
@@ -1171,6 +3571,367 @@ mod tests {
         }
     }
 
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_out_of_place() {
+        for base_algo in [FftAlgo::Dif4, FftAlgo::Dit8] {
+            // the first pass is done by the base FFT, and by a radix 2, 4 or 8 pass
+            for n in [32, 64, 128, 256, 4096] {
+                let mut z = vec![c64::default(); n];
+                for z in &mut z {
+                    z.re = random();
+                    z.im = random();
+                }
+                let orig = z.clone();
+                let input = z.clone();
+                let mut out = vec![c64::default(); n];
+
+                let plan = Plan::new(
+                    n,
+                    Method::UserProvided {
+                        base_algo,
+                        base_n: 32,
+                    },
+                );
+                // the out-of-place transforms need no more scratch space than the in-place ones,
+                // so the stack is sized exactly for the latter
+                let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+                let mut stack = PodStack::new(&mut mem);
+
+                plan.fwd(&mut z, stack.rb_mut());
+                plan.fwd_out_of_place(&input, &mut out, stack.rb_mut());
+                assert_eq!(z, out);
+                assert_eq!(input, orig);
+
+                let transformed = z.clone();
+                plan.inv(&mut z, stack.rb_mut());
+                plan.inv_out_of_place(&transformed, &mut out, stack.rb_mut());
+                assert_eq!(z, out);
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_pointwise() {
+        for n in [16, 32, 64, 1024] {
+            let mut a = vec![c64::default(); n];
+            let mut b = vec![c64::default(); n];
+            let mut acc = vec![c64::default(); n];
+            for (a, b, acc) in izip!(&mut a, &mut b, &mut acc) {
+                *a = c64::new(random(), random());
+                *b = c64::new(random(), random());
+                *acc = c64::new(random(), random());
+            }
+
+            let plan = Plan::new(
+                n,
+                Method::UserProvided {
+                    base_algo: FftAlgo::Dif4,
+                    base_n: n.min(32),
+                },
+            );
+            let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+            let mut stack = PodStack::new(&mut mem);
+
+            let mut prod = a.clone();
+            plan.pointwise_mul(&mut prod, &b);
+            for (prod, a, b) in izip!(&prod, &a, &b) {
+                assert!((prod - a * b).norm() < 1e-12);
+            }
+
+            let mut mul_add = acc.clone();
+            plan.pointwise_mul_add(&mut mul_add, &a, &b);
+            for (mul_add, acc, a, b) in izip!(&mul_add, &acc, &a, &b) {
+                assert!((mul_add - (acc + a * b)).norm() < 1e-12);
+            }
+
+            let mut mul_conj_add = acc.clone();
+            plan.pointwise_mul_conj_add(&mut mul_conj_add, &a, &b);
+            for (mul_conj_add, acc, a, b) in izip!(&mul_conj_add, &acc, &a, &b) {
+                assert!((mul_conj_add - (acc + a * b.conj())).norm() < 1e-12);
+            }
+
+            let mut fourier = a.clone();
+            plan.fwd(&mut fourier, stack.rb_mut());
+            let mut expected = fourier.clone();
+            plan.inv(&mut expected, stack.rb_mut());
+
+            let mut sum = acc.clone();
+            plan.inv_then_add(&mut sum, &mut fourier, stack.rb_mut());
+            assert_eq!(fourier, expected);
+            for (sum, acc, expected) in izip!(&sum, &acc, &expected) {
+                assert!((sum - (acc + expected)).norm() < 1e-12);
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_repermute() {
+        for n in [32, 64, 256, 4096] {
+            for (base_n1, base_n2) in [(32, 32), (32, 64), (1024, 32), (64, 256)] {
+                let base_n1 = base_n1.min(n);
+                let base_n2 = base_n2.min(n);
+                let plan1 = Plan::new(
+                    n,
+                    Method::UserProvided {
+                        base_algo: FftAlgo::Dif4,
+                        base_n: base_n1,
+                    },
+                );
+                let plan2 = Plan::new(
+                    n,
+                    Method::UserProvided {
+                        base_algo: FftAlgo::Dit8,
+                        base_n: base_n2,
+                    },
+                );
+                let mut mem = GlobalPodBuffer::new(
+                    plan1
+                        .fft_scratch()
+                        .unwrap()
+                        .or(plan2.fft_scratch().unwrap()),
+                );
+                let mut stack = PodStack::new(&mut mem);
+
+                let mut src = vec![c64::default(); n];
+                for z in &mut src {
+                    *z = c64::new(random(), random());
+                }
+                let orig = src.clone();
+
+                plan1.fwd(&mut src, stack.rb_mut());
+                let mut dst = vec![c64::default(); n];
+                plan2.repermute_from(&plan1, &src, &mut dst);
+                plan2.inv(&mut dst, stack.rb_mut());
+
+                for (z_actual, z_expected) in dst.iter().zip(&orig) {
+                    assert!((z_actual / n as f64 - z_expected).abs() < 1e-12);
+                }
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_split() {
+        for (base_algo, base_n) in [
+            (FftAlgo::Dif4, 32),
+            (FftAlgo::Dit8, 64),
+            (FftAlgo::Dif16, 256),
+            (FftAlgo::Dit2, 1024),
+        ] {
+            for n in [base_n, 2 * base_n, 4 * base_n, 8 * base_n, 64 * base_n] {
+                let plan = Plan::new(n, Method::UserProvided { base_algo, base_n });
+                let split = SplitPlan::new(&plan);
+                let mut mem = GlobalPodBuffer::new(
+                    plan.fft_scratch()
+                        .unwrap()
+                        .try_or(split.fft_scratch().unwrap())
+                        .unwrap()
+                        .try_or(plan.fft_split_scratch().unwrap())
+                        .unwrap(),
+                );
+                let mut stack = PodStack::new(&mut mem);
+
+                // the inputs are bounded by 1, so the outputs of both transforms are bounded by `n`
+                for fwd in [true, false] {
+                    let mut x = vec![c64::default(); n];
+                    for z in &mut x {
+                        *z = c64::new(random(), random());
+                    }
+                    let mut re: vec::Vec<f64> = x.iter().map(|z| z.re).collect();
+                    let mut im: vec::Vec<f64> = x.iter().map(|z| z.im).collect();
+                    let mut plan_re = re.clone();
+                    let mut plan_im = im.clone();
+
+                    if fwd {
+                        plan.fwd(&mut x, stack.rb_mut());
+                        split.fwd(&mut re, &mut im, stack.rb_mut());
+                        plan.fwd_split(&mut plan_re, &mut plan_im, stack.rb_mut());
+                    } else {
+                        plan.inv(&mut x, stack.rb_mut());
+                        split.inv(&mut re, &mut im, stack.rb_mut());
+                        plan.inv_split(&mut plan_re, &mut plan_im, stack.rb_mut());
+                    }
+                    for (z, re, im, plan_re, plan_im) in izip!(&x, &re, &im, &plan_re, &plan_im) {
+                        assert!((*z - c64::new(*re, *im)).abs() < 1e-12 * n as f64);
+                        assert!((*z - c64::new(*plan_re, *plan_im)).abs() < 1e-12 * n as f64);
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel() {
+        for n in [1 << 12, 1 << 14, 1 << 16] {
+            for parallel_threshold in [1 << 9, 1 << 12, DEFAULT_PARALLEL_THRESHOLD] {
+                let mut z = vec![c64::default(); n];
+                for z in &mut z {
+                    z.re = random();
+                    z.im = random();
+                }
+
+                let mut serial_plan = Plan::new(
+                    n,
+                    Method::UserProvided {
+                        base_algo: FftAlgo::Dif4,
+                        base_n: 64,
+                    },
+                );
+                serial_plan.set_parallel_threshold(usize::MAX);
+                let mut parallel_plan = serial_plan.clone();
+                parallel_plan.set_parallel_threshold(parallel_threshold);
+
+                let mut mem = GlobalPodBuffer::new(parallel_plan.fft_scratch().unwrap());
+                let mut stack = PodStack::new(&mut mem);
+
+                let mut z_target = z.clone();
+                serial_plan.fwd(&mut z_target, stack.rb_mut());
+                parallel_plan.fwd(&mut z, stack.rb_mut());
+                assert_eq!(z, z_target);
+
+                serial_plan.inv(&mut z_target, stack.rb_mut());
+                parallel_plan.inv(&mut z, stack.rb_mut());
+                assert_eq!(z, z_target);
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_try_new() {
+        let method = |base_n| Method::UserProvided {
+            base_algo: FftAlgo::Dif4,
+            base_n,
+        };
+
+        assert!(Plan::try_new(1 << 12, method(1 << 10)).is_ok());
+        assert!(Plan::try_new(16, method(16)).is_ok());
+        assert_eq!(
+            Plan::try_new(1000, method(32)).unwrap_err(),
+            PlanError::NotPowerOfTwo { size: 1000 },
+        );
+        assert_eq!(
+            Plan::try_new(1024, method(48)).unwrap_err(),
+            PlanError::NotPowerOfTwo { size: 48 },
+        );
+        assert_eq!(
+            Plan::try_new(64, method(128)).unwrap_err(),
+            PlanError::TooLarge { size: 128, max: 64 },
+        );
+        assert_eq!(
+            Plan::try_new(1 << 12, method(1 << 11)).unwrap_err(),
+            PlanError::TooLarge {
+                size: 1 << 11,
+                max: 1 << 10,
+            },
+        );
+        assert_eq!(
+            Plan::try_new(64, method(16)).unwrap_err(),
+            PlanError::BaseTooSmall {
+                base_n: 16,
+                min: 32
+            },
+        );
+        assert_eq!(
+            Plan32::try_new(64, method(16)).unwrap_err(),
+            PlanError::BaseTooSmall {
+                base_n: 16,
+                min: 32
+            },
+        );
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_batch() {
+        for n in [32, 64, 256, 512, 1024, 2048, 4096, 16384] {
+            for base_n in [32, 64, 1024] {
+                for count in [0, 1, 3, 17] {
+                    let mut z = vec![c64::default(); n * count];
+                    for z in &mut z {
+                        z.re = random();
+                        z.im = random();
+                    }
+
+                    let plan = Plan::new(
+                        n,
+                        Method::UserProvided {
+                            base_algo: FftAlgo::Dif4,
+                            base_n: base_n.min(n),
+                        },
+                    );
+                    let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+                    let mut stack = PodStack::new(&mut mem);
+
+                    let mut z_target = z.clone();
+                    for z in z_target.chunks_exact_mut(n) {
+                        plan.fwd(z, stack.rb_mut());
+                    }
+                    plan.fwd_batch(&mut z, count, stack.rb_mut());
+                    for (z_actual, z_expected) in z.iter().zip(&z_target) {
+                        assert!((z_actual - z_expected).abs() < 1e-12);
+                    }
+
+                    for z in z_target.chunks_exact_mut(n) {
+                        plan.inv(z, stack.rb_mut());
+                    }
+                    plan.inv_batch(&mut z, count, stack.rb_mut());
+                    for (z_actual, z_expected) in z.iter().zip(&z_target) {
+                        assert!((z_actual - z_expected).abs() < 1e-12);
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_fwd_c32() {
+        for n in [32, 128, 256, 512, 1024, 4096] {
+            for base_n in [32, 64] {
+                let mut z = vec![c32::default(); n];
+
+                for z in &mut z {
+                    z.re = random();
+                    z.im = random();
+                }
+
+                let orig = z.clone();
+                let mut z_target = z.clone();
+                let mut planner = rustfft::FftPlanner::new();
+                let fwd = planner.plan_fft_forward(n);
+                fwd.process(&mut z_target);
+
+                let plan = Plan32::new(
+                    n,
+                    Method::UserProvided {
+                        base_algo: FftAlgo::Dif4,
+                        base_n: base_n.min(n),
+                    },
+                );
+                let base_n = plan.algo().1;
+                let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+                let mut stack = PodStack::new(&mut mem);
+                plan.fwd(&mut z, stack.rb_mut());
+
+                for (i, z_target) in z_target.iter().enumerate() {
+                    let idx = bit_rev_twice(n.trailing_zeros(), base_n.trailing_zeros(), i);
+                    assert!((z[idx] - z_target).abs() < 1e-5 * n as f32);
+                }
+
+                plan.inv(&mut z, stack);
+                for (z_actual, z_expected) in z.iter().zip(&orig) {
+                    assert!((z_actual / n as f32 - z_expected).abs() < 1e-5);
+                }
+            }
+        }
+    }
+
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
     #[test]
     fn test_equivalency() {
@@ -9394,6 +12155,62 @@ mod tests {
         ];
         assert_eq!(target.as_slice(), z.as_slice());
     }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_simd_level() {
+        let levels = [
+            SimdLevel::Scalar,
+            SimdLevel::Avx2,
+            SimdLevel::Avx512,
+            SimdLevel::Neon,
+        ];
+        for (n, base_n) in [(32, 32), (256, 32), (4096, 64)] {
+            let method = Method::UserProvided {
+                base_algo: FftAlgo::Dif4,
+                base_n,
+            };
+            let scalar = Plan::new_with_simd(n, method, SimdLevel::Scalar);
+            let mut mem = GlobalPodBuffer::new(scalar.fft_scratch().unwrap());
+            let mut stack = PodStack::new(&mut mem);
+
+            let mut x = vec![c64::default(); n];
+            for z in &mut x {
+                *z = c64::new(random(), random());
+            }
+            let mut expected = x.clone();
+            scalar.fwd(&mut expected, stack.rb_mut());
+
+            for simd in levels {
+                match Plan::try_new_with_simd(n, method, simd) {
+                    Ok(plan) => {
+                        assert!(simd.is_available());
+                        assert_eq!(plan.simd_level(), simd);
+
+                        // the fourier coefficients are in the same order for all the levels
+                        let mut y = x.clone();
+                        plan.fwd(&mut y, stack.rb_mut());
+                        for (y, expected) in y.iter().zip(&expected) {
+                            assert!((y - expected).abs() < 1e-10 * n as f64);
+                        }
+                        plan.inv(&mut y, stack.rb_mut());
+                        for (y, x) in y.iter().zip(&x) {
+                            assert!((y / n as f64 - x).abs() < 1e-10);
+                        }
+                    }
+                    Err(err) => {
+                        assert!(!simd.is_available());
+                        assert_eq!(err, PlanError::SimdUnavailable { simd });
+                    }
+                }
+
+                match Plan32::try_new_with_simd(n, method, simd) {
+                    Ok(plan) => assert_eq!(plan.simd_level(), simd),
+                    Err(err) => assert_eq!(err, PlanError::SimdUnavailable { simd }),
+                }
+            }
+        }
+    }
 }
 
 #[cfg(all(test, feature = "serde"))]
@@ -9464,4 +12281,53 @@ mod tests_serde {
             }
         }
     }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_plan_serde() {
+        for (n, base_n) in [(32, 32), (256, 32), (4096, 64), (4096, 1024)] {
+            let plan = Plan::new(
+                n,
+                Method::UserProvided {
+                    base_algo: FftAlgo::Dif4,
+                    base_n,
+                },
+            );
+            let json = serde_json::to_string(&plan).unwrap();
+            let plan2: Plan = serde_json::from_str(&json).unwrap();
+            assert_eq!(plan2.fft_size(), n);
+            assert_eq!(plan2.algo(), (FftAlgo::Dif4, base_n));
+
+            let mut buf = Vec::<u8>::new();
+            let mut serializer = bincode::Serializer::new(&mut buf, bincode::options());
+            serde::Serialize::serialize(&plan, &mut serializer).unwrap();
+            let mut deserializer = bincode::de::Deserializer::from_slice(&buf, bincode::options());
+            let plan3: Plan = serde::Deserialize::deserialize(&mut deserializer).unwrap();
+
+            let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+            let mut stack = PodStack::new(&mut mem);
+
+            // the fourier coefficients are in the same order, so they can be shared
+            let mut z = vec![c64::default(); n];
+            for z in &mut z {
+                *z = c64::new(random(), random());
+            }
+            let mut x = z.clone();
+            let mut y = z.clone();
+            plan.fwd(&mut z, stack.rb_mut());
+            plan2.fwd(&mut x, stack.rb_mut());
+            plan3.fwd(&mut y, stack.rb_mut());
+            assert_eq!(z, x);
+            assert_eq!(z, y);
+        }
+
+        for json in [
+            r#"{"n":48,"base_algo":"Dif4","base_n":32}"#,
+            r#"{"n":64,"base_algo":"Dif4","base_n":128}"#,
+            r#"{"n":64,"base_algo":"Dif4","base_n":16}"#,
+            r#"{"n":4096,"base_algo":"Dif4","base_n":2048}"#,
+        ] {
+            assert!(serde_json::from_str::<Plan>(json).is_err());
+        }
+    }
 }
@@ -0,0 +1,177 @@
+//! FFT plan wisdom.
+//!
+//! Selecting a plan with `ordered::Method::Measure` or `unordered::Method::Measure`, which are
+//! available with the `std` feature, benchmarks all the candidate algorithms every time a plan is
+//! created, which is slow and may select different algorithms from one run to the next. A [`Wisdom`] records the outcome of these measurements, so that it
+//! can be saved, shipped to other machines, and used later to create plans without measuring
+//! again.
+
+extern crate alloc;
+
+use crate::{ordered::FftAlgo, SimdLevel};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::time::Duration;
+
+/// Algorithm selected for an FFT size and a SIMD instruction set.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Entry {
+    n: usize,
+    simd: SimdLevel,
+    algo: FftAlgo,
+    base_n: usize,
+}
+
+/// Store of the algorithms selected for the ordered and unordered FFT plans, indexed by the FFT
+/// size and the SIMD instruction set of the machine they were measured on.
+///
+/// For the ordered plans, the stored algorithm is used for the kernels of the FFT. For the
+/// unordered plans, the stored algorithm and size are those of the internal ordered FFT.
+///
+/// The choices are measured on the 64-bit plans, and are also used by the 32-bit plans.
+///
+/// # Example
+#[cfg_attr(feature = "std", doc = " ```")]
+#[cfg_attr(not(feature = "std"), doc = " ```ignore")]
+/// use concrete_fft::{unordered, wisdom::Wisdom};
+/// use core::time::Duration;
+///
+/// let mut wisdom = Wisdom::new();
+/// wisdom.measure_unordered(1024, Duration::from_millis(10));
+///
+/// let plan = unordered::Plan::new(1024, unordered::Method::Wisdom(&wisdom));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Wisdom {
+    ordered: Vec<Entry>,
+    unordered: Vec<Entry>,
+}
+
+fn get(entries: &[Entry], n: usize, simd: SimdLevel) -> Option<(FftAlgo, usize)> {
+    entries
+        .iter()
+        .find(|entry| entry.n == n && entry.simd == simd)
+        .map(|entry| (entry.algo, entry.base_n))
+}
+
+fn set(entries: &mut Vec<Entry>, n: usize, simd: SimdLevel, algo: FftAlgo, base_n: usize) {
+    let new = Entry {
+        n,
+        simd,
+        algo,
+        base_n,
+    };
+    match entries
+        .iter_mut()
+        .find(|entry| entry.n == n && entry.simd == simd)
+    {
+        Some(entry) => *entry = new,
+        None => entries.push(new),
+    }
+}
+
+impl Wisdom {
+    /// Returns an empty wisdom.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the algorithm stored for an ordered FFT of size `n` with the given SIMD instruction
+    /// set.
+    pub fn ordered(&self, n: usize, simd: SimdLevel) -> Option<FftAlgo> {
+        get(&self.ordered, n, simd).map(|(algo, _)| algo)
+    }
+
+    /// Returns the internal ordered FFT algorithm and size stored for an unordered FFT of size `n`
+    /// with the given SIMD instruction set.
+    pub fn unordered(&self, n: usize, simd: SimdLevel) -> Option<(FftAlgo, usize)> {
+        get(&self.unordered, n, simd)
+    }
+
+    /// Stores the algorithm to use for an ordered FFT of size `n` with the given SIMD instruction
+    /// set, replacing the previous one if any.
+    pub fn set_ordered(&mut self, n: usize, simd: SimdLevel, algo: FftAlgo) {
+        set(&mut self.ordered, n, simd, algo, n);
+    }
+
+    /// Stores the internal ordered FFT algorithm and size to use for an unordered FFT of size `n`
+    /// with the given SIMD instruction set, replacing the previous ones if any.
+    pub fn set_unordered(&mut self, n: usize, simd: SimdLevel, base_algo: FftAlgo, base_n: usize) {
+        set(&mut self.unordered, n, simd, base_algo, base_n);
+    }
+
+    /// Measures the fastest algorithm for an ordered FFT of size `n` on the current machine, and
+    /// stores it. The provided duration specifies how long the benchmark of each algorithm should
+    /// last.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn measure_ordered(&mut self, n: usize, duration: Duration) -> FftAlgo {
+        let algo = crate::ordered::measure_algo::<crate::c64>(n, duration, SimdLevel::detect());
+        self.set_ordered(n, SimdLevel::detect(), algo);
+        algo
+    }
+
+    /// Measures the fastest internal ordered FFT for an unordered FFT of size `n` on the current
+    /// machine, and stores it. The provided duration specifies how long the benchmark of each
+    /// algorithm should last.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn measure_unordered(&mut self, n: usize, duration: Duration) -> (FftAlgo, usize) {
+        let (base_algo, base_n) =
+            crate::unordered::measure_base::<crate::c64>(n, duration, SimdLevel::detect());
+        self.set_unordered(n, SimdLevel::detect(), base_algo, base_n);
+        (base_algo, base_n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{c64, ordered, unordered};
+    use alloc::vec;
+    use dyn_stack::{GlobalPodBuffer, PodStack, ReborrowMut};
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[test]
+    fn test_wisdom() {
+        let simd = SimdLevel::detect();
+        let mut wisdom = Wisdom::new();
+        assert_eq!(wisdom.ordered(256, simd), None);
+        assert_eq!(
+            ordered::Plan::try_new(256, ordered::Method::Wisdom(&wisdom)).unwrap_err(),
+            crate::PlanError::MissingWisdom { size: 256 },
+        );
+
+        wisdom.set_ordered(256, simd, FftAlgo::Dit4);
+        wisdom.set_ordered(256, simd, FftAlgo::Dif8);
+        wisdom.set_unordered(256, simd, FftAlgo::Dif4, 64);
+        assert_eq!(wisdom.ordered(256, simd), Some(FftAlgo::Dif8));
+        assert_eq!(wisdom.unordered(256, simd), Some((FftAlgo::Dif4, 64)));
+
+        let plan = ordered::Plan::new(256, ordered::Method::Wisdom(&wisdom));
+        assert_eq!(plan.algo(), FftAlgo::Dif8);
+        let plan = unordered::Plan::new(256, unordered::Method::Wisdom(&wisdom));
+        assert_eq!(plan.algo(), (FftAlgo::Dif4, 64));
+
+        let mut mem = GlobalPodBuffer::new(plan.fft_scratch().unwrap());
+        let mut stack = PodStack::new(&mut mem);
+        let mut z = vec![c64::default(); 256];
+        plan.fwd(&mut z, stack.rb_mut());
+        plan.inv(&mut z, stack.rb_mut());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_wisdom_serde() {
+        let mut wisdom = Wisdom::new();
+        wisdom.set_ordered(1024, SimdLevel::Avx2, FftAlgo::Dif16);
+        wisdom.set_unordered(1024, SimdLevel::Scalar, FftAlgo::Dit8, 512);
+
+        let json = serde_json::to_string(&wisdom).unwrap();
+        assert_eq!(serde_json::from_str::<Wisdom>(&json).unwrap(), wisdom);
+        let bytes = bincode::serialize(&wisdom).unwrap();
+        assert_eq!(bincode::deserialize::<Wisdom>(&bytes).unwrap(), wisdom);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{c64, fft_simd::*};
+use crate::{c32, c64, fft_simd::*};
 use pulp::{cast, x86::*};
 
 impl FftSimd<c64x2> for V3 {
@@ -73,7 +73,7 @@ impl FftSimd<c64x2> for V3 {
     }
 }
 
-#[cfg(feature = "nightly")]
+#[cfg(feature = "avx512")]
 impl FftSimd<c64x4> for V4 {
     #[inline(always)]
     fn try_new() -> Option<Self> {
@@ -132,24 +132,235 @@ impl FftSimd<c64x4> for V4 {
 
     #[inline(always)]
     fn transpose(self, r0: c64x4, r1: c64x4, r2: c64x4, r3: c64x4) -> (c64x4, c64x4, c64x4, c64x4) {
-        let t0 = self
-            .avx512f
-            ._mm512_shuffle_f64x2::<0b1000_1000>(cast(r0), cast(r1));
-        let t1 = self
-            .avx512f
-            ._mm512_shuffle_f64x2::<0b1101_1101>(cast(r0), cast(r1));
-        let t2 = self
-            .avx512f
-            ._mm512_shuffle_f64x2::<0b1000_1000>(cast(r2), cast(r3));
-        let t3 = self
-            .avx512f
-            ._mm512_shuffle_f64x2::<0b1101_1101>(cast(r2), cast(r3));
-
-        let s0 = cast(self.avx512f._mm512_shuffle_f64x2::<0b1000_1000>(t0, t2));
-        let s1 = cast(self.avx512f._mm512_shuffle_f64x2::<0b1101_1101>(t0, t2));
-        let s2 = cast(self.avx512f._mm512_shuffle_f64x2::<0b1000_1000>(t1, t3));
-        let s3 = cast(self.avx512f._mm512_shuffle_f64x2::<0b1101_1101>(t1, t3));
-
-        (s0, s2, s1, s3)
+        transpose_4x4_f64x2(self, r0, r1, r2, r3)
+    }
+}
+
+// 4x4 transpose of the 128-bit blocks of the registers, each block holding one c64
+#[cfg(feature = "avx512")]
+#[inline(always)]
+fn transpose_4x4_f64x2(
+    simd: V4,
+    r0: c64x4,
+    r1: c64x4,
+    r2: c64x4,
+    r3: c64x4,
+) -> (c64x4, c64x4, c64x4, c64x4) {
+    let avx512f = simd.avx512f;
+    let t0 = avx512f._mm512_shuffle_f64x2::<0b1000_1000>(cast(r0), cast(r1));
+    let t1 = avx512f._mm512_shuffle_f64x2::<0b1101_1101>(cast(r0), cast(r1));
+    let t2 = avx512f._mm512_shuffle_f64x2::<0b1000_1000>(cast(r2), cast(r3));
+    let t3 = avx512f._mm512_shuffle_f64x2::<0b1101_1101>(cast(r2), cast(r3));
+
+    let s0 = cast(avx512f._mm512_shuffle_f64x2::<0b1000_1000>(t0, t2));
+    let s1 = cast(avx512f._mm512_shuffle_f64x2::<0b1101_1101>(t0, t2));
+    let s2 = cast(avx512f._mm512_shuffle_f64x2::<0b1000_1000>(t1, t3));
+    let s3 = cast(avx512f._mm512_shuffle_f64x2::<0b1101_1101>(t1, t3));
+
+    (s0, s2, s1, s3)
+}
+
+impl FftSimd<c32x4> for V3 {
+    #[inline(always)]
+    fn try_new() -> Option<Self> {
+        Self::try_new()
+    }
+    #[inline(always)]
+    fn vectorize(self, f: impl pulp::NullaryFnOnce<Output = ()>) {
+        self.vectorize(f)
+    }
+
+    #[inline(always)]
+    fn splat_f64(self, value: f64) -> c32x4 {
+        cast(self.splat_f32x8(value as f32))
+    }
+
+    #[inline(always)]
+    fn splat(self, value: c32) -> c32x4 {
+        // a c32 has the same size as an f64, so we can broadcast its bits as such
+        cast(self.splat_f64x4(cast(value)))
+    }
+
+    #[inline(always)]
+    fn xor(self, a: c32x4, b: c32x4) -> c32x4 {
+        cast(self.xor_f32x8(cast(a), cast(b)))
+    }
+
+    #[inline(always)]
+    fn swap_re_im(self, xy: c32x4) -> c32x4 {
+        cast(self.avx._mm256_permute_ps::<0b10_11_00_01>(cast(xy)))
+    }
+
+    #[inline(always)]
+    fn add(self, a: c32x4, b: c32x4) -> c32x4 {
+        cast(self.add_f32x8(cast(a), cast(b)))
+    }
+
+    #[inline(always)]
+    fn sub(self, a: c32x4, b: c32x4) -> c32x4 {
+        cast(self.sub_f32x8(cast(a), cast(b)))
+    }
+
+    #[inline(always)]
+    fn real_mul(self, a: c32x4, b: c32x4) -> c32x4 {
+        cast(self.mul_f32x8(cast(a), cast(b)))
+    }
+
+    #[inline(always)]
+    fn mul(self, a: c32x4, b: c32x4) -> c32x4 {
+        let xy = cast(b);
+        let yx = cast(self.swap_re_im(b));
+        let ab = cast(a);
+        let aa = cast(self.avx._mm256_moveldup_ps(ab));
+        let bb = cast(self.avx._mm256_movehdup_ps(ab));
+        cast(self.mul_subadd_f32x8(aa, xy, self.mul_f32x8(bb, yx)))
+    }
+
+    #[inline(always)]
+    fn transpose(self, r0: c32x4, r1: c32x4, r2: c32x4, r3: c32x4) -> (c32x4, c32x4, c32x4, c32x4) {
+        // each c32 has the same size as an f64, so this is a 4x4 transpose of f64 values
+        let t0 = self.avx._mm256_unpacklo_pd(cast(r0), cast(r1));
+        let t1 = self.avx._mm256_unpackhi_pd(cast(r0), cast(r1));
+        let t2 = self.avx._mm256_unpacklo_pd(cast(r2), cast(r3));
+        let t3 = self.avx._mm256_unpackhi_pd(cast(r2), cast(r3));
+
+        let s0 = cast(self.avx._mm256_permute2f128_pd::<0b0010_0000>(t0, t2));
+        let s1 = cast(self.avx._mm256_permute2f128_pd::<0b0010_0000>(t1, t3));
+        let s2 = cast(self.avx._mm256_permute2f128_pd::<0b0011_0001>(t0, t2));
+        let s3 = cast(self.avx._mm256_permute2f128_pd::<0b0011_0001>(t1, t3));
+
+        (s0, s1, s2, s3)
+    }
+}
+
+#[cfg(feature = "avx512")]
+impl FftSimd<c32x8> for V4 {
+    #[inline(always)]
+    fn try_new() -> Option<Self> {
+        Self::try_new()
+    }
+    #[inline(always)]
+    fn vectorize(self, f: impl pulp::NullaryFnOnce<Output = ()>) {
+        self.vectorize(f)
+    }
+
+    #[inline(always)]
+    fn splat_f64(self, value: f64) -> c32x8 {
+        cast(self.splat_f32x16(value as f32))
+    }
+
+    #[inline(always)]
+    fn splat(self, value: c32) -> c32x8 {
+        // a c32 has the same size as an f64, so we can broadcast its bits as such
+        cast(self.splat_f64x8(cast(value)))
+    }
+
+    #[inline(always)]
+    fn xor(self, a: c32x8, b: c32x8) -> c32x8 {
+        cast(self.xor_f32x16(cast(a), cast(b)))
+    }
+
+    #[inline(always)]
+    fn swap_re_im(self, xy: c32x8) -> c32x8 {
+        cast(self.avx512f._mm512_permute_ps::<0b10_11_00_01>(cast(xy)))
+    }
+
+    #[inline(always)]
+    fn add(self, a: c32x8, b: c32x8) -> c32x8 {
+        cast(self.add_f32x16(cast(a), cast(b)))
+    }
+
+    #[inline(always)]
+    fn sub(self, a: c32x8, b: c32x8) -> c32x8 {
+        cast(self.sub_f32x16(cast(a), cast(b)))
+    }
+
+    #[inline(always)]
+    fn real_mul(self, a: c32x8, b: c32x8) -> c32x8 {
+        cast(self.mul_f32x16(cast(a), cast(b)))
+    }
+
+    #[inline(always)]
+    fn mul(self, a: c32x8, b: c32x8) -> c32x8 {
+        let xy = cast(b);
+        let yx = cast(self.swap_re_im(b));
+        let ab = cast(a);
+        let aa = cast(self.avx512f._mm512_moveldup_ps(ab));
+        let bb = cast(self.avx512f._mm512_movehdup_ps(ab));
+        cast(self.mul_subadd_f32x16(aa, xy, self.mul_f32x16(bb, yx)))
+    }
+
+    #[inline(always)]
+    fn catlo(self, a: c32x8, b: c32x8) -> c32x8 {
+        cast(
+            self.avx512f
+                ._mm512_shuffle_f64x2::<0b0100_0100>(cast(a), cast(b)),
+        )
+    }
+
+    #[inline(always)]
+    fn cathi(self, a: c32x8, b: c32x8) -> c32x8 {
+        cast(
+            self.avx512f
+                ._mm512_shuffle_f64x2::<0b1110_1110>(cast(a), cast(b)),
+        )
+    }
+
+    #[inline(always)]
+    fn transpose(self, r0: c32x8, r1: c32x8, r2: c32x8, r3: c32x8) -> (c32x8, c32x8, c32x8, c32x8) {
+        // each c32 has the same size as an f64, so we interleave pairs of f64 values, then
+        // transpose the resulting 128-bit blocks
+        let avx512f = self.avx512f;
+        let t0 = avx512f._mm512_unpacklo_pd(cast(r0), cast(r1));
+        let t1 = avx512f._mm512_unpackhi_pd(cast(r0), cast(r1));
+        let t2 = avx512f._mm512_unpacklo_pd(cast(r2), cast(r3));
+        let t3 = avx512f._mm512_unpackhi_pd(cast(r2), cast(r3));
+
+        let (s0, s1, s2, s3) = transpose_4x4_f64x2(self, cast(t0), cast(t2), cast(t1), cast(t3));
+        (cast(s0), cast(s1), cast(s2), cast(s3))
+    }
+
+    #[inline(always)]
+    fn untranspose(
+        self,
+        r0: c32x8,
+        r1: c32x8,
+        r2: c32x8,
+        r3: c32x8,
+    ) -> (c32x8, c32x8, c32x8, c32x8) {
+        // the transpose of the 128-bit blocks is its own inverse
+        let (t0, t2, t1, t3) = transpose_4x4_f64x2(self, cast(r0), cast(r1), cast(r2), cast(r3));
+
+        let avx512f = self.avx512f;
+        let s0 = avx512f._mm512_unpacklo_pd(cast(t0), cast(t1));
+        let s1 = avx512f._mm512_unpackhi_pd(cast(t0), cast(t1));
+        let s2 = avx512f._mm512_unpacklo_pd(cast(t2), cast(t3));
+        let s3 = avx512f._mm512_unpackhi_pd(cast(t2), cast(t3));
+        (cast(s0), cast(s1), cast(s2), cast(s3))
+    }
+
+    #[inline(always)]
+    fn transpose_8x8(self, x: [c32x8; 8]) -> [c32x8; 8] {
+        // each c32 has the same size as an f64, so this is an 8x8 transpose of f64 values
+        let avx512f = self.avx512f;
+        let lo = |a: c32x8, b: c32x8| cast(avx512f._mm512_unpacklo_pd(cast(a), cast(b)));
+        let hi = |a: c32x8, b: c32x8| cast(avx512f._mm512_unpackhi_pd(cast(a), cast(b)));
+
+        let (e0, e1, e2, e3) = transpose_4x4_f64x2(
+            self,
+            lo(x[0], x[1]),
+            lo(x[2], x[3]),
+            lo(x[4], x[5]),
+            lo(x[6], x[7]),
+        );
+        let (o0, o1, o2, o3) = transpose_4x4_f64x2(
+            self,
+            hi(x[0], x[1]),
+            hi(x[2], x[3]),
+            hi(x[4], x[5]),
+            hi(x[6], x[7]),
+        );
+
+        [e0, o0, e1, o1, e2, o2, e3, o3].map(cast)
     }
 }